@@ -0,0 +1,239 @@
+use std::time::Duration;
+
+use chrono::{DateTime, Datelike, Local, Timelike};
+use serde::{Deserialize, Serialize};
+
+use crate::Alarm;
+
+// Longest volume ramp accepted, an hour is already far more than any alarm needs
+const MAX_RAMP_DURATION: f32 = 60.0 * 60.0;
+
+/// An alarm as kept by the service, see `Alarm` in msg_alarms.fbs.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ScheduledAlarm {
+    pub id: String,
+    pub device_id: String,
+    /// 0 is Monday to 6 Sunday, empty for every day
+    pub days: Vec<u8>,
+    pub hour: u8,
+    pub minute: u8,
+    pub url: String,
+    pub volume: f32,
+    pub ramp_volume: f32,
+    /// Seconds
+    pub ramp_duration: f32,
+    pub enabled: bool,
+}
+
+impl ScheduledAlarm {
+    pub fn from_fbs(alarm: Option<Alarm>) -> Result<Self, String> {
+        let alarm = alarm.ok_or("Alarm is none")?;
+
+        let days: Vec<u8> = alarm
+            .days()
+            .map(|days| days.bytes().to_vec())
+            .unwrap_or_default();
+        if let Some(day) = days.iter().find(|day| **day > 6) {
+            return Err(format!("Invalid day {}", day));
+        }
+        if alarm.hour() > 23 || alarm.minute() > 59 {
+            return Err(format!(
+                "Invalid time {}:{:02}",
+                alarm.hour(),
+                alarm.minute()
+            ));
+        }
+        for volume in [alarm.volume(), alarm.ramp_volume()] {
+            if !volume.is_finite() || volume < 0.0 {
+                return Err(format!("Invalid volume {}", volume));
+            }
+        }
+        let ramp_duration = alarm.ramp_duration();
+        if !(0.0..=MAX_RAMP_DURATION).contains(&ramp_duration) {
+            return Err(format!("Invalid ramp duration {}", ramp_duration));
+        }
+        let url = alarm.url().ok_or("Url is none")?;
+        // Speakers only play HTTP(S) URLs, anything else would only fail when the alarm fires.
+        // Same check as `validate_url` in speakers/src/media_source.rs, keep the two in sync
+        let has_scheme = url.starts_with("http://") || url.starts_with("https://");
+        if !has_scheme || url.chars().any(|c| c.is_whitespace() || c.is_control()) {
+            return Err(format!("Invalid url {}", url));
+        }
+
+        Ok(Self {
+            id: alarm.id().unwrap_or_default().to_string(),
+            device_id: alarm.device_id().ok_or("Device id is none")?.to_string(),
+            days,
+            hour: alarm.hour(),
+            minute: alarm.minute(),
+            url: url.to_string(),
+            volume: alarm.volume(),
+            ramp_volume: alarm.ramp_volume(),
+            ramp_duration: alarm.ramp_duration(),
+            enabled: alarm.enabled(),
+        })
+    }
+
+    /// Whether the alarm goes off in the minute `now` falls in.
+    pub fn is_due(&self, now: &DateTime<Local>) -> bool {
+        let weekday = now.weekday().num_days_from_monday() as u8;
+        self.enabled
+            && self.hour as u32 == now.hour()
+            && self.minute as u32 == now.minute()
+            && (self.days.is_empty() || self.days.contains(&weekday))
+    }
+
+    /// Volume to ramp up to and how long to take, if the alarm ramps at all.
+    pub fn ramp(&self) -> Option<(f32, Duration)> {
+        if self.ramp_volume <= self.volume || self.ramp_duration <= 0.0 {
+            return None;
+        }
+        Some((
+            self.ramp_volume,
+            Duration::from_secs_f32(self.ramp_duration),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use flatbuffers::FlatBufferBuilder;
+
+    use super::*;
+    use crate::AlarmArgs;
+
+    fn alarm(days: Vec<u8>) -> ScheduledAlarm {
+        ScheduledAlarm {
+            id: "alarm-1".to_string(),
+            device_id: "kitchen".to_string(),
+            days,
+            hour: 7,
+            minute: 30,
+            url: "https://example.com/wake-up".to_string(),
+            volume: 20.0,
+            ramp_volume: 0.0,
+            ramp_duration: 0.0,
+            enabled: true,
+        }
+    }
+
+    fn local(day: u32, hour: u32, minute: u32) -> DateTime<Local> {
+        // 2024-01-01 is a Monday
+        Local
+            .with_ymd_and_hms(2024, 1, day, hour, minute, 0)
+            .unwrap()
+    }
+
+    fn from_fbs(url: &str, hour: u8) -> Result<ScheduledAlarm, String> {
+        from_fbs_with_ramp(url, hour, 0.0)
+    }
+
+    fn from_fbs_with_ramp(
+        url: &str,
+        hour: u8,
+        ramp_duration: f32,
+    ) -> Result<ScheduledAlarm, String> {
+        let mut fbb = FlatBufferBuilder::new();
+        let device_id = fbb.create_string("kitchen");
+        let url = fbb.create_string(url);
+        let alarm = Alarm::create(
+            &mut fbb,
+            &AlarmArgs {
+                device_id: Some(device_id),
+                url: Some(url),
+                hour,
+                ramp_duration,
+                ..Default::default()
+            },
+        );
+        fbb.finish(alarm, None);
+        ScheduledAlarm::from_fbs(Some(
+            flatbuffers::root::<Alarm>(fbb.finished_data()).unwrap(),
+        ))
+    }
+
+    #[test]
+    fn is_due_in_its_minute_only() {
+        let alarm = alarm(Vec::new());
+
+        assert!(alarm.is_due(&local(1, 7, 30)));
+        assert!(!alarm.is_due(&local(1, 7, 31)));
+        assert!(!alarm.is_due(&local(1, 8, 30)));
+    }
+
+    #[test]
+    fn is_due_on_its_days_only() {
+        // Monday and Wednesday
+        let alarm = alarm(vec![0, 2]);
+
+        assert!(alarm.is_due(&local(1, 7, 30)));
+        assert!(!alarm.is_due(&local(2, 7, 30)));
+        assert!(alarm.is_due(&local(3, 7, 30)));
+    }
+
+    #[test]
+    fn disabled_alarm_is_never_due() {
+        let alarm = ScheduledAlarm {
+            enabled: false,
+            ..alarm(Vec::new())
+        };
+
+        assert!(!alarm.is_due(&local(1, 7, 30)));
+    }
+
+    #[test]
+    fn ramp_only_goes_up() {
+        let mut alarm = alarm(Vec::new());
+        assert_eq!(alarm.ramp(), None);
+
+        alarm.ramp_volume = 60.0;
+        alarm.ramp_duration = 90.0;
+        assert_eq!(alarm.ramp(), Some((60.0, Duration::from_secs(90))));
+
+        alarm.ramp_volume = 10.0;
+        assert_eq!(alarm.ramp(), None);
+
+        alarm.ramp_volume = 60.0;
+        alarm.ramp_duration = 0.0;
+        assert_eq!(alarm.ramp(), None);
+    }
+
+    #[test]
+    fn from_fbs_accepts_a_valid_alarm() {
+        let alarm = from_fbs("https://example.com/wake-up", 7).unwrap();
+
+        assert_eq!(alarm.device_id, "kitchen");
+        assert_eq!(alarm.url, "https://example.com/wake-up");
+        assert_eq!(alarm.hour, 7);
+        assert!(alarm.enabled);
+    }
+
+    #[test]
+    fn from_fbs_rejects_invalid_urls() {
+        for url in [
+            "",
+            "example.com",
+            "file:///etc/passwd",
+            "https://example.com/a b",
+        ] {
+            assert_eq!(from_fbs(url, 7).err(), Some(format!("Invalid url {}", url)));
+        }
+    }
+
+    #[test]
+    fn from_fbs_rejects_invalid_times() {
+        assert_eq!(
+            from_fbs("https://example.com/wake-up", 24).err(),
+            Some("Invalid time 24:00".to_string())
+        );
+    }
+
+    #[test]
+    fn from_fbs_rejects_ramps_longer_than_an_hour() {
+        assert!(from_fbs_with_ramp("https://example.com/wake-up", 7, 3600.0).is_ok());
+        for ramp_duration in [-1.0, 3601.0, 1e20, f32::NAN] {
+            assert!(from_fbs_with_ramp("https://example.com/wake-up", 7, ramp_duration).is_err());
+        }
+    }
+}
@@ -0,0 +1,422 @@
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex, PoisonError,
+    },
+    thread,
+    time::Duration,
+};
+
+use chrono::{DateTime, Local, NaiveDate, Timelike};
+
+use crate::{
+    alarm::ScheduledAlarm,
+    alarm_store::AlarmStore,
+    fbs::{
+        construct_alarm_fired_event_message, construct_alarms_changed_event_message,
+        construct_insert_alarm_song_command, construct_set_music_volume_command, NcSendable,
+    },
+    root_as_message, AlarmCommand, AlarmCommandContent, AlarmQuery, AlarmQueryContent, Message,
+    MessageContent, PlayContent, PlayStarted, SpeakerCommand, SpeakerCommandContent, SpeakerEvent,
+    SpeakerEventContent,
+};
+
+const SCHEDULER_INTERVAL: Duration = Duration::from_secs(1);
+// Volume steps of a ramp are sent this far apart
+const RAMP_STEP: Duration = Duration::from_secs(5);
+
+/// A volume ramp in progress, cancelled once its alarm song is no longer playing or someone
+/// else takes over the volume.
+struct Ramp {
+    alarm_id: String,
+    url: String,
+    /// Volumes the alarm and its ramp sent that haven't come back on speaker.command yet
+    sent_volumes: Arc<Mutex<Vec<f32>>>,
+    cancelled: Arc<AtomicBool>,
+}
+
+impl Ramp {
+    /// Whether `message` about the ramp's speaker means the ramp has to stop.
+    fn is_ended_by(&self, message: &Message) -> bool {
+        if let Some(event) = message.content_as_speaker_event() {
+            ends_ramp(&event, &self.url)
+        } else if let Some(command) = message.content_as_speaker_command() {
+            self.is_overridden_by(&command)
+        } else {
+            false
+        }
+    }
+
+    /// Whether `command` stops the speaker or sets a volume the ramp didn't send itself.
+    fn is_overridden_by(&self, command: &SpeakerCommand) -> bool {
+        match command.command_type() {
+            SpeakerCommandContent::Stop => true,
+            SpeakerCommandContent::SetMusicVolume => {
+                let Some(volume) = command
+                    .command_as_set_music_volume()
+                    .map(|set_music_volume| set_music_volume.volume())
+                else {
+                    return false;
+                };
+                let mut sent_volumes = self.sent_volumes.lock().unwrap();
+                match sent_volumes.iter().position(|sent| *sent == volume) {
+                    Some(index) => {
+                        sent_volumes.remove(index);
+                        false
+                    }
+                    None => true,
+                }
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Drop for Ramp {
+    fn drop(&mut self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+}
+
+pub struct AlarmHandler {
+    store: AlarmStore,
+    /// Ramps by device id, one per device as a new alarm takes over from the last
+    ramps: HashMap<String, Ramp>,
+    /// Minute the scheduler last fired alarms for
+    last_checked: (NaiveDate, u32, u32),
+    nc: Arc<nats::Connection>,
+}
+
+/// Fires the alarms due each minute.
+pub fn make_scheduler_thread(alarm_handler: Arc<Mutex<AlarmHandler>>) {
+    thread::spawn(move || loop {
+        thread::sleep(SCHEDULER_INTERVAL);
+
+        let mut alarm_handler = alarm_handler.lock().unwrap_or_else(PoisonError::into_inner);
+        alarm_handler.fire_due_alarms(Local::now());
+    });
+}
+
+fn minute_of(time: &DateTime<Local>) -> (NaiveDate, u32, u32) {
+    (time.date_naive(), time.hour(), time.minute())
+}
+
+/// Raises the speaker's music volume from `from` to `to` in steps over `duration`, stopping
+/// early once `cancelled` is set. Each volume goes in `sent_volumes` before it is sent.
+fn make_ramp_thread(
+    device_id: String,
+    from: f32,
+    to: f32,
+    duration: Duration,
+    sent_volumes: Arc<Mutex<Vec<f32>>>,
+    cancelled: Arc<AtomicBool>,
+    nc: Arc<nats::Connection>,
+) {
+    thread::spawn(move || {
+        let steps = (duration.as_secs_f32() / RAMP_STEP.as_secs_f32())
+            .ceil()
+            .max(1.0) as u32;
+        for step in 1..=steps {
+            thread::sleep(duration / steps);
+            if cancelled.load(Ordering::SeqCst) {
+                return;
+            }
+
+            let volume = from + (to - from) * step as f32 / steps as f32;
+            sent_volumes.lock().unwrap().push(volume);
+            let message = construct_set_music_volume_command(volume, &device_id);
+            if let Err(e) = nc.publish("speaker.command", message) {
+                eprintln!("Error ramping volume on {}: {}", device_id, e);
+                return;
+            }
+        }
+    });
+}
+
+impl AlarmHandler {
+    pub fn new(nc: Arc<nats::Connection>) -> Result<Self, String> {
+        Ok(Self {
+            store: AlarmStore::load()?,
+            ramps: HashMap::new(),
+            // Alarms of the minute the service (re)starts in may have already gone off
+            last_checked: minute_of(&Local::now()),
+            nc,
+        })
+    }
+
+    pub fn handle_alarm_message(&mut self, data: &[u8]) -> Result<Option<Vec<u8>>, String> {
+        let message = root_as_message(data).map_err(|e| e.to_string())?;
+        match message.content_type() {
+            MessageContent::AlarmCommand => {
+                let command = message
+                    .content_as_alarm_command()
+                    .ok_or("AlarmCommand is none")?;
+                self.handle_alarm_command(command)
+            }
+            MessageContent::AlarmQuery => {
+                let query = message
+                    .content_as_alarm_query()
+                    .ok_or("AlarmQuery is none")?;
+                self.handle_alarm_query(query)
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Cancels the ramp of a speaker whose alarm song ended, was stopped or was replaced, whose
+    /// sleep timer fired, or whose volume was changed by anyone but the ramp.
+    pub fn handle_speaker_message(&mut self, data: &[u8]) -> Result<Option<Vec<u8>>, String> {
+        let message = root_as_message(data).map_err(|e| e.to_string())?;
+        let device_id = match message.content_type() {
+            MessageContent::SpeakerEvent => message
+                .content_as_speaker_event()
+                .ok_or("SpeakerEvent is none")?
+                .device_id(),
+            MessageContent::SpeakerCommand => message
+                .content_as_speaker_command()
+                .ok_or("SpeakerCommand is none")?
+                .device_id(),
+            _ => return Ok(None),
+        };
+        let device_id = device_id.ok_or("Device id is none")?;
+
+        let ended = self
+            .ramps
+            .get(device_id)
+            .is_some_and(|ramp| ramp.is_ended_by(&message));
+        if ended && self.ramps.remove(device_id).is_some() {
+            println!("Volume ramp on {} cancelled", device_id);
+        }
+        Ok(None)
+    }
+
+    pub fn handle_alarm_command(
+        &mut self,
+        command: AlarmCommand,
+    ) -> Result<Option<Vec<u8>>, String> {
+        match command.command_type() {
+            AlarmCommandContent::CreateAlarm => {
+                let create_alarm = command
+                    .command_as_create_alarm()
+                    .ok_or("No create alarm command")?;
+                let alarm = ScheduledAlarm::from_fbs(create_alarm.alarm())?;
+                self.store.upsert(alarm)?;
+            }
+            AlarmCommandContent::DeleteAlarm => {
+                let id = command
+                    .command_as_delete_alarm()
+                    .ok_or("No delete alarm command")?
+                    .id()
+                    .ok_or("No id")?;
+                self.store.delete(id)?;
+                self.ramps.retain(|_, ramp| ramp.alarm_id != id);
+            }
+            _ => return Err("Unknown command".to_string()),
+        }
+
+        Ok(Some(construct_alarms_changed_event_message(
+            self.store.alarms(),
+        )))
+    }
+
+    pub fn handle_alarm_query(&mut self, query: AlarmQuery) -> Result<Option<Vec<u8>>, String> {
+        match query.query_type() {
+            AlarmQueryContent::QueryAlarms => Ok(Some(construct_alarms_changed_event_message(
+                self.store.alarms(),
+            ))),
+            _ => Err("Unknown query".to_string()),
+        }
+    }
+
+    /// Fires the alarms set for the minute of `now`, once per minute.
+    pub fn fire_due_alarms(&mut self, now: DateTime<Local>) {
+        let minute = minute_of(&now);
+        if minute == self.last_checked {
+            return;
+        }
+        self.last_checked = minute;
+
+        let due_alarms: Vec<ScheduledAlarm> = self
+            .store
+            .alarms()
+            .iter()
+            .filter(|alarm| alarm.is_due(&now))
+            .cloned()
+            .collect();
+        for alarm in &due_alarms {
+            self.fire_alarm(alarm)
+                .map(|_| Some(construct_alarm_fired_event_message(alarm)))
+                .send(&self.nc, "alarm.event");
+        }
+    }
+
+    /// Sets the speaker's volume and has playlists start the alarm's url on it. Playing it
+    /// directly would leave playlists taking its FileEnded for the end of the queue's head.
+    fn fire_alarm(&mut self, alarm: &ScheduledAlarm) -> Result<(), String> {
+        println!("Alarm {} fired on {}", alarm.id, alarm.device_id);
+        // The previous alarm's ramp would fight this one's volume
+        self.ramps.remove(&alarm.device_id);
+        // Known before it is sent, so the ramp doesn't take it for someone else's
+        let sent_volumes = Arc::new(Mutex::new(vec![alarm.volume]));
+
+        let commands = [
+            (
+                "speaker.command",
+                construct_set_music_volume_command(alarm.volume, &alarm.device_id),
+            ),
+            (
+                "playlist.command",
+                construct_insert_alarm_song_command(alarm),
+            ),
+        ];
+        for (topic, command) in commands {
+            self.nc
+                .publish(topic, command)
+                .map_err(|e| format!("Error firing alarm {}: {}", alarm.id, e))?;
+        }
+
+        if let Some((ramp_volume, ramp_duration)) = alarm.ramp() {
+            let cancelled = Arc::new(AtomicBool::new(false));
+            make_ramp_thread(
+                alarm.device_id.clone(),
+                alarm.volume,
+                ramp_volume,
+                ramp_duration,
+                sent_volumes.clone(),
+                cancelled.clone(),
+                self.nc.clone(),
+            );
+            self.ramps.insert(
+                alarm.device_id.clone(),
+                Ramp {
+                    alarm_id: alarm.id.clone(),
+                    url: alarm.url.clone(),
+                    sent_volumes,
+                    cancelled,
+                },
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Whether `event` means the alarm song at `url` is no longer playing. Its own PlayStarted
+/// arrives after the ramp began, anything else starting replaced it. A sleep timer firing
+/// stops the speaker, raising the volume afterwards would only surprise the next play.
+fn ends_ramp(event: &SpeakerEvent, url: &str) -> bool {
+    match event.event_type() {
+        SpeakerEventContent::FileEnded
+        | SpeakerEventContent::PlaybackFailed
+        | SpeakerEventContent::SleepTimerFired => true,
+        SpeakerEventContent::PlayStarted => {
+            event
+                .event_as_play_started()
+                .and_then(|play_started| play_started_url(&play_started))
+                != Some(url)
+        }
+        _ => false,
+    }
+}
+
+fn play_started_url<'a>(play_started: &PlayStarted<'a>) -> Option<&'a str> {
+    match play_started.content_type() {
+        PlayContent::PlayYoutube => play_started.content_as_play_youtube()?.url(),
+        PlayContent::PlayLocalFile => play_started.content_as_play_local_file()?.path(),
+        PlayContent::PlayStream => play_started.content_as_play_stream()?.url(),
+        PlayContent::PlayDlna => play_started.content_as_play_dlna()?.url(),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use flatbuffers::FlatBufferBuilder;
+
+    use super::*;
+    use crate::{
+        fbs::construct_set_music_volume_command, MessageArgs, SleepTimerFired, SleepTimerFiredArgs,
+        SpeakerEventArgs,
+    };
+
+    fn ramp(sent_volumes: Vec<f32>) -> Ramp {
+        Ramp {
+            alarm_id: "alarm-1".to_string(),
+            url: "https://example.com/wake-up".to_string(),
+            sent_volumes: Arc::new(Mutex::new(sent_volumes)),
+            cancelled: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    fn ends(ramp: &Ramp, data: &[u8]) -> bool {
+        ramp.is_ended_by(&root_as_message(data).unwrap())
+    }
+
+    fn sleep_timer_fired() -> Vec<u8> {
+        let mut fbb = FlatBufferBuilder::new();
+        let sleep_timer_fired = SleepTimerFired::create(&mut fbb, &SleepTimerFiredArgs {});
+        let device_id = fbb.create_string("kitchen");
+        let event = SpeakerEvent::create(
+            &mut fbb,
+            &SpeakerEventArgs {
+                device_id: Some(device_id),
+                event_type: SpeakerEventContent::SleepTimerFired,
+                event: Some(sleep_timer_fired.as_union_value()),
+            },
+        );
+        let root = Message::create(
+            &mut fbb,
+            &MessageArgs {
+                timestamp: 0,
+                content_type: MessageContent::SpeakerEvent,
+                content: Some(event.as_union_value()),
+            },
+        );
+        fbb.finish(root, None);
+        fbb.finished_data().to_vec()
+    }
+
+    #[test]
+    fn own_volumes_keep_the_ramp_going() {
+        let ramp = ramp(vec![20.0, 30.0]);
+
+        assert!(!ends(
+            &ramp,
+            &construct_set_music_volume_command(20.0, "kitchen")
+        ));
+        assert!(!ends(
+            &ramp,
+            &construct_set_music_volume_command(30.0, "kitchen")
+        ));
+        assert!(ramp.sent_volumes.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn someone_elses_volume_ends_the_ramp() {
+        let ramp = ramp(vec![20.0]);
+
+        assert!(ends(
+            &ramp,
+            &construct_set_music_volume_command(45.0, "kitchen")
+        ));
+    }
+
+    #[test]
+    fn a_volume_seen_once_isnt_the_ramps_twice() {
+        let ramp = ramp(vec![20.0]);
+
+        assert!(!ends(
+            &ramp,
+            &construct_set_music_volume_command(20.0, "kitchen")
+        ));
+        assert!(ends(
+            &ramp,
+            &construct_set_music_volume_command(20.0, "kitchen")
+        ));
+    }
+
+    #[test]
+    fn sleep_timer_firing_ends_the_ramp() {
+        assert!(ends(&ramp(Vec::new()), &sleep_timer_fired()));
+    }
+}
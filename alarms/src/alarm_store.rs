@@ -0,0 +1,79 @@
+use crate::alarm::ScheduledAlarm;
+
+const DEFAULT_ALARMS_PATH: &str = "/config/alarms.json";
+
+/// Writes `contents` to a tmp file renamed over `path` once complete, so a crash mid-save
+/// leaves only the tmp file behind.
+fn write_atomically(path: &str, contents: &str) -> std::io::Result<()> {
+    let tmp_path = format!("{}.tmp", path);
+    std::fs::write(&tmp_path, contents).and_then(|_| std::fs::rename(&tmp_path, path))
+}
+
+/// The alarms, persisted as JSON in `ALARMS_PATH`.
+pub struct AlarmStore {
+    path: String,
+    alarms: Vec<ScheduledAlarm>,
+}
+
+impl AlarmStore {
+    pub fn load() -> Result<Self, String> {
+        let path = std::env::var("ALARMS_PATH").unwrap_or_else(|_| DEFAULT_ALARMS_PATH.to_string());
+
+        let alarms = match std::fs::read_to_string(&path) {
+            Ok(json) => serde_json::from_str(&json)
+                .map_err(|e| format!("Error parsing alarms {}: {}", path, e))?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+            Err(e) => return Err(format!("Error reading alarms {}: {}", path, e)),
+        };
+
+        Ok(Self { path, alarms })
+    }
+
+    pub fn alarms(&self) -> &[ScheduledAlarm] {
+        &self.alarms
+    }
+
+    /// Replaces the alarm with the same id, or adds it with a new id if it has none.
+    pub fn upsert(&mut self, mut alarm: ScheduledAlarm) -> Result<(), String> {
+        if alarm.id.is_empty() {
+            alarm.id = self.next_id();
+        }
+
+        match self
+            .alarms
+            .iter_mut()
+            .find(|existing| existing.id == alarm.id)
+        {
+            Some(existing) => *existing = alarm,
+            None => self.alarms.push(alarm),
+        }
+        self.save()
+    }
+
+    pub fn delete(&mut self, id: &str) -> Result<(), String> {
+        let count = self.alarms.len();
+        self.alarms.retain(|alarm| alarm.id != id);
+        if self.alarms.len() == count {
+            return Err(format!("Alarm {} does not exist", id));
+        }
+        self.save()
+    }
+
+    fn next_id(&self) -> String {
+        let time_since_epoch = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap();
+        let mut id = format!("alarm-{}", time_since_epoch.as_millis());
+        // Two alarms created in the same millisecond
+        while self.alarms.iter().any(|alarm| alarm.id == id) {
+            id += "-1";
+        }
+        id
+    }
+
+    fn save(&self) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(&self.alarms).map_err(|e| e.to_string())?;
+        write_atomically(&self.path, &json)
+            .map_err(|e| format!("Error writing alarms {}: {}", self.path, e))
+    }
+}
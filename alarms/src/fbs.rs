@@ -0,0 +1,245 @@
+use flatbuffers::{FlatBufferBuilder, WIPOffset};
+use nats::Connection;
+
+use crate::{
+    alarm::ScheduledAlarm, Alarm, AlarmArgs, AlarmEvent, AlarmEventArgs, AlarmEventContent,
+    AlarmFired, AlarmFiredArgs, AlarmsChanged, AlarmsChangedArgs, Error, ErrorArgs, InsertSong,
+    InsertSongArgs, Message, MessageArgs, MessageContent, PlaylistCommand, PlaylistCommandArgs,
+    PlaylistCommandContent, SetMusicVolume, SetMusicVolumeArgs, Song, SongArgs, SpeakerCommand,
+    SpeakerCommandArgs, SpeakerCommandContent,
+};
+
+pub trait NcSendable {
+    fn send(self, nc: &Connection, topic: &str);
+}
+
+impl NcSendable for Result<Option<Vec<u8>>, String> {
+    fn send(self, nc: &Connection, topic: &str) {
+        match self {
+            Ok(Some(event)) => {
+                nc.publish(topic, event).unwrap();
+            }
+            Ok(None) => {}
+            Err(err) => {
+                let error_message = construct_error_message(err);
+                nc.publish("error", error_message).unwrap();
+            }
+        }
+    }
+}
+
+fn get_current_timestamp() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
+
+pub fn construct_error_message(error: String) -> Vec<u8> {
+    let mut fbb = flatbuffers::FlatBufferBuilder::new();
+    let error_str = fbb.create_string(&error);
+    let from_str = fbb.create_string("alarms");
+
+    let error = Error::create(
+        &mut fbb,
+        &ErrorArgs {
+            from: Some(from_str),
+            message: Some(error_str),
+            ..Default::default()
+        },
+    );
+
+    let timestamp = get_current_timestamp();
+
+    let root = Message::create(
+        &mut fbb,
+        &MessageArgs {
+            timestamp,
+            content_type: MessageContent::Error,
+            content: Some(error.as_union_value()),
+        },
+    );
+    fbb.finish(root, None);
+
+    fbb.finished_data().to_vec()
+}
+
+pub fn construct_set_music_volume_command(volume: f32, device_id: &str) -> Vec<u8> {
+    let mut fbb = flatbuffers::FlatBufferBuilder::new();
+
+    let set_music_volume = SetMusicVolume::create(&mut fbb, &SetMusicVolumeArgs { volume });
+
+    let device_id_str = fbb.create_string(device_id);
+
+    let command = SpeakerCommand::create(
+        &mut fbb,
+        &SpeakerCommandArgs {
+            device_id: Some(device_id_str),
+            command_type: SpeakerCommandContent::SetMusicVolume,
+            command: Some(set_music_volume.as_union_value()),
+        },
+    );
+
+    let timestamp = get_current_timestamp();
+
+    let root = Message::create(
+        &mut fbb,
+        &MessageArgs {
+            timestamp,
+            content_type: MessageContent::SpeakerCommand,
+            content: Some(command.as_union_value()),
+        },
+    );
+    fbb.finish(root, None);
+
+    fbb.finished_data().to_vec()
+}
+
+/// Puts the alarm's url in front of the device's queue, so playlists plays it and moves on to
+/// the rest of the queue once it has finished.
+pub fn construct_insert_alarm_song_command(alarm: &ScheduledAlarm) -> Vec<u8> {
+    let mut fbb = flatbuffers::FlatBufferBuilder::new();
+    let url_str = fbb.create_string(&alarm.url);
+    let title_str = fbb.create_string(&format!("Alarm {}", alarm.id));
+    let thumbnail_str = fbb.create_string("");
+
+    let song = Song::create(
+        &mut fbb,
+        &SongArgs {
+            url: Some(url_str),
+            title: Some(title_str),
+            thumbnail_b64: Some(thumbnail_str),
+            id: None,
+            // Played once, not held by repeat one or kept in the rotation of repeat all
+            one_shot: true,
+        },
+    );
+    let insert_song = InsertSong::create(
+        &mut fbb,
+        &InsertSongArgs {
+            index: 0,
+            song: Some(song),
+            before_id: None,
+        },
+    );
+
+    let device_id_str = fbb.create_string(&alarm.device_id);
+
+    let command = PlaylistCommand::create(
+        &mut fbb,
+        &PlaylistCommandArgs {
+            device_id: Some(device_id_str),
+            command_type: PlaylistCommandContent::InsertSong,
+            command: Some(insert_song.as_union_value()),
+            ..Default::default()
+        },
+    );
+
+    let timestamp = get_current_timestamp();
+
+    let root = Message::create(
+        &mut fbb,
+        &MessageArgs {
+            timestamp,
+            content_type: MessageContent::PlaylistCommand,
+            content: Some(command.as_union_value()),
+        },
+    );
+    fbb.finish(root, None);
+
+    fbb.finished_data().to_vec()
+}
+
+fn create_alarm<'a>(
+    fbb: &mut FlatBufferBuilder<'a>,
+    alarm: &ScheduledAlarm,
+) -> WIPOffset<Alarm<'a>> {
+    let id = fbb.create_string(&alarm.id);
+    let device_id = fbb.create_string(&alarm.device_id);
+    let days = fbb.create_vector(&alarm.days);
+    let url = fbb.create_string(&alarm.url);
+
+    Alarm::create(
+        fbb,
+        &AlarmArgs {
+            id: Some(id),
+            device_id: Some(device_id),
+            days: Some(days),
+            hour: alarm.hour,
+            minute: alarm.minute,
+            url: Some(url),
+            volume: alarm.volume,
+            ramp_volume: alarm.ramp_volume,
+            ramp_duration: alarm.ramp_duration,
+            enabled: alarm.enabled,
+        },
+    )
+}
+
+pub fn construct_alarms_changed_event_message(alarms: &[ScheduledAlarm]) -> Vec<u8> {
+    let mut fbb = flatbuffers::FlatBufferBuilder::new();
+
+    let alarms: Vec<_> = alarms
+        .iter()
+        .map(|alarm| create_alarm(&mut fbb, alarm))
+        .collect();
+    let alarms = fbb.create_vector(&alarms);
+
+    let alarms_changed = AlarmsChanged::create(
+        &mut fbb,
+        &AlarmsChangedArgs {
+            alarms: Some(alarms),
+        },
+    );
+
+    let alarm_event = AlarmEvent::create(
+        &mut fbb,
+        &AlarmEventArgs {
+            event_type: AlarmEventContent::AlarmsChanged,
+            event: Some(alarms_changed.as_union_value()),
+        },
+    );
+
+    let timestamp = get_current_timestamp();
+
+    let root = Message::create(
+        &mut fbb,
+        &MessageArgs {
+            timestamp,
+            content_type: MessageContent::AlarmEvent,
+            content: Some(alarm_event.as_union_value()),
+        },
+    );
+    fbb.finish(root, None);
+
+    fbb.finished_data().to_vec()
+}
+
+pub fn construct_alarm_fired_event_message(alarm: &ScheduledAlarm) -> Vec<u8> {
+    let mut fbb = flatbuffers::FlatBufferBuilder::new();
+
+    let alarm = create_alarm(&mut fbb, alarm);
+    let alarm_fired = AlarmFired::create(&mut fbb, &AlarmFiredArgs { alarm: Some(alarm) });
+
+    let alarm_event = AlarmEvent::create(
+        &mut fbb,
+        &AlarmEventArgs {
+            event_type: AlarmEventContent::AlarmFired,
+            event: Some(alarm_fired.as_union_value()),
+        },
+    );
+
+    let timestamp = get_current_timestamp();
+
+    let root = Message::create(
+        &mut fbb,
+        &MessageArgs {
+            timestamp,
+            content_type: MessageContent::AlarmEvent,
+            content: Some(alarm_event.as_union_value()),
+        },
+    );
+    fbb.finish(root, None);
+
+    fbb.finished_data().to_vec()
+}
@@ -0,0 +1,97 @@
+extern crate flatbuffers;
+
+#[allow(dead_code, unused_imports, warnings, clippy::all)]
+#[path = "./schemas/msg_playlists_generated.rs"]
+mod msg_playlists_generated;
+
+#[allow(dead_code, unused_imports, warnings, clippy::all)]
+#[path = "./schemas/msg_speakers_generated.rs"]
+mod msg_speakers_generated;
+
+#[allow(dead_code, unused_imports, warnings, clippy::all)]
+#[path = "./schemas/msg_echo_generated.rs"]
+mod msg_echo_generated;
+
+#[allow(dead_code, unused_imports, warnings, clippy::all)]
+#[path = "./schemas/msg_print_generated.rs"]
+mod msg_print_generated;
+
+#[allow(dead_code, unused_imports, warnings, clippy::all)]
+#[path = "./schemas/msg_error_generated.rs"]
+mod msg_error_generated;
+
+#[allow(dead_code, unused_imports, warnings, clippy::all)]
+#[path = "./schemas/msg_alarms_generated.rs"]
+mod msg_alarms_generated;
+
+#[allow(dead_code, unused_imports, warnings, clippy::all)]
+#[path = "./schemas/root_generated.rs"]
+mod root_generated;
+
+pub mod alarm;
+pub mod alarm_handler;
+pub mod alarm_store;
+pub mod fbs;
+
+use std::{
+    panic::{self, AssertUnwindSafe},
+    sync::{Arc, Mutex, PoisonError},
+    thread,
+};
+
+use alarm_handler::{make_scheduler_thread, AlarmHandler};
+use fbs::NcSendable;
+pub use msg_alarms_generated::*;
+pub use msg_echo_generated::*;
+pub use msg_error_generated::*;
+pub use msg_playlists_generated::*;
+pub use msg_print_generated::*;
+pub use msg_speakers_generated::*;
+pub use root_generated::*;
+
+/// Runs one message through the handler, sending back whatever went wrong so a bad message
+/// can never stop the alarms from firing.
+fn handle_safely(
+    nc: &nats::Connection,
+    alarm_handler: &Mutex<AlarmHandler>,
+    handle: impl FnOnce(&mut AlarmHandler) -> Result<Option<Vec<u8>>, String>,
+) {
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        // A panic on an earlier message poisons the lock, the alarms themselves are still fine
+        let mut alarm_handler = alarm_handler.lock().unwrap_or_else(PoisonError::into_inner);
+        handle(&mut alarm_handler)
+    }));
+    result
+        .unwrap_or_else(|_| Err("Panicked while handling a message".to_string()))
+        .send(nc, "alarm.event");
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let nc = Arc::new(nats::connect("nats://nats-server:4222")?);
+    let alarm_handler = Arc::new(Mutex::new(AlarmHandler::new(nc.clone())?));
+    make_scheduler_thread(Arc::clone(&alarm_handler));
+    //listen for messages on all subjects
+    let sub = nc.subscribe("alarm.*")?;
+    // Ramps stop once their alarm song ends, is stopped or gets replaced
+    let sub_speaker_event = nc.subscribe("speaker.event")?;
+    let sub_speaker_command = nc.subscribe("speaker.command")?;
+
+    for sub_speaker in [sub_speaker_event, sub_speaker_command] {
+        let nc = nc.clone();
+        let alarm_handler = alarm_handler.clone();
+        thread::spawn(move || {
+            for msg in sub_speaker.messages() {
+                handle_safely(&nc, &alarm_handler, |alarm_handler| {
+                    alarm_handler.handle_speaker_message(&msg.data)
+                });
+            }
+        });
+    }
+
+    for msg in sub.messages() {
+        handle_safely(&nc, &alarm_handler, |alarm_handler| {
+            alarm_handler.handle_alarm_message(&msg.data)
+        });
+    }
+    Ok(())
+}
@@ -0,0 +1,7729 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+
+// @generated
+
+use core::mem;
+use core::cmp::Ordering;
+
+extern crate flatbuffers;
+use self::flatbuffers::{EndianScalar, Follow};
+
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_PLAY_CONTENT: u8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_PLAY_CONTENT: u8 = 5;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_PLAY_CONTENT: [PlayContent; 6] = [
+  PlayContent::NONE,
+  PlayContent::PlayYoutube,
+  PlayContent::PlayWav,
+  PlayContent::PlayLocalFile,
+  PlayContent::PlayStream,
+  PlayContent::PlayDlna,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct PlayContent(pub u8);
+#[allow(non_upper_case_globals)]
+impl PlayContent {
+  pub const NONE: Self = Self(0);
+  pub const PlayYoutube: Self = Self(1);
+  pub const PlayWav: Self = Self(2);
+  pub const PlayLocalFile: Self = Self(3);
+  pub const PlayStream: Self = Self(4);
+  pub const PlayDlna: Self = Self(5);
+
+  pub const ENUM_MIN: u8 = 0;
+  pub const ENUM_MAX: u8 = 5;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::NONE,
+    Self::PlayYoutube,
+    Self::PlayWav,
+    Self::PlayLocalFile,
+    Self::PlayStream,
+    Self::PlayDlna,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::NONE => Some("NONE"),
+      Self::PlayYoutube => Some("PlayYoutube"),
+      Self::PlayWav => Some("PlayWav"),
+      Self::PlayLocalFile => Some("PlayLocalFile"),
+      Self::PlayStream => Some("PlayStream"),
+      Self::PlayDlna => Some("PlayDlna"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for PlayContent {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for PlayContent {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = flatbuffers::read_scalar_at::<u8>(buf, loc);
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for PlayContent {
+    type Output = PlayContent;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<u8>(dst, self.0);
+    }
+}
+
+impl flatbuffers::EndianScalar for PlayContent {
+  type Scalar = u8;
+  #[inline]
+  fn to_little_endian(self) -> u8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: u8) -> Self {
+    let b = u8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for PlayContent {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    u8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for PlayContent {}
+pub struct PlayContentUnionTableOffset {}
+
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_SPEAKER_COMMAND_CONTENT: u8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_SPEAKER_COMMAND_CONTENT: u8 = 20;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_SPEAKER_COMMAND_CONTENT: [SpeakerCommandContent; 21] = [
+  SpeakerCommandContent::NONE,
+  SpeakerCommandContent::SetMusicVolume,
+  SpeakerCommandContent::TogglePause,
+  SpeakerCommandContent::Play,
+  SpeakerCommandContent::Stop,
+  SpeakerCommandContent::Seek,
+  SpeakerCommandContent::Preload,
+  SpeakerCommandContent::SetCrossfade,
+  SpeakerCommandContent::CreateSpeakerGroup,
+  SpeakerCommandContent::DissolveSpeakerGroup,
+  SpeakerCommandContent::PlayAnnouncement,
+  SpeakerCommandContent::SetDucking,
+  SpeakerCommandContent::UpdateSpeakerInfo,
+  SpeakerCommandContent::ReloadDeviceFilter,
+  SpeakerCommandContent::SetHardwareVolume,
+  SpeakerCommandContent::SetMute,
+  SpeakerCommandContent::SetSpeed,
+  SpeakerCommandContent::SeekRelative,
+  SpeakerCommandContent::SetAudioFilter,
+  SpeakerCommandContent::SetSleepTimer,
+  SpeakerCommandContent::CancelSleepTimer,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct SpeakerCommandContent(pub u8);
+#[allow(non_upper_case_globals)]
+impl SpeakerCommandContent {
+  pub const NONE: Self = Self(0);
+  pub const SetMusicVolume: Self = Self(1);
+  pub const TogglePause: Self = Self(2);
+  pub const Play: Self = Self(3);
+  pub const Stop: Self = Self(4);
+  pub const Seek: Self = Self(5);
+  pub const Preload: Self = Self(6);
+  pub const SetCrossfade: Self = Self(7);
+  pub const CreateSpeakerGroup: Self = Self(8);
+  pub const DissolveSpeakerGroup: Self = Self(9);
+  pub const PlayAnnouncement: Self = Self(10);
+  pub const SetDucking: Self = Self(11);
+  pub const UpdateSpeakerInfo: Self = Self(12);
+  pub const ReloadDeviceFilter: Self = Self(13);
+  pub const SetHardwareVolume: Self = Self(14);
+  pub const SetMute: Self = Self(15);
+  pub const SetSpeed: Self = Self(16);
+  pub const SeekRelative: Self = Self(17);
+  pub const SetAudioFilter: Self = Self(18);
+  pub const SetSleepTimer: Self = Self(19);
+  pub const CancelSleepTimer: Self = Self(20);
+
+  pub const ENUM_MIN: u8 = 0;
+  pub const ENUM_MAX: u8 = 20;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::NONE,
+    Self::SetMusicVolume,
+    Self::TogglePause,
+    Self::Play,
+    Self::Stop,
+    Self::Seek,
+    Self::Preload,
+    Self::SetCrossfade,
+    Self::CreateSpeakerGroup,
+    Self::DissolveSpeakerGroup,
+    Self::PlayAnnouncement,
+    Self::SetDucking,
+    Self::UpdateSpeakerInfo,
+    Self::ReloadDeviceFilter,
+    Self::SetHardwareVolume,
+    Self::SetMute,
+    Self::SetSpeed,
+    Self::SeekRelative,
+    Self::SetAudioFilter,
+    Self::SetSleepTimer,
+    Self::CancelSleepTimer,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::NONE => Some("NONE"),
+      Self::SetMusicVolume => Some("SetMusicVolume"),
+      Self::TogglePause => Some("TogglePause"),
+      Self::Play => Some("Play"),
+      Self::Stop => Some("Stop"),
+      Self::Seek => Some("Seek"),
+      Self::Preload => Some("Preload"),
+      Self::SetCrossfade => Some("SetCrossfade"),
+      Self::CreateSpeakerGroup => Some("CreateSpeakerGroup"),
+      Self::DissolveSpeakerGroup => Some("DissolveSpeakerGroup"),
+      Self::PlayAnnouncement => Some("PlayAnnouncement"),
+      Self::SetDucking => Some("SetDucking"),
+      Self::UpdateSpeakerInfo => Some("UpdateSpeakerInfo"),
+      Self::ReloadDeviceFilter => Some("ReloadDeviceFilter"),
+      Self::SetHardwareVolume => Some("SetHardwareVolume"),
+      Self::SetMute => Some("SetMute"),
+      Self::SetSpeed => Some("SetSpeed"),
+      Self::SeekRelative => Some("SeekRelative"),
+      Self::SetAudioFilter => Some("SetAudioFilter"),
+      Self::SetSleepTimer => Some("SetSleepTimer"),
+      Self::CancelSleepTimer => Some("CancelSleepTimer"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for SpeakerCommandContent {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for SpeakerCommandContent {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = flatbuffers::read_scalar_at::<u8>(buf, loc);
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for SpeakerCommandContent {
+    type Output = SpeakerCommandContent;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<u8>(dst, self.0);
+    }
+}
+
+impl flatbuffers::EndianScalar for SpeakerCommandContent {
+  type Scalar = u8;
+  #[inline]
+  fn to_little_endian(self) -> u8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: u8) -> Self {
+    let b = u8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for SpeakerCommandContent {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    u8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for SpeakerCommandContent {}
+pub struct SpeakerCommandContentUnionTableOffset {}
+
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_SPEAKER_QUERY_CONTENT: u8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_SPEAKER_QUERY_CONTENT: u8 = 7;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_SPEAKER_QUERY_CONTENT: [SpeakerQueryContent; 8] = [
+  SpeakerQueryContent::NONE,
+  SpeakerQueryContent::QueryMusicVolume,
+  SpeakerQueryContent::QueryPause,
+  SpeakerQueryContent::QueryPlay,
+  SpeakerQueryContent::QuerySeek,
+  SpeakerQueryContent::QueryDuration,
+  SpeakerQueryContent::QueryHardwareVolume,
+  SpeakerQueryContent::QuerySleepTimer,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct SpeakerQueryContent(pub u8);
+#[allow(non_upper_case_globals)]
+impl SpeakerQueryContent {
+  pub const NONE: Self = Self(0);
+  pub const QueryMusicVolume: Self = Self(1);
+  pub const QueryPause: Self = Self(2);
+  pub const QueryPlay: Self = Self(3);
+  pub const QuerySeek: Self = Self(4);
+  pub const QueryDuration: Self = Self(5);
+  pub const QueryHardwareVolume: Self = Self(6);
+  pub const QuerySleepTimer: Self = Self(7);
+
+  pub const ENUM_MIN: u8 = 0;
+  pub const ENUM_MAX: u8 = 7;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::NONE,
+    Self::QueryMusicVolume,
+    Self::QueryPause,
+    Self::QueryPlay,
+    Self::QuerySeek,
+    Self::QueryDuration,
+    Self::QueryHardwareVolume,
+    Self::QuerySleepTimer,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::NONE => Some("NONE"),
+      Self::QueryMusicVolume => Some("QueryMusicVolume"),
+      Self::QueryPause => Some("QueryPause"),
+      Self::QueryPlay => Some("QueryPlay"),
+      Self::QuerySeek => Some("QuerySeek"),
+      Self::QueryDuration => Some("QueryDuration"),
+      Self::QueryHardwareVolume => Some("QueryHardwareVolume"),
+      Self::QuerySleepTimer => Some("QuerySleepTimer"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for SpeakerQueryContent {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for SpeakerQueryContent {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = flatbuffers::read_scalar_at::<u8>(buf, loc);
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for SpeakerQueryContent {
+    type Output = SpeakerQueryContent;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<u8>(dst, self.0);
+    }
+}
+
+impl flatbuffers::EndianScalar for SpeakerQueryContent {
+  type Scalar = u8;
+  #[inline]
+  fn to_little_endian(self) -> u8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: u8) -> Self {
+    let b = u8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for SpeakerQueryContent {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    u8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for SpeakerQueryContent {}
+pub struct SpeakerQueryContentUnionTableOffset {}
+
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_SPEAKER_EVENT_CONTENT: u8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_SPEAKER_EVENT_CONTENT: u8 = 18;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_SPEAKER_EVENT_CONTENT: [SpeakerEventContent; 19] = [
+  SpeakerEventContent::NONE,
+  SpeakerEventContent::MusicVolumeChanged,
+  SpeakerEventContent::PlayStarted,
+  SpeakerEventContent::PlayStopped,
+  SpeakerEventContent::SeekChanged,
+  SpeakerEventContent::DurationChanged,
+  SpeakerEventContent::FileEnded,
+  SpeakerEventContent::PauseChanged,
+  SpeakerEventContent::PlaybackFailed,
+  SpeakerEventContent::SpeakerGroupChanged,
+  SpeakerEventContent::AnnouncementStarted,
+  SpeakerEventContent::AnnouncementEnded,
+  SpeakerEventContent::HardwareVolumeChanged,
+  SpeakerEventContent::MuteChanged,
+  SpeakerEventContent::SpeedChanged,
+  SpeakerEventContent::AudioFilterChanged,
+  SpeakerEventContent::NowPlaying,
+  SpeakerEventContent::SleepTimerSet,
+  SpeakerEventContent::SleepTimerFired,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct SpeakerEventContent(pub u8);
+#[allow(non_upper_case_globals)]
+impl SpeakerEventContent {
+  pub const NONE: Self = Self(0);
+  pub const MusicVolumeChanged: Self = Self(1);
+  pub const PlayStarted: Self = Self(2);
+  pub const PlayStopped: Self = Self(3);
+  pub const SeekChanged: Self = Self(4);
+  pub const DurationChanged: Self = Self(5);
+  pub const FileEnded: Self = Self(6);
+  pub const PauseChanged: Self = Self(7);
+  pub const PlaybackFailed: Self = Self(8);
+  pub const SpeakerGroupChanged: Self = Self(9);
+  pub const AnnouncementStarted: Self = Self(10);
+  pub const AnnouncementEnded: Self = Self(11);
+  pub const HardwareVolumeChanged: Self = Self(12);
+  pub const MuteChanged: Self = Self(13);
+  pub const SpeedChanged: Self = Self(14);
+  pub const AudioFilterChanged: Self = Self(15);
+  pub const NowPlaying: Self = Self(16);
+  pub const SleepTimerSet: Self = Self(17);
+  pub const SleepTimerFired: Self = Self(18);
+
+  pub const ENUM_MIN: u8 = 0;
+  pub const ENUM_MAX: u8 = 18;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::NONE,
+    Self::MusicVolumeChanged,
+    Self::PlayStarted,
+    Self::PlayStopped,
+    Self::SeekChanged,
+    Self::DurationChanged,
+    Self::FileEnded,
+    Self::PauseChanged,
+    Self::PlaybackFailed,
+    Self::SpeakerGroupChanged,
+    Self::AnnouncementStarted,
+    Self::AnnouncementEnded,
+    Self::HardwareVolumeChanged,
+    Self::MuteChanged,
+    Self::SpeedChanged,
+    Self::AudioFilterChanged,
+    Self::NowPlaying,
+    Self::SleepTimerSet,
+    Self::SleepTimerFired,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::NONE => Some("NONE"),
+      Self::MusicVolumeChanged => Some("MusicVolumeChanged"),
+      Self::PlayStarted => Some("PlayStarted"),
+      Self::PlayStopped => Some("PlayStopped"),
+      Self::SeekChanged => Some("SeekChanged"),
+      Self::DurationChanged => Some("DurationChanged"),
+      Self::FileEnded => Some("FileEnded"),
+      Self::PauseChanged => Some("PauseChanged"),
+      Self::PlaybackFailed => Some("PlaybackFailed"),
+      Self::SpeakerGroupChanged => Some("SpeakerGroupChanged"),
+      Self::AnnouncementStarted => Some("AnnouncementStarted"),
+      Self::AnnouncementEnded => Some("AnnouncementEnded"),
+      Self::HardwareVolumeChanged => Some("HardwareVolumeChanged"),
+      Self::MuteChanged => Some("MuteChanged"),
+      Self::SpeedChanged => Some("SpeedChanged"),
+      Self::AudioFilterChanged => Some("AudioFilterChanged"),
+      Self::NowPlaying => Some("NowPlaying"),
+      Self::SleepTimerSet => Some("SleepTimerSet"),
+      Self::SleepTimerFired => Some("SleepTimerFired"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for SpeakerEventContent {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for SpeakerEventContent {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = flatbuffers::read_scalar_at::<u8>(buf, loc);
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for SpeakerEventContent {
+    type Output = SpeakerEventContent;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<u8>(dst, self.0);
+    }
+}
+
+impl flatbuffers::EndianScalar for SpeakerEventContent {
+  type Scalar = u8;
+  #[inline]
+  fn to_little_endian(self) -> u8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: u8) -> Self {
+    let b = u8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for SpeakerEventContent {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    u8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for SpeakerEventContent {}
+pub struct SpeakerEventContentUnionTableOffset {}
+
+pub enum SetMusicVolumeOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct SetMusicVolume<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SetMusicVolume<'a> {
+  type Inner = SetMusicVolume<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> SetMusicVolume<'a> {
+  pub const VT_VOLUME: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    SetMusicVolume { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args SetMusicVolumeArgs
+  ) -> flatbuffers::WIPOffset<SetMusicVolume<'bldr>> {
+    let mut builder = SetMusicVolumeBuilder::new(_fbb);
+    builder.add_volume(args.volume);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn volume(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(SetMusicVolume::VT_VOLUME, Some(0.0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for SetMusicVolume<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<f32>("volume", Self::VT_VOLUME, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct SetMusicVolumeArgs {
+    pub volume: f32,
+}
+impl<'a> Default for SetMusicVolumeArgs {
+  #[inline]
+  fn default() -> Self {
+    SetMusicVolumeArgs {
+      volume: 0.0,
+    }
+  }
+}
+
+pub struct SetMusicVolumeBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> SetMusicVolumeBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_volume(&mut self, volume: f32) {
+    self.fbb_.push_slot::<f32>(SetMusicVolume::VT_VOLUME, volume, 0.0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> SetMusicVolumeBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    SetMusicVolumeBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<SetMusicVolume<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for SetMusicVolume<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("SetMusicVolume");
+      ds.field("volume", &self.volume());
+      ds.finish()
+  }
+}
+pub enum TogglePauseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct TogglePause<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for TogglePause<'a> {
+  type Inner = TogglePause<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> TogglePause<'a> {
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    TogglePause { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    _args: &'args TogglePauseArgs
+  ) -> flatbuffers::WIPOffset<TogglePause<'bldr>> {
+    let mut builder = TogglePauseBuilder::new(_fbb);
+    builder.finish()
+  }
+
+}
+
+impl flatbuffers::Verifiable for TogglePause<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct TogglePauseArgs {
+}
+impl<'a> Default for TogglePauseArgs {
+  #[inline]
+  fn default() -> Self {
+    TogglePauseArgs {
+    }
+  }
+}
+
+pub struct TogglePauseBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> TogglePauseBuilder<'a, 'b> {
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> TogglePauseBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    TogglePauseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<TogglePause<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for TogglePause<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("TogglePause");
+      ds.finish()
+  }
+}
+pub enum PlayYoutubeOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct PlayYoutube<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for PlayYoutube<'a> {
+  type Inner = PlayYoutube<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> PlayYoutube<'a> {
+  pub const VT_URL: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    PlayYoutube { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args PlayYoutubeArgs<'args>
+  ) -> flatbuffers::WIPOffset<PlayYoutube<'bldr>> {
+    let mut builder = PlayYoutubeBuilder::new(_fbb);
+    if let Some(x) = args.url { builder.add_url(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn url(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(PlayYoutube::VT_URL, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for PlayYoutube<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("url", Self::VT_URL, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct PlayYoutubeArgs<'a> {
+    pub url: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for PlayYoutubeArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    PlayYoutubeArgs {
+      url: None,
+    }
+  }
+}
+
+pub struct PlayYoutubeBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> PlayYoutubeBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_url(&mut self, url: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(PlayYoutube::VT_URL, url);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> PlayYoutubeBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    PlayYoutubeBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<PlayYoutube<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for PlayYoutube<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("PlayYoutube");
+      ds.field("url", &self.url());
+      ds.finish()
+  }
+}
+pub enum PlayWavOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct PlayWav<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for PlayWav<'a> {
+  type Inner = PlayWav<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> PlayWav<'a> {
+  pub const VT_DATA: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    PlayWav { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args PlayWavArgs<'args>
+  ) -> flatbuffers::WIPOffset<PlayWav<'bldr>> {
+    let mut builder = PlayWavBuilder::new(_fbb);
+    if let Some(x) = args.data { builder.add_data(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn data(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(PlayWav::VT_DATA, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for PlayWav<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("data", Self::VT_DATA, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct PlayWavArgs<'a> {
+    pub data: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+}
+impl<'a> Default for PlayWavArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    PlayWavArgs {
+      data: None,
+    }
+  }
+}
+
+pub struct PlayWavBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> PlayWavBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_data(&mut self, data: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(PlayWav::VT_DATA, data);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> PlayWavBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    PlayWavBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<PlayWav<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for PlayWav<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("PlayWav");
+      ds.field("data", &self.data());
+      ds.finish()
+  }
+}
+pub enum PlayLocalFileOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct PlayLocalFile<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for PlayLocalFile<'a> {
+  type Inner = PlayLocalFile<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> PlayLocalFile<'a> {
+  pub const VT_PATH: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    PlayLocalFile { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args PlayLocalFileArgs<'args>
+  ) -> flatbuffers::WIPOffset<PlayLocalFile<'bldr>> {
+    let mut builder = PlayLocalFileBuilder::new(_fbb);
+    if let Some(x) = args.path { builder.add_path(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn path(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(PlayLocalFile::VT_PATH, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for PlayLocalFile<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("path", Self::VT_PATH, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct PlayLocalFileArgs<'a> {
+    pub path: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for PlayLocalFileArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    PlayLocalFileArgs {
+      path: None,
+    }
+  }
+}
+
+pub struct PlayLocalFileBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> PlayLocalFileBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_path(&mut self, path: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(PlayLocalFile::VT_PATH, path);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> PlayLocalFileBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    PlayLocalFileBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<PlayLocalFile<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for PlayLocalFile<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("PlayLocalFile");
+      ds.field("path", &self.path());
+      ds.finish()
+  }
+}
+pub enum PlayStreamOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct PlayStream<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for PlayStream<'a> {
+  type Inner = PlayStream<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> PlayStream<'a> {
+  pub const VT_URL: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    PlayStream { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args PlayStreamArgs<'args>
+  ) -> flatbuffers::WIPOffset<PlayStream<'bldr>> {
+    let mut builder = PlayStreamBuilder::new(_fbb);
+    if let Some(x) = args.url { builder.add_url(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn url(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(PlayStream::VT_URL, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for PlayStream<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("url", Self::VT_URL, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct PlayStreamArgs<'a> {
+    pub url: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for PlayStreamArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    PlayStreamArgs {
+      url: None,
+    }
+  }
+}
+
+pub struct PlayStreamBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> PlayStreamBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_url(&mut self, url: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(PlayStream::VT_URL, url);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> PlayStreamBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    PlayStreamBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<PlayStream<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for PlayStream<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("PlayStream");
+      ds.field("url", &self.url());
+      ds.finish()
+  }
+}
+pub enum PlayDlnaOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct PlayDlna<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for PlayDlna<'a> {
+  type Inner = PlayDlna<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> PlayDlna<'a> {
+  pub const VT_URL: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    PlayDlna { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args PlayDlnaArgs<'args>
+  ) -> flatbuffers::WIPOffset<PlayDlna<'bldr>> {
+    let mut builder = PlayDlnaBuilder::new(_fbb);
+    if let Some(x) = args.url { builder.add_url(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn url(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(PlayDlna::VT_URL, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for PlayDlna<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("url", Self::VT_URL, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct PlayDlnaArgs<'a> {
+    pub url: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for PlayDlnaArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    PlayDlnaArgs {
+      url: None,
+    }
+  }
+}
+
+pub struct PlayDlnaBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> PlayDlnaBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_url(&mut self, url: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(PlayDlna::VT_URL, url);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> PlayDlnaBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    PlayDlnaBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<PlayDlna<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for PlayDlna<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("PlayDlna");
+      ds.field("url", &self.url());
+      ds.finish()
+  }
+}
+pub enum PlayOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Play<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Play<'a> {
+  type Inner = Play<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> Play<'a> {
+  pub const VT_CONTENT_TYPE: flatbuffers::VOffsetT = 4;
+  pub const VT_CONTENT: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Play { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args PlayArgs
+  ) -> flatbuffers::WIPOffset<Play<'bldr>> {
+    let mut builder = PlayBuilder::new(_fbb);
+    if let Some(x) = args.content { builder.add_content(x); }
+    builder.add_content_type(args.content_type);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn content_type(&self) -> PlayContent {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<PlayContent>(Play::VT_CONTENT_TYPE, Some(PlayContent::NONE)).unwrap()}
+  }
+  #[inline]
+  pub fn content(&self) -> Option<flatbuffers::Table<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(Play::VT_CONTENT, None)}
+  }
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn content_as_play_youtube(&self) -> Option<PlayYoutube<'a>> {
+    if self.content_type() == PlayContent::PlayYoutube {
+      self.content().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { PlayYoutube::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn content_as_play_wav(&self) -> Option<PlayWav<'a>> {
+    if self.content_type() == PlayContent::PlayWav {
+      self.content().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { PlayWav::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn content_as_play_local_file(&self) -> Option<PlayLocalFile<'a>> {
+    if self.content_type() == PlayContent::PlayLocalFile {
+      self.content().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { PlayLocalFile::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn content_as_play_stream(&self) -> Option<PlayStream<'a>> {
+    if self.content_type() == PlayContent::PlayStream {
+      self.content().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { PlayStream::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn content_as_play_dlna(&self) -> Option<PlayDlna<'a>> {
+    if self.content_type() == PlayContent::PlayDlna {
+      self.content().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { PlayDlna::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+}
+
+impl flatbuffers::Verifiable for Play<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_union::<PlayContent, _>("content_type", Self::VT_CONTENT_TYPE, "content", Self::VT_CONTENT, false, |key, v, pos| {
+        match key {
+          PlayContent::PlayYoutube => v.verify_union_variant::<flatbuffers::ForwardsUOffset<PlayYoutube>>("PlayContent::PlayYoutube", pos),
+          PlayContent::PlayWav => v.verify_union_variant::<flatbuffers::ForwardsUOffset<PlayWav>>("PlayContent::PlayWav", pos),
+          PlayContent::PlayLocalFile => v.verify_union_variant::<flatbuffers::ForwardsUOffset<PlayLocalFile>>("PlayContent::PlayLocalFile", pos),
+          PlayContent::PlayStream => v.verify_union_variant::<flatbuffers::ForwardsUOffset<PlayStream>>("PlayContent::PlayStream", pos),
+          PlayContent::PlayDlna => v.verify_union_variant::<flatbuffers::ForwardsUOffset<PlayDlna>>("PlayContent::PlayDlna", pos),
+          _ => Ok(()),
+        }
+     })?
+     .finish();
+    Ok(())
+  }
+}
+pub struct PlayArgs {
+    pub content_type: PlayContent,
+    pub content: Option<flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>>,
+}
+impl<'a> Default for PlayArgs {
+  #[inline]
+  fn default() -> Self {
+    PlayArgs {
+      content_type: PlayContent::NONE,
+      content: None,
+    }
+  }
+}
+
+pub struct PlayBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> PlayBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_content_type(&mut self, content_type: PlayContent) {
+    self.fbb_.push_slot::<PlayContent>(Play::VT_CONTENT_TYPE, content_type, PlayContent::NONE);
+  }
+  #[inline]
+  pub fn add_content(&mut self, content: flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Play::VT_CONTENT, content);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> PlayBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    PlayBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Play<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Play<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Play");
+      ds.field("content_type", &self.content_type());
+      match self.content_type() {
+        PlayContent::PlayYoutube => {
+          if let Some(x) = self.content_as_play_youtube() {
+            ds.field("content", &x)
+          } else {
+            ds.field("content", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        PlayContent::PlayWav => {
+          if let Some(x) = self.content_as_play_wav() {
+            ds.field("content", &x)
+          } else {
+            ds.field("content", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        PlayContent::PlayLocalFile => {
+          if let Some(x) = self.content_as_play_local_file() {
+            ds.field("content", &x)
+          } else {
+            ds.field("content", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        PlayContent::PlayStream => {
+          if let Some(x) = self.content_as_play_stream() {
+            ds.field("content", &x)
+          } else {
+            ds.field("content", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        PlayContent::PlayDlna => {
+          if let Some(x) = self.content_as_play_dlna() {
+            ds.field("content", &x)
+          } else {
+            ds.field("content", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        _ => {
+          let x: Option<()> = None;
+          ds.field("content", &x)
+        },
+      };
+      ds.finish()
+  }
+}
+pub enum StopOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Stop<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Stop<'a> {
+  type Inner = Stop<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> Stop<'a> {
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Stop { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    _args: &'args StopArgs
+  ) -> flatbuffers::WIPOffset<Stop<'bldr>> {
+    let mut builder = StopBuilder::new(_fbb);
+    builder.finish()
+  }
+
+}
+
+impl flatbuffers::Verifiable for Stop<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct StopArgs {
+}
+impl<'a> Default for StopArgs {
+  #[inline]
+  fn default() -> Self {
+    StopArgs {
+    }
+  }
+}
+
+pub struct StopBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> StopBuilder<'a, 'b> {
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> StopBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    StopBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Stop<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Stop<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Stop");
+      ds.finish()
+  }
+}
+pub enum SeekOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Seek<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Seek<'a> {
+  type Inner = Seek<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> Seek<'a> {
+  pub const VT_SEEK: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Seek { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args SeekArgs
+  ) -> flatbuffers::WIPOffset<Seek<'bldr>> {
+    let mut builder = SeekBuilder::new(_fbb);
+    builder.add_seek(args.seek);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn seek(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(Seek::VT_SEEK, Some(0.0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for Seek<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<f32>("seek", Self::VT_SEEK, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct SeekArgs {
+    pub seek: f32,
+}
+impl<'a> Default for SeekArgs {
+  #[inline]
+  fn default() -> Self {
+    SeekArgs {
+      seek: 0.0,
+    }
+  }
+}
+
+pub struct SeekBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> SeekBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_seek(&mut self, seek: f32) {
+    self.fbb_.push_slot::<f32>(Seek::VT_SEEK, seek, 0.0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> SeekBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    SeekBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Seek<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Seek<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Seek");
+      ds.field("seek", &self.seek());
+      ds.finish()
+  }
+}
+pub enum PreloadOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Preload<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Preload<'a> {
+  type Inner = Preload<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> Preload<'a> {
+  pub const VT_CONTENT_TYPE: flatbuffers::VOffsetT = 4;
+  pub const VT_CONTENT: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Preload { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args PreloadArgs
+  ) -> flatbuffers::WIPOffset<Preload<'bldr>> {
+    let mut builder = PreloadBuilder::new(_fbb);
+    if let Some(x) = args.content { builder.add_content(x); }
+    builder.add_content_type(args.content_type);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn content_type(&self) -> PlayContent {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<PlayContent>(Preload::VT_CONTENT_TYPE, Some(PlayContent::NONE)).unwrap()}
+  }
+  #[inline]
+  pub fn content(&self) -> Option<flatbuffers::Table<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(Preload::VT_CONTENT, None)}
+  }
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn content_as_play_youtube(&self) -> Option<PlayYoutube<'a>> {
+    if self.content_type() == PlayContent::PlayYoutube {
+      self.content().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { PlayYoutube::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn content_as_play_wav(&self) -> Option<PlayWav<'a>> {
+    if self.content_type() == PlayContent::PlayWav {
+      self.content().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { PlayWav::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn content_as_play_local_file(&self) -> Option<PlayLocalFile<'a>> {
+    if self.content_type() == PlayContent::PlayLocalFile {
+      self.content().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { PlayLocalFile::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn content_as_play_stream(&self) -> Option<PlayStream<'a>> {
+    if self.content_type() == PlayContent::PlayStream {
+      self.content().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { PlayStream::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn content_as_play_dlna(&self) -> Option<PlayDlna<'a>> {
+    if self.content_type() == PlayContent::PlayDlna {
+      self.content().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { PlayDlna::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+}
+
+impl flatbuffers::Verifiable for Preload<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_union::<PlayContent, _>("content_type", Self::VT_CONTENT_TYPE, "content", Self::VT_CONTENT, false, |key, v, pos| {
+        match key {
+          PlayContent::PlayYoutube => v.verify_union_variant::<flatbuffers::ForwardsUOffset<PlayYoutube>>("PlayContent::PlayYoutube", pos),
+          PlayContent::PlayWav => v.verify_union_variant::<flatbuffers::ForwardsUOffset<PlayWav>>("PlayContent::PlayWav", pos),
+          PlayContent::PlayLocalFile => v.verify_union_variant::<flatbuffers::ForwardsUOffset<PlayLocalFile>>("PlayContent::PlayLocalFile", pos),
+          PlayContent::PlayStream => v.verify_union_variant::<flatbuffers::ForwardsUOffset<PlayStream>>("PlayContent::PlayStream", pos),
+          PlayContent::PlayDlna => v.verify_union_variant::<flatbuffers::ForwardsUOffset<PlayDlna>>("PlayContent::PlayDlna", pos),
+          _ => Ok(()),
+        }
+     })?
+     .finish();
+    Ok(())
+  }
+}
+pub struct PreloadArgs {
+    pub content_type: PlayContent,
+    pub content: Option<flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>>,
+}
+impl<'a> Default for PreloadArgs {
+  #[inline]
+  fn default() -> Self {
+    PreloadArgs {
+      content_type: PlayContent::NONE,
+      content: None,
+    }
+  }
+}
+
+pub struct PreloadBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> PreloadBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_content_type(&mut self, content_type: PlayContent) {
+    self.fbb_.push_slot::<PlayContent>(Preload::VT_CONTENT_TYPE, content_type, PlayContent::NONE);
+  }
+  #[inline]
+  pub fn add_content(&mut self, content: flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Preload::VT_CONTENT, content);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> PreloadBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    PreloadBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Preload<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Preload<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Preload");
+      ds.field("content_type", &self.content_type());
+      match self.content_type() {
+        PlayContent::PlayYoutube => {
+          if let Some(x) = self.content_as_play_youtube() {
+            ds.field("content", &x)
+          } else {
+            ds.field("content", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        PlayContent::PlayWav => {
+          if let Some(x) = self.content_as_play_wav() {
+            ds.field("content", &x)
+          } else {
+            ds.field("content", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        PlayContent::PlayLocalFile => {
+          if let Some(x) = self.content_as_play_local_file() {
+            ds.field("content", &x)
+          } else {
+            ds.field("content", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        PlayContent::PlayStream => {
+          if let Some(x) = self.content_as_play_stream() {
+            ds.field("content", &x)
+          } else {
+            ds.field("content", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        PlayContent::PlayDlna => {
+          if let Some(x) = self.content_as_play_dlna() {
+            ds.field("content", &x)
+          } else {
+            ds.field("content", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        _ => {
+          let x: Option<()> = None;
+          ds.field("content", &x)
+        },
+      };
+      ds.finish()
+  }
+}
+pub enum SetCrossfadeOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct SetCrossfade<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SetCrossfade<'a> {
+  type Inner = SetCrossfade<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> SetCrossfade<'a> {
+  pub const VT_DURATION: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    SetCrossfade { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args SetCrossfadeArgs
+  ) -> flatbuffers::WIPOffset<SetCrossfade<'bldr>> {
+    let mut builder = SetCrossfadeBuilder::new(_fbb);
+    builder.add_duration(args.duration);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn duration(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(SetCrossfade::VT_DURATION, Some(0.0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for SetCrossfade<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<f32>("duration", Self::VT_DURATION, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct SetCrossfadeArgs {
+    pub duration: f32,
+}
+impl<'a> Default for SetCrossfadeArgs {
+  #[inline]
+  fn default() -> Self {
+    SetCrossfadeArgs {
+      duration: 0.0,
+    }
+  }
+}
+
+pub struct SetCrossfadeBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> SetCrossfadeBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_duration(&mut self, duration: f32) {
+    self.fbb_.push_slot::<f32>(SetCrossfade::VT_DURATION, duration, 0.0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> SetCrossfadeBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    SetCrossfadeBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<SetCrossfade<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for SetCrossfade<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("SetCrossfade");
+      ds.field("duration", &self.duration());
+      ds.finish()
+  }
+}
+pub enum CreateSpeakerGroupOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct CreateSpeakerGroup<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for CreateSpeakerGroup<'a> {
+  type Inner = CreateSpeakerGroup<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> CreateSpeakerGroup<'a> {
+  pub const VT_DEVICE_IDS: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    CreateSpeakerGroup { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args CreateSpeakerGroupArgs<'args>
+  ) -> flatbuffers::WIPOffset<CreateSpeakerGroup<'bldr>> {
+    let mut builder = CreateSpeakerGroupBuilder::new(_fbb);
+    if let Some(x) = args.device_ids { builder.add_device_ids(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn device_ids(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>(CreateSpeakerGroup::VT_DEVICE_IDS, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for CreateSpeakerGroup<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>>>("device_ids", Self::VT_DEVICE_IDS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct CreateSpeakerGroupArgs<'a> {
+    pub device_ids: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>,
+}
+impl<'a> Default for CreateSpeakerGroupArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    CreateSpeakerGroupArgs {
+      device_ids: None,
+    }
+  }
+}
+
+pub struct CreateSpeakerGroupBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> CreateSpeakerGroupBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_device_ids(&mut self, device_ids: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CreateSpeakerGroup::VT_DEVICE_IDS, device_ids);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> CreateSpeakerGroupBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    CreateSpeakerGroupBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<CreateSpeakerGroup<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for CreateSpeakerGroup<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("CreateSpeakerGroup");
+      ds.field("device_ids", &self.device_ids());
+      ds.finish()
+  }
+}
+pub enum DissolveSpeakerGroupOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct DissolveSpeakerGroup<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for DissolveSpeakerGroup<'a> {
+  type Inner = DissolveSpeakerGroup<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> DissolveSpeakerGroup<'a> {
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    DissolveSpeakerGroup { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    _args: &'args DissolveSpeakerGroupArgs
+  ) -> flatbuffers::WIPOffset<DissolveSpeakerGroup<'bldr>> {
+    let mut builder = DissolveSpeakerGroupBuilder::new(_fbb);
+    builder.finish()
+  }
+
+}
+
+impl flatbuffers::Verifiable for DissolveSpeakerGroup<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct DissolveSpeakerGroupArgs {
+}
+impl<'a> Default for DissolveSpeakerGroupArgs {
+  #[inline]
+  fn default() -> Self {
+    DissolveSpeakerGroupArgs {
+    }
+  }
+}
+
+pub struct DissolveSpeakerGroupBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> DissolveSpeakerGroupBuilder<'a, 'b> {
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> DissolveSpeakerGroupBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    DissolveSpeakerGroupBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<DissolveSpeakerGroup<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for DissolveSpeakerGroup<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("DissolveSpeakerGroup");
+      ds.finish()
+  }
+}
+pub enum PlayAnnouncementOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct PlayAnnouncement<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for PlayAnnouncement<'a> {
+  type Inner = PlayAnnouncement<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> PlayAnnouncement<'a> {
+  pub const VT_CONTENT_TYPE: flatbuffers::VOffsetT = 4;
+  pub const VT_CONTENT: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    PlayAnnouncement { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args PlayAnnouncementArgs
+  ) -> flatbuffers::WIPOffset<PlayAnnouncement<'bldr>> {
+    let mut builder = PlayAnnouncementBuilder::new(_fbb);
+    if let Some(x) = args.content { builder.add_content(x); }
+    builder.add_content_type(args.content_type);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn content_type(&self) -> PlayContent {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<PlayContent>(PlayAnnouncement::VT_CONTENT_TYPE, Some(PlayContent::NONE)).unwrap()}
+  }
+  #[inline]
+  pub fn content(&self) -> Option<flatbuffers::Table<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(PlayAnnouncement::VT_CONTENT, None)}
+  }
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn content_as_play_youtube(&self) -> Option<PlayYoutube<'a>> {
+    if self.content_type() == PlayContent::PlayYoutube {
+      self.content().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { PlayYoutube::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn content_as_play_wav(&self) -> Option<PlayWav<'a>> {
+    if self.content_type() == PlayContent::PlayWav {
+      self.content().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { PlayWav::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn content_as_play_local_file(&self) -> Option<PlayLocalFile<'a>> {
+    if self.content_type() == PlayContent::PlayLocalFile {
+      self.content().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { PlayLocalFile::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn content_as_play_stream(&self) -> Option<PlayStream<'a>> {
+    if self.content_type() == PlayContent::PlayStream {
+      self.content().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { PlayStream::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn content_as_play_dlna(&self) -> Option<PlayDlna<'a>> {
+    if self.content_type() == PlayContent::PlayDlna {
+      self.content().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { PlayDlna::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+}
+
+impl flatbuffers::Verifiable for PlayAnnouncement<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_union::<PlayContent, _>("content_type", Self::VT_CONTENT_TYPE, "content", Self::VT_CONTENT, false, |key, v, pos| {
+        match key {
+          PlayContent::PlayYoutube => v.verify_union_variant::<flatbuffers::ForwardsUOffset<PlayYoutube>>("PlayContent::PlayYoutube", pos),
+          PlayContent::PlayWav => v.verify_union_variant::<flatbuffers::ForwardsUOffset<PlayWav>>("PlayContent::PlayWav", pos),
+          PlayContent::PlayLocalFile => v.verify_union_variant::<flatbuffers::ForwardsUOffset<PlayLocalFile>>("PlayContent::PlayLocalFile", pos),
+          PlayContent::PlayStream => v.verify_union_variant::<flatbuffers::ForwardsUOffset<PlayStream>>("PlayContent::PlayStream", pos),
+          PlayContent::PlayDlna => v.verify_union_variant::<flatbuffers::ForwardsUOffset<PlayDlna>>("PlayContent::PlayDlna", pos),
+          _ => Ok(()),
+        }
+     })?
+     .finish();
+    Ok(())
+  }
+}
+pub struct PlayAnnouncementArgs {
+    pub content_type: PlayContent,
+    pub content: Option<flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>>,
+}
+impl<'a> Default for PlayAnnouncementArgs {
+  #[inline]
+  fn default() -> Self {
+    PlayAnnouncementArgs {
+      content_type: PlayContent::NONE,
+      content: None,
+    }
+  }
+}
+
+pub struct PlayAnnouncementBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> PlayAnnouncementBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_content_type(&mut self, content_type: PlayContent) {
+    self.fbb_.push_slot::<PlayContent>(PlayAnnouncement::VT_CONTENT_TYPE, content_type, PlayContent::NONE);
+  }
+  #[inline]
+  pub fn add_content(&mut self, content: flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(PlayAnnouncement::VT_CONTENT, content);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> PlayAnnouncementBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    PlayAnnouncementBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<PlayAnnouncement<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for PlayAnnouncement<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("PlayAnnouncement");
+      ds.field("content_type", &self.content_type());
+      match self.content_type() {
+        PlayContent::PlayYoutube => {
+          if let Some(x) = self.content_as_play_youtube() {
+            ds.field("content", &x)
+          } else {
+            ds.field("content", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        PlayContent::PlayWav => {
+          if let Some(x) = self.content_as_play_wav() {
+            ds.field("content", &x)
+          } else {
+            ds.field("content", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        PlayContent::PlayLocalFile => {
+          if let Some(x) = self.content_as_play_local_file() {
+            ds.field("content", &x)
+          } else {
+            ds.field("content", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        PlayContent::PlayStream => {
+          if let Some(x) = self.content_as_play_stream() {
+            ds.field("content", &x)
+          } else {
+            ds.field("content", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        PlayContent::PlayDlna => {
+          if let Some(x) = self.content_as_play_dlna() {
+            ds.field("content", &x)
+          } else {
+            ds.field("content", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        _ => {
+          let x: Option<()> = None;
+          ds.field("content", &x)
+        },
+      };
+      ds.finish()
+  }
+}
+pub enum SetDuckingOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct SetDucking<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SetDucking<'a> {
+  type Inner = SetDucking<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> SetDucking<'a> {
+  pub const VT_AMOUNT: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    SetDucking { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args SetDuckingArgs
+  ) -> flatbuffers::WIPOffset<SetDucking<'bldr>> {
+    let mut builder = SetDuckingBuilder::new(_fbb);
+    builder.add_amount(args.amount);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn amount(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(SetDucking::VT_AMOUNT, Some(0.0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for SetDucking<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<f32>("amount", Self::VT_AMOUNT, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct SetDuckingArgs {
+    pub amount: f32,
+}
+impl<'a> Default for SetDuckingArgs {
+  #[inline]
+  fn default() -> Self {
+    SetDuckingArgs {
+      amount: 0.0,
+    }
+  }
+}
+
+pub struct SetDuckingBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> SetDuckingBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_amount(&mut self, amount: f32) {
+    self.fbb_.push_slot::<f32>(SetDucking::VT_AMOUNT, amount, 0.0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> SetDuckingBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    SetDuckingBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<SetDucking<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for SetDucking<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("SetDucking");
+      ds.field("amount", &self.amount());
+      ds.finish()
+  }
+}
+pub enum UpdateSpeakerInfoOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct UpdateSpeakerInfo<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for UpdateSpeakerInfo<'a> {
+  type Inner = UpdateSpeakerInfo<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> UpdateSpeakerInfo<'a> {
+  pub const VT_NAME: flatbuffers::VOffsetT = 4;
+  pub const VT_ROOM: flatbuffers::VOffsetT = 6;
+  pub const VT_ICON: flatbuffers::VOffsetT = 8;
+  pub const VT_DEFAULT_VOLUME: flatbuffers::VOffsetT = 10;
+  pub const VT_HIDDEN: flatbuffers::VOffsetT = 12;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    UpdateSpeakerInfo { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args UpdateSpeakerInfoArgs<'args>
+  ) -> flatbuffers::WIPOffset<UpdateSpeakerInfo<'bldr>> {
+    let mut builder = UpdateSpeakerInfoBuilder::new(_fbb);
+    builder.add_default_volume(args.default_volume);
+    if let Some(x) = args.icon { builder.add_icon(x); }
+    if let Some(x) = args.room { builder.add_room(x); }
+    if let Some(x) = args.name { builder.add_name(x); }
+    builder.add_hidden(args.hidden);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn name(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(UpdateSpeakerInfo::VT_NAME, None)}
+  }
+  #[inline]
+  pub fn room(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(UpdateSpeakerInfo::VT_ROOM, None)}
+  }
+  #[inline]
+  pub fn icon(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(UpdateSpeakerInfo::VT_ICON, None)}
+  }
+  #[inline]
+  pub fn default_volume(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(UpdateSpeakerInfo::VT_DEFAULT_VOLUME, Some(-1.0)).unwrap()}
+  }
+  #[inline]
+  pub fn hidden(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(UpdateSpeakerInfo::VT_HIDDEN, Some(false)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for UpdateSpeakerInfo<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("room", Self::VT_ROOM, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("icon", Self::VT_ICON, false)?
+     .visit_field::<f32>("default_volume", Self::VT_DEFAULT_VOLUME, false)?
+     .visit_field::<bool>("hidden", Self::VT_HIDDEN, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct UpdateSpeakerInfoArgs<'a> {
+    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub room: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub icon: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub default_volume: f32,
+    pub hidden: bool,
+}
+impl<'a> Default for UpdateSpeakerInfoArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    UpdateSpeakerInfoArgs {
+      name: None,
+      room: None,
+      icon: None,
+      default_volume: -1.0,
+      hidden: false,
+    }
+  }
+}
+
+pub struct UpdateSpeakerInfoBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> UpdateSpeakerInfoBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(UpdateSpeakerInfo::VT_NAME, name);
+  }
+  #[inline]
+  pub fn add_room(&mut self, room: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(UpdateSpeakerInfo::VT_ROOM, room);
+  }
+  #[inline]
+  pub fn add_icon(&mut self, icon: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(UpdateSpeakerInfo::VT_ICON, icon);
+  }
+  #[inline]
+  pub fn add_default_volume(&mut self, default_volume: f32) {
+    self.fbb_.push_slot::<f32>(UpdateSpeakerInfo::VT_DEFAULT_VOLUME, default_volume, -1.0);
+  }
+  #[inline]
+  pub fn add_hidden(&mut self, hidden: bool) {
+    self.fbb_.push_slot::<bool>(UpdateSpeakerInfo::VT_HIDDEN, hidden, false);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> UpdateSpeakerInfoBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    UpdateSpeakerInfoBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<UpdateSpeakerInfo<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for UpdateSpeakerInfo<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("UpdateSpeakerInfo");
+      ds.field("name", &self.name());
+      ds.field("room", &self.room());
+      ds.field("icon", &self.icon());
+      ds.field("default_volume", &self.default_volume());
+      ds.field("hidden", &self.hidden());
+      ds.finish()
+  }
+}
+pub enum ReloadDeviceFilterOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ReloadDeviceFilter<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ReloadDeviceFilter<'a> {
+  type Inner = ReloadDeviceFilter<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> ReloadDeviceFilter<'a> {
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    ReloadDeviceFilter { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    _args: &'args ReloadDeviceFilterArgs
+  ) -> flatbuffers::WIPOffset<ReloadDeviceFilter<'bldr>> {
+    let mut builder = ReloadDeviceFilterBuilder::new(_fbb);
+    builder.finish()
+  }
+
+}
+
+impl flatbuffers::Verifiable for ReloadDeviceFilter<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ReloadDeviceFilterArgs {
+}
+impl<'a> Default for ReloadDeviceFilterArgs {
+  #[inline]
+  fn default() -> Self {
+    ReloadDeviceFilterArgs {
+    }
+  }
+}
+
+pub struct ReloadDeviceFilterBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> ReloadDeviceFilterBuilder<'a, 'b> {
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ReloadDeviceFilterBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    ReloadDeviceFilterBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<ReloadDeviceFilter<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for ReloadDeviceFilter<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("ReloadDeviceFilter");
+      ds.finish()
+  }
+}
+pub enum SetHardwareVolumeOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct SetHardwareVolume<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SetHardwareVolume<'a> {
+  type Inner = SetHardwareVolume<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> SetHardwareVolume<'a> {
+  pub const VT_VOLUME: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    SetHardwareVolume { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args SetHardwareVolumeArgs
+  ) -> flatbuffers::WIPOffset<SetHardwareVolume<'bldr>> {
+    let mut builder = SetHardwareVolumeBuilder::new(_fbb);
+    builder.add_volume(args.volume);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn volume(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(SetHardwareVolume::VT_VOLUME, Some(0.0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for SetHardwareVolume<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<f32>("volume", Self::VT_VOLUME, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct SetHardwareVolumeArgs {
+    pub volume: f32,
+}
+impl<'a> Default for SetHardwareVolumeArgs {
+  #[inline]
+  fn default() -> Self {
+    SetHardwareVolumeArgs {
+      volume: 0.0,
+    }
+  }
+}
+
+pub struct SetHardwareVolumeBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> SetHardwareVolumeBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_volume(&mut self, volume: f32) {
+    self.fbb_.push_slot::<f32>(SetHardwareVolume::VT_VOLUME, volume, 0.0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> SetHardwareVolumeBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    SetHardwareVolumeBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<SetHardwareVolume<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for SetHardwareVolume<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("SetHardwareVolume");
+      ds.field("volume", &self.volume());
+      ds.finish()
+  }
+}
+pub enum SetMuteOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct SetMute<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SetMute<'a> {
+  type Inner = SetMute<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> SetMute<'a> {
+  pub const VT_MUTED: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    SetMute { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args SetMuteArgs
+  ) -> flatbuffers::WIPOffset<SetMute<'bldr>> {
+    let mut builder = SetMuteBuilder::new(_fbb);
+    builder.add_muted(args.muted);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn muted(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(SetMute::VT_MUTED, Some(false)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for SetMute<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<bool>("muted", Self::VT_MUTED, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct SetMuteArgs {
+    pub muted: bool,
+}
+impl<'a> Default for SetMuteArgs {
+  #[inline]
+  fn default() -> Self {
+    SetMuteArgs {
+      muted: false,
+    }
+  }
+}
+
+pub struct SetMuteBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> SetMuteBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_muted(&mut self, muted: bool) {
+    self.fbb_.push_slot::<bool>(SetMute::VT_MUTED, muted, false);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> SetMuteBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    SetMuteBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<SetMute<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for SetMute<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("SetMute");
+      ds.field("muted", &self.muted());
+      ds.finish()
+  }
+}
+pub enum SetSpeedOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct SetSpeed<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SetSpeed<'a> {
+  type Inner = SetSpeed<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> SetSpeed<'a> {
+  pub const VT_SPEED: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    SetSpeed { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args SetSpeedArgs
+  ) -> flatbuffers::WIPOffset<SetSpeed<'bldr>> {
+    let mut builder = SetSpeedBuilder::new(_fbb);
+    builder.add_speed(args.speed);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn speed(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(SetSpeed::VT_SPEED, Some(0.0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for SetSpeed<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<f32>("speed", Self::VT_SPEED, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct SetSpeedArgs {
+    pub speed: f32,
+}
+impl<'a> Default for SetSpeedArgs {
+  #[inline]
+  fn default() -> Self {
+    SetSpeedArgs {
+      speed: 0.0,
+    }
+  }
+}
+
+pub struct SetSpeedBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> SetSpeedBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_speed(&mut self, speed: f32) {
+    self.fbb_.push_slot::<f32>(SetSpeed::VT_SPEED, speed, 0.0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> SetSpeedBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    SetSpeedBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<SetSpeed<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for SetSpeed<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("SetSpeed");
+      ds.field("speed", &self.speed());
+      ds.finish()
+  }
+}
+pub enum SeekRelativeOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct SeekRelative<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SeekRelative<'a> {
+  type Inner = SeekRelative<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> SeekRelative<'a> {
+  pub const VT_OFFSET: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    SeekRelative { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args SeekRelativeArgs
+  ) -> flatbuffers::WIPOffset<SeekRelative<'bldr>> {
+    let mut builder = SeekRelativeBuilder::new(_fbb);
+    builder.add_offset(args.offset);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn offset(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(SeekRelative::VT_OFFSET, Some(0.0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for SeekRelative<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<f32>("offset", Self::VT_OFFSET, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct SeekRelativeArgs {
+    pub offset: f32,
+}
+impl<'a> Default for SeekRelativeArgs {
+  #[inline]
+  fn default() -> Self {
+    SeekRelativeArgs {
+      offset: 0.0,
+    }
+  }
+}
+
+pub struct SeekRelativeBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> SeekRelativeBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_offset(&mut self, offset: f32) {
+    self.fbb_.push_slot::<f32>(SeekRelative::VT_OFFSET, offset, 0.0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> SeekRelativeBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    SeekRelativeBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<SeekRelative<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for SeekRelative<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("SeekRelative");
+      ds.field("offset", &self.offset());
+      ds.finish()
+  }
+}
+pub enum SetAudioFilterOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct SetAudioFilter<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SetAudioFilter<'a> {
+  type Inner = SetAudioFilter<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> SetAudioFilter<'a> {
+  pub const VT_PRESET: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    SetAudioFilter { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args SetAudioFilterArgs<'args>
+  ) -> flatbuffers::WIPOffset<SetAudioFilter<'bldr>> {
+    let mut builder = SetAudioFilterBuilder::new(_fbb);
+    if let Some(x) = args.preset { builder.add_preset(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn preset(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(SetAudioFilter::VT_PRESET, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for SetAudioFilter<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("preset", Self::VT_PRESET, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct SetAudioFilterArgs<'a> {
+    pub preset: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for SetAudioFilterArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    SetAudioFilterArgs {
+      preset: None,
+    }
+  }
+}
+
+pub struct SetAudioFilterBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> SetAudioFilterBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_preset(&mut self, preset: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SetAudioFilter::VT_PRESET, preset);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> SetAudioFilterBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    SetAudioFilterBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<SetAudioFilter<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for SetAudioFilter<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("SetAudioFilter");
+      ds.field("preset", &self.preset());
+      ds.finish()
+  }
+}
+pub enum SetSleepTimerOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct SetSleepTimer<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SetSleepTimer<'a> {
+  type Inner = SetSleepTimer<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> SetSleepTimer<'a> {
+  pub const VT_DURATION: flatbuffers::VOffsetT = 4;
+  pub const VT_FADE: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    SetSleepTimer { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args SetSleepTimerArgs
+  ) -> flatbuffers::WIPOffset<SetSleepTimer<'bldr>> {
+    let mut builder = SetSleepTimerBuilder::new(_fbb);
+    builder.add_fade(args.fade);
+    builder.add_duration(args.duration);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn duration(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(SetSleepTimer::VT_DURATION, Some(0.0)).unwrap()}
+  }
+  #[inline]
+  pub fn fade(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(SetSleepTimer::VT_FADE, Some(30.0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for SetSleepTimer<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<f32>("duration", Self::VT_DURATION, false)?
+     .visit_field::<f32>("fade", Self::VT_FADE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct SetSleepTimerArgs {
+    pub duration: f32,
+    pub fade: f32,
+}
+impl<'a> Default for SetSleepTimerArgs {
+  #[inline]
+  fn default() -> Self {
+    SetSleepTimerArgs {
+      duration: 0.0,
+      fade: 30.0,
+    }
+  }
+}
+
+pub struct SetSleepTimerBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> SetSleepTimerBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_duration(&mut self, duration: f32) {
+    self.fbb_.push_slot::<f32>(SetSleepTimer::VT_DURATION, duration, 0.0);
+  }
+  #[inline]
+  pub fn add_fade(&mut self, fade: f32) {
+    self.fbb_.push_slot::<f32>(SetSleepTimer::VT_FADE, fade, 30.0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> SetSleepTimerBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    SetSleepTimerBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<SetSleepTimer<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for SetSleepTimer<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("SetSleepTimer");
+      ds.field("duration", &self.duration());
+      ds.field("fade", &self.fade());
+      ds.finish()
+  }
+}
+pub enum CancelSleepTimerOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct CancelSleepTimer<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for CancelSleepTimer<'a> {
+  type Inner = CancelSleepTimer<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> CancelSleepTimer<'a> {
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    CancelSleepTimer { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    _args: &'args CancelSleepTimerArgs
+  ) -> flatbuffers::WIPOffset<CancelSleepTimer<'bldr>> {
+    let mut builder = CancelSleepTimerBuilder::new(_fbb);
+    builder.finish()
+  }
+
+}
+
+impl flatbuffers::Verifiable for CancelSleepTimer<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct CancelSleepTimerArgs {
+}
+impl<'a> Default for CancelSleepTimerArgs {
+  #[inline]
+  fn default() -> Self {
+    CancelSleepTimerArgs {
+    }
+  }
+}
+
+pub struct CancelSleepTimerBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> CancelSleepTimerBuilder<'a, 'b> {
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> CancelSleepTimerBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    CancelSleepTimerBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<CancelSleepTimer<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for CancelSleepTimer<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("CancelSleepTimer");
+      ds.finish()
+  }
+}
+pub enum QueryMusicVolumeOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct QueryMusicVolume<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for QueryMusicVolume<'a> {
+  type Inner = QueryMusicVolume<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> QueryMusicVolume<'a> {
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    QueryMusicVolume { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    _args: &'args QueryMusicVolumeArgs
+  ) -> flatbuffers::WIPOffset<QueryMusicVolume<'bldr>> {
+    let mut builder = QueryMusicVolumeBuilder::new(_fbb);
+    builder.finish()
+  }
+
+}
+
+impl flatbuffers::Verifiable for QueryMusicVolume<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct QueryMusicVolumeArgs {
+}
+impl<'a> Default for QueryMusicVolumeArgs {
+  #[inline]
+  fn default() -> Self {
+    QueryMusicVolumeArgs {
+    }
+  }
+}
+
+pub struct QueryMusicVolumeBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> QueryMusicVolumeBuilder<'a, 'b> {
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> QueryMusicVolumeBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    QueryMusicVolumeBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<QueryMusicVolume<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for QueryMusicVolume<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("QueryMusicVolume");
+      ds.finish()
+  }
+}
+pub enum QueryPauseOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct QueryPause<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for QueryPause<'a> {
+  type Inner = QueryPause<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> QueryPause<'a> {
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    QueryPause { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    _args: &'args QueryPauseArgs
+  ) -> flatbuffers::WIPOffset<QueryPause<'bldr>> {
+    let mut builder = QueryPauseBuilder::new(_fbb);
+    builder.finish()
+  }
+
+}
+
+impl flatbuffers::Verifiable for QueryPause<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct QueryPauseArgs {
+}
+impl<'a> Default for QueryPauseArgs {
+  #[inline]
+  fn default() -> Self {
+    QueryPauseArgs {
+    }
+  }
+}
+
+pub struct QueryPauseBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> QueryPauseBuilder<'a, 'b> {
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> QueryPauseBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    QueryPauseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<QueryPause<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for QueryPause<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("QueryPause");
+      ds.finish()
+  }
+}
+pub enum QueryPlayOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct QueryPlay<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for QueryPlay<'a> {
+  type Inner = QueryPlay<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> QueryPlay<'a> {
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    QueryPlay { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    _args: &'args QueryPlayArgs
+  ) -> flatbuffers::WIPOffset<QueryPlay<'bldr>> {
+    let mut builder = QueryPlayBuilder::new(_fbb);
+    builder.finish()
+  }
+
+}
+
+impl flatbuffers::Verifiable for QueryPlay<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct QueryPlayArgs {
+}
+impl<'a> Default for QueryPlayArgs {
+  #[inline]
+  fn default() -> Self {
+    QueryPlayArgs {
+    }
+  }
+}
+
+pub struct QueryPlayBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> QueryPlayBuilder<'a, 'b> {
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> QueryPlayBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    QueryPlayBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<QueryPlay<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for QueryPlay<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("QueryPlay");
+      ds.finish()
+  }
+}
+pub enum QuerySeekOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct QuerySeek<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for QuerySeek<'a> {
+  type Inner = QuerySeek<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> QuerySeek<'a> {
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    QuerySeek { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    _args: &'args QuerySeekArgs
+  ) -> flatbuffers::WIPOffset<QuerySeek<'bldr>> {
+    let mut builder = QuerySeekBuilder::new(_fbb);
+    builder.finish()
+  }
+
+}
+
+impl flatbuffers::Verifiable for QuerySeek<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct QuerySeekArgs {
+}
+impl<'a> Default for QuerySeekArgs {
+  #[inline]
+  fn default() -> Self {
+    QuerySeekArgs {
+    }
+  }
+}
+
+pub struct QuerySeekBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> QuerySeekBuilder<'a, 'b> {
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> QuerySeekBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    QuerySeekBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<QuerySeek<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for QuerySeek<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("QuerySeek");
+      ds.finish()
+  }
+}
+pub enum QueryDurationOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct QueryDuration<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for QueryDuration<'a> {
+  type Inner = QueryDuration<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> QueryDuration<'a> {
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    QueryDuration { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    _args: &'args QueryDurationArgs
+  ) -> flatbuffers::WIPOffset<QueryDuration<'bldr>> {
+    let mut builder = QueryDurationBuilder::new(_fbb);
+    builder.finish()
+  }
+
+}
+
+impl flatbuffers::Verifiable for QueryDuration<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct QueryDurationArgs {
+}
+impl<'a> Default for QueryDurationArgs {
+  #[inline]
+  fn default() -> Self {
+    QueryDurationArgs {
+    }
+  }
+}
+
+pub struct QueryDurationBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> QueryDurationBuilder<'a, 'b> {
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> QueryDurationBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    QueryDurationBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<QueryDuration<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for QueryDuration<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("QueryDuration");
+      ds.finish()
+  }
+}
+pub enum QueryHardwareVolumeOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct QueryHardwareVolume<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for QueryHardwareVolume<'a> {
+  type Inner = QueryHardwareVolume<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> QueryHardwareVolume<'a> {
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    QueryHardwareVolume { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    _args: &'args QueryHardwareVolumeArgs
+  ) -> flatbuffers::WIPOffset<QueryHardwareVolume<'bldr>> {
+    let mut builder = QueryHardwareVolumeBuilder::new(_fbb);
+    builder.finish()
+  }
+
+}
+
+impl flatbuffers::Verifiable for QueryHardwareVolume<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct QueryHardwareVolumeArgs {
+}
+impl<'a> Default for QueryHardwareVolumeArgs {
+  #[inline]
+  fn default() -> Self {
+    QueryHardwareVolumeArgs {
+    }
+  }
+}
+
+pub struct QueryHardwareVolumeBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> QueryHardwareVolumeBuilder<'a, 'b> {
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> QueryHardwareVolumeBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    QueryHardwareVolumeBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<QueryHardwareVolume<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for QueryHardwareVolume<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("QueryHardwareVolume");
+      ds.finish()
+  }
+}
+pub enum QuerySleepTimerOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct QuerySleepTimer<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for QuerySleepTimer<'a> {
+  type Inner = QuerySleepTimer<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> QuerySleepTimer<'a> {
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    QuerySleepTimer { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    _args: &'args QuerySleepTimerArgs
+  ) -> flatbuffers::WIPOffset<QuerySleepTimer<'bldr>> {
+    let mut builder = QuerySleepTimerBuilder::new(_fbb);
+    builder.finish()
+  }
+
+}
+
+impl flatbuffers::Verifiable for QuerySleepTimer<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct QuerySleepTimerArgs {
+}
+impl<'a> Default for QuerySleepTimerArgs {
+  #[inline]
+  fn default() -> Self {
+    QuerySleepTimerArgs {
+    }
+  }
+}
+
+pub struct QuerySleepTimerBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> QuerySleepTimerBuilder<'a, 'b> {
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> QuerySleepTimerBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    QuerySleepTimerBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<QuerySleepTimer<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for QuerySleepTimer<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("QuerySleepTimer");
+      ds.finish()
+  }
+}
+pub enum MusicVolumeChangedOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct MusicVolumeChanged<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for MusicVolumeChanged<'a> {
+  type Inner = MusicVolumeChanged<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> MusicVolumeChanged<'a> {
+  pub const VT_VOLUME: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    MusicVolumeChanged { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args MusicVolumeChangedArgs
+  ) -> flatbuffers::WIPOffset<MusicVolumeChanged<'bldr>> {
+    let mut builder = MusicVolumeChangedBuilder::new(_fbb);
+    builder.add_volume(args.volume);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn volume(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(MusicVolumeChanged::VT_VOLUME, Some(0.0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for MusicVolumeChanged<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<f32>("volume", Self::VT_VOLUME, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct MusicVolumeChangedArgs {
+    pub volume: f32,
+}
+impl<'a> Default for MusicVolumeChangedArgs {
+  #[inline]
+  fn default() -> Self {
+    MusicVolumeChangedArgs {
+      volume: 0.0,
+    }
+  }
+}
+
+pub struct MusicVolumeChangedBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> MusicVolumeChangedBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_volume(&mut self, volume: f32) {
+    self.fbb_.push_slot::<f32>(MusicVolumeChanged::VT_VOLUME, volume, 0.0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> MusicVolumeChangedBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    MusicVolumeChangedBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<MusicVolumeChanged<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for MusicVolumeChanged<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("MusicVolumeChanged");
+      ds.field("volume", &self.volume());
+      ds.finish()
+  }
+}
+pub enum PlayStartedOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct PlayStarted<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for PlayStarted<'a> {
+  type Inner = PlayStarted<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> PlayStarted<'a> {
+  pub const VT_CONTENT_TYPE: flatbuffers::VOffsetT = 4;
+  pub const VT_CONTENT: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    PlayStarted { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args PlayStartedArgs
+  ) -> flatbuffers::WIPOffset<PlayStarted<'bldr>> {
+    let mut builder = PlayStartedBuilder::new(_fbb);
+    if let Some(x) = args.content { builder.add_content(x); }
+    builder.add_content_type(args.content_type);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn content_type(&self) -> PlayContent {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<PlayContent>(PlayStarted::VT_CONTENT_TYPE, Some(PlayContent::NONE)).unwrap()}
+  }
+  #[inline]
+  pub fn content(&self) -> Option<flatbuffers::Table<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(PlayStarted::VT_CONTENT, None)}
+  }
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn content_as_play_youtube(&self) -> Option<PlayYoutube<'a>> {
+    if self.content_type() == PlayContent::PlayYoutube {
+      self.content().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { PlayYoutube::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn content_as_play_wav(&self) -> Option<PlayWav<'a>> {
+    if self.content_type() == PlayContent::PlayWav {
+      self.content().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { PlayWav::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn content_as_play_local_file(&self) -> Option<PlayLocalFile<'a>> {
+    if self.content_type() == PlayContent::PlayLocalFile {
+      self.content().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { PlayLocalFile::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn content_as_play_stream(&self) -> Option<PlayStream<'a>> {
+    if self.content_type() == PlayContent::PlayStream {
+      self.content().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { PlayStream::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn content_as_play_dlna(&self) -> Option<PlayDlna<'a>> {
+    if self.content_type() == PlayContent::PlayDlna {
+      self.content().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { PlayDlna::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+}
+
+impl flatbuffers::Verifiable for PlayStarted<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_union::<PlayContent, _>("content_type", Self::VT_CONTENT_TYPE, "content", Self::VT_CONTENT, false, |key, v, pos| {
+        match key {
+          PlayContent::PlayYoutube => v.verify_union_variant::<flatbuffers::ForwardsUOffset<PlayYoutube>>("PlayContent::PlayYoutube", pos),
+          PlayContent::PlayWav => v.verify_union_variant::<flatbuffers::ForwardsUOffset<PlayWav>>("PlayContent::PlayWav", pos),
+          PlayContent::PlayLocalFile => v.verify_union_variant::<flatbuffers::ForwardsUOffset<PlayLocalFile>>("PlayContent::PlayLocalFile", pos),
+          PlayContent::PlayStream => v.verify_union_variant::<flatbuffers::ForwardsUOffset<PlayStream>>("PlayContent::PlayStream", pos),
+          PlayContent::PlayDlna => v.verify_union_variant::<flatbuffers::ForwardsUOffset<PlayDlna>>("PlayContent::PlayDlna", pos),
+          _ => Ok(()),
+        }
+     })?
+     .finish();
+    Ok(())
+  }
+}
+pub struct PlayStartedArgs {
+    pub content_type: PlayContent,
+    pub content: Option<flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>>,
+}
+impl<'a> Default for PlayStartedArgs {
+  #[inline]
+  fn default() -> Self {
+    PlayStartedArgs {
+      content_type: PlayContent::NONE,
+      content: None,
+    }
+  }
+}
+
+pub struct PlayStartedBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> PlayStartedBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_content_type(&mut self, content_type: PlayContent) {
+    self.fbb_.push_slot::<PlayContent>(PlayStarted::VT_CONTENT_TYPE, content_type, PlayContent::NONE);
+  }
+  #[inline]
+  pub fn add_content(&mut self, content: flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(PlayStarted::VT_CONTENT, content);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> PlayStartedBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    PlayStartedBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<PlayStarted<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for PlayStarted<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("PlayStarted");
+      ds.field("content_type", &self.content_type());
+      match self.content_type() {
+        PlayContent::PlayYoutube => {
+          if let Some(x) = self.content_as_play_youtube() {
+            ds.field("content", &x)
+          } else {
+            ds.field("content", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        PlayContent::PlayWav => {
+          if let Some(x) = self.content_as_play_wav() {
+            ds.field("content", &x)
+          } else {
+            ds.field("content", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        PlayContent::PlayLocalFile => {
+          if let Some(x) = self.content_as_play_local_file() {
+            ds.field("content", &x)
+          } else {
+            ds.field("content", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        PlayContent::PlayStream => {
+          if let Some(x) = self.content_as_play_stream() {
+            ds.field("content", &x)
+          } else {
+            ds.field("content", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        PlayContent::PlayDlna => {
+          if let Some(x) = self.content_as_play_dlna() {
+            ds.field("content", &x)
+          } else {
+            ds.field("content", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        _ => {
+          let x: Option<()> = None;
+          ds.field("content", &x)
+        },
+      };
+      ds.finish()
+  }
+}
+pub enum PlayStoppedOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct PlayStopped<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for PlayStopped<'a> {
+  type Inner = PlayStopped<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> PlayStopped<'a> {
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    PlayStopped { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    _args: &'args PlayStoppedArgs
+  ) -> flatbuffers::WIPOffset<PlayStopped<'bldr>> {
+    let mut builder = PlayStoppedBuilder::new(_fbb);
+    builder.finish()
+  }
+
+}
+
+impl flatbuffers::Verifiable for PlayStopped<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct PlayStoppedArgs {
+}
+impl<'a> Default for PlayStoppedArgs {
+  #[inline]
+  fn default() -> Self {
+    PlayStoppedArgs {
+    }
+  }
+}
+
+pub struct PlayStoppedBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> PlayStoppedBuilder<'a, 'b> {
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> PlayStoppedBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    PlayStoppedBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<PlayStopped<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for PlayStopped<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("PlayStopped");
+      ds.finish()
+  }
+}
+pub enum SeekChangedOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct SeekChanged<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SeekChanged<'a> {
+  type Inner = SeekChanged<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> SeekChanged<'a> {
+  pub const VT_SEEK: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    SeekChanged { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args SeekChangedArgs
+  ) -> flatbuffers::WIPOffset<SeekChanged<'bldr>> {
+    let mut builder = SeekChangedBuilder::new(_fbb);
+    builder.add_seek(args.seek);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn seek(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(SeekChanged::VT_SEEK, Some(0.0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for SeekChanged<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<f32>("seek", Self::VT_SEEK, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct SeekChangedArgs {
+    pub seek: f32,
+}
+impl<'a> Default for SeekChangedArgs {
+  #[inline]
+  fn default() -> Self {
+    SeekChangedArgs {
+      seek: 0.0,
+    }
+  }
+}
+
+pub struct SeekChangedBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> SeekChangedBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_seek(&mut self, seek: f32) {
+    self.fbb_.push_slot::<f32>(SeekChanged::VT_SEEK, seek, 0.0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> SeekChangedBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    SeekChangedBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<SeekChanged<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for SeekChanged<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("SeekChanged");
+      ds.field("seek", &self.seek());
+      ds.finish()
+  }
+}
+pub enum DurationChangedOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct DurationChanged<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for DurationChanged<'a> {
+  type Inner = DurationChanged<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> DurationChanged<'a> {
+  pub const VT_DURATION: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    DurationChanged { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args DurationChangedArgs
+  ) -> flatbuffers::WIPOffset<DurationChanged<'bldr>> {
+    let mut builder = DurationChangedBuilder::new(_fbb);
+    builder.add_duration(args.duration);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn duration(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(DurationChanged::VT_DURATION, Some(0.0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for DurationChanged<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<f32>("duration", Self::VT_DURATION, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct DurationChangedArgs {
+    pub duration: f32,
+}
+impl<'a> Default for DurationChangedArgs {
+  #[inline]
+  fn default() -> Self {
+    DurationChangedArgs {
+      duration: 0.0,
+    }
+  }
+}
+
+pub struct DurationChangedBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> DurationChangedBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_duration(&mut self, duration: f32) {
+    self.fbb_.push_slot::<f32>(DurationChanged::VT_DURATION, duration, 0.0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> DurationChangedBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    DurationChangedBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<DurationChanged<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for DurationChanged<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("DurationChanged");
+      ds.field("duration", &self.duration());
+      ds.finish()
+  }
+}
+pub enum FileEndedOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct FileEnded<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for FileEnded<'a> {
+  type Inner = FileEnded<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> FileEnded<'a> {
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    FileEnded { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    _args: &'args FileEndedArgs
+  ) -> flatbuffers::WIPOffset<FileEnded<'bldr>> {
+    let mut builder = FileEndedBuilder::new(_fbb);
+    builder.finish()
+  }
+
+}
+
+impl flatbuffers::Verifiable for FileEnded<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct FileEndedArgs {
+}
+impl<'a> Default for FileEndedArgs {
+  #[inline]
+  fn default() -> Self {
+    FileEndedArgs {
+    }
+  }
+}
+
+pub struct FileEndedBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> FileEndedBuilder<'a, 'b> {
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> FileEndedBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    FileEndedBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<FileEnded<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for FileEnded<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("FileEnded");
+      ds.finish()
+  }
+}
+pub enum PauseChangedOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct PauseChanged<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for PauseChanged<'a> {
+  type Inner = PauseChanged<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> PauseChanged<'a> {
+  pub const VT_PAUSED: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    PauseChanged { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args PauseChangedArgs
+  ) -> flatbuffers::WIPOffset<PauseChanged<'bldr>> {
+    let mut builder = PauseChangedBuilder::new(_fbb);
+    builder.add_paused(args.paused);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn paused(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(PauseChanged::VT_PAUSED, Some(false)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for PauseChanged<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<bool>("paused", Self::VT_PAUSED, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct PauseChangedArgs {
+    pub paused: bool,
+}
+impl<'a> Default for PauseChangedArgs {
+  #[inline]
+  fn default() -> Self {
+    PauseChangedArgs {
+      paused: false,
+    }
+  }
+}
+
+pub struct PauseChangedBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> PauseChangedBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_paused(&mut self, paused: bool) {
+    self.fbb_.push_slot::<bool>(PauseChanged::VT_PAUSED, paused, false);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> PauseChangedBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    PauseChangedBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<PauseChanged<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for PauseChanged<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("PauseChanged");
+      ds.field("paused", &self.paused());
+      ds.finish()
+  }
+}
+pub enum SpeakerGroupChangedOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct SpeakerGroupChanged<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SpeakerGroupChanged<'a> {
+  type Inner = SpeakerGroupChanged<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> SpeakerGroupChanged<'a> {
+  pub const VT_DEVICE_IDS: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    SpeakerGroupChanged { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args SpeakerGroupChangedArgs<'args>
+  ) -> flatbuffers::WIPOffset<SpeakerGroupChanged<'bldr>> {
+    let mut builder = SpeakerGroupChangedBuilder::new(_fbb);
+    if let Some(x) = args.device_ids { builder.add_device_ids(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn device_ids(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>(SpeakerGroupChanged::VT_DEVICE_IDS, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for SpeakerGroupChanged<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>>>("device_ids", Self::VT_DEVICE_IDS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct SpeakerGroupChangedArgs<'a> {
+    pub device_ids: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>,
+}
+impl<'a> Default for SpeakerGroupChangedArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    SpeakerGroupChangedArgs {
+      device_ids: None,
+    }
+  }
+}
+
+pub struct SpeakerGroupChangedBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> SpeakerGroupChangedBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_device_ids(&mut self, device_ids: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SpeakerGroupChanged::VT_DEVICE_IDS, device_ids);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> SpeakerGroupChangedBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    SpeakerGroupChangedBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<SpeakerGroupChanged<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for SpeakerGroupChanged<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("SpeakerGroupChanged");
+      ds.field("device_ids", &self.device_ids());
+      ds.finish()
+  }
+}
+pub enum HardwareVolumeChangedOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct HardwareVolumeChanged<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for HardwareVolumeChanged<'a> {
+  type Inner = HardwareVolumeChanged<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> HardwareVolumeChanged<'a> {
+  pub const VT_VOLUME: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    HardwareVolumeChanged { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args HardwareVolumeChangedArgs
+  ) -> flatbuffers::WIPOffset<HardwareVolumeChanged<'bldr>> {
+    let mut builder = HardwareVolumeChangedBuilder::new(_fbb);
+    builder.add_volume(args.volume);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn volume(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(HardwareVolumeChanged::VT_VOLUME, Some(0.0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for HardwareVolumeChanged<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<f32>("volume", Self::VT_VOLUME, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct HardwareVolumeChangedArgs {
+    pub volume: f32,
+}
+impl<'a> Default for HardwareVolumeChangedArgs {
+  #[inline]
+  fn default() -> Self {
+    HardwareVolumeChangedArgs {
+      volume: 0.0,
+    }
+  }
+}
+
+pub struct HardwareVolumeChangedBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> HardwareVolumeChangedBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_volume(&mut self, volume: f32) {
+    self.fbb_.push_slot::<f32>(HardwareVolumeChanged::VT_VOLUME, volume, 0.0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> HardwareVolumeChangedBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    HardwareVolumeChangedBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<HardwareVolumeChanged<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for HardwareVolumeChanged<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("HardwareVolumeChanged");
+      ds.field("volume", &self.volume());
+      ds.finish()
+  }
+}
+pub enum NowPlayingOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct NowPlaying<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for NowPlaying<'a> {
+  type Inner = NowPlaying<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> NowPlaying<'a> {
+  pub const VT_TITLE: flatbuffers::VOffsetT = 4;
+  pub const VT_STREAM_TITLE: flatbuffers::VOffsetT = 6;
+  pub const VT_CHAPTER: flatbuffers::VOffsetT = 8;
+  pub const VT_CHAPTERS: flatbuffers::VOffsetT = 10;
+  pub const VT_CHAPTER_TITLE: flatbuffers::VOffsetT = 12;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    NowPlaying { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args NowPlayingArgs<'args>
+  ) -> flatbuffers::WIPOffset<NowPlaying<'bldr>> {
+    let mut builder = NowPlayingBuilder::new(_fbb);
+    if let Some(x) = args.chapter_title { builder.add_chapter_title(x); }
+    builder.add_chapters(args.chapters);
+    builder.add_chapter(args.chapter);
+    if let Some(x) = args.stream_title { builder.add_stream_title(x); }
+    if let Some(x) = args.title { builder.add_title(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn title(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(NowPlaying::VT_TITLE, None)}
+  }
+  #[inline]
+  pub fn stream_title(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(NowPlaying::VT_STREAM_TITLE, None)}
+  }
+  #[inline]
+  pub fn chapter(&self) -> i32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i32>(NowPlaying::VT_CHAPTER, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn chapters(&self) -> i32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i32>(NowPlaying::VT_CHAPTERS, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn chapter_title(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(NowPlaying::VT_CHAPTER_TITLE, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for NowPlaying<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("title", Self::VT_TITLE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("stream_title", Self::VT_STREAM_TITLE, false)?
+     .visit_field::<i32>("chapter", Self::VT_CHAPTER, false)?
+     .visit_field::<i32>("chapters", Self::VT_CHAPTERS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("chapter_title", Self::VT_CHAPTER_TITLE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct NowPlayingArgs<'a> {
+    pub title: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub stream_title: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub chapter: i32,
+    pub chapters: i32,
+    pub chapter_title: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for NowPlayingArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    NowPlayingArgs {
+      title: None,
+      stream_title: None,
+      chapter: 0,
+      chapters: 0,
+      chapter_title: None,
+    }
+  }
+}
+
+pub struct NowPlayingBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> NowPlayingBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_title(&mut self, title: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(NowPlaying::VT_TITLE, title);
+  }
+  #[inline]
+  pub fn add_stream_title(&mut self, stream_title: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(NowPlaying::VT_STREAM_TITLE, stream_title);
+  }
+  #[inline]
+  pub fn add_chapter(&mut self, chapter: i32) {
+    self.fbb_.push_slot::<i32>(NowPlaying::VT_CHAPTER, chapter, 0);
+  }
+  #[inline]
+  pub fn add_chapters(&mut self, chapters: i32) {
+    self.fbb_.push_slot::<i32>(NowPlaying::VT_CHAPTERS, chapters, 0);
+  }
+  #[inline]
+  pub fn add_chapter_title(&mut self, chapter_title: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(NowPlaying::VT_CHAPTER_TITLE, chapter_title);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> NowPlayingBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    NowPlayingBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<NowPlaying<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for NowPlaying<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("NowPlaying");
+      ds.field("title", &self.title());
+      ds.field("stream_title", &self.stream_title());
+      ds.field("chapter", &self.chapter());
+      ds.field("chapters", &self.chapters());
+      ds.field("chapter_title", &self.chapter_title());
+      ds.finish()
+  }
+}
+pub enum MuteChangedOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct MuteChanged<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for MuteChanged<'a> {
+  type Inner = MuteChanged<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> MuteChanged<'a> {
+  pub const VT_MUTED: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    MuteChanged { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args MuteChangedArgs
+  ) -> flatbuffers::WIPOffset<MuteChanged<'bldr>> {
+    let mut builder = MuteChangedBuilder::new(_fbb);
+    builder.add_muted(args.muted);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn muted(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(MuteChanged::VT_MUTED, Some(false)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for MuteChanged<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<bool>("muted", Self::VT_MUTED, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct MuteChangedArgs {
+    pub muted: bool,
+}
+impl<'a> Default for MuteChangedArgs {
+  #[inline]
+  fn default() -> Self {
+    MuteChangedArgs {
+      muted: false,
+    }
+  }
+}
+
+pub struct MuteChangedBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> MuteChangedBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_muted(&mut self, muted: bool) {
+    self.fbb_.push_slot::<bool>(MuteChanged::VT_MUTED, muted, false);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> MuteChangedBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    MuteChangedBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<MuteChanged<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for MuteChanged<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("MuteChanged");
+      ds.field("muted", &self.muted());
+      ds.finish()
+  }
+}
+pub enum SpeedChangedOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct SpeedChanged<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SpeedChanged<'a> {
+  type Inner = SpeedChanged<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> SpeedChanged<'a> {
+  pub const VT_SPEED: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    SpeedChanged { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args SpeedChangedArgs
+  ) -> flatbuffers::WIPOffset<SpeedChanged<'bldr>> {
+    let mut builder = SpeedChangedBuilder::new(_fbb);
+    builder.add_speed(args.speed);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn speed(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(SpeedChanged::VT_SPEED, Some(0.0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for SpeedChanged<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<f32>("speed", Self::VT_SPEED, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct SpeedChangedArgs {
+    pub speed: f32,
+}
+impl<'a> Default for SpeedChangedArgs {
+  #[inline]
+  fn default() -> Self {
+    SpeedChangedArgs {
+      speed: 0.0,
+    }
+  }
+}
+
+pub struct SpeedChangedBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> SpeedChangedBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_speed(&mut self, speed: f32) {
+    self.fbb_.push_slot::<f32>(SpeedChanged::VT_SPEED, speed, 0.0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> SpeedChangedBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    SpeedChangedBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<SpeedChanged<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for SpeedChanged<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("SpeedChanged");
+      ds.field("speed", &self.speed());
+      ds.finish()
+  }
+}
+pub enum AudioFilterChangedOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct AudioFilterChanged<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for AudioFilterChanged<'a> {
+  type Inner = AudioFilterChanged<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> AudioFilterChanged<'a> {
+  pub const VT_PRESET: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    AudioFilterChanged { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args AudioFilterChangedArgs<'args>
+  ) -> flatbuffers::WIPOffset<AudioFilterChanged<'bldr>> {
+    let mut builder = AudioFilterChangedBuilder::new(_fbb);
+    if let Some(x) = args.preset { builder.add_preset(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn preset(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(AudioFilterChanged::VT_PRESET, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for AudioFilterChanged<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("preset", Self::VT_PRESET, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct AudioFilterChangedArgs<'a> {
+    pub preset: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for AudioFilterChangedArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    AudioFilterChangedArgs {
+      preset: None,
+    }
+  }
+}
+
+pub struct AudioFilterChangedBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> AudioFilterChangedBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_preset(&mut self, preset: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(AudioFilterChanged::VT_PRESET, preset);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> AudioFilterChangedBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    AudioFilterChangedBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<AudioFilterChanged<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for AudioFilterChanged<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("AudioFilterChanged");
+      ds.field("preset", &self.preset());
+      ds.finish()
+  }
+}
+pub enum AnnouncementStartedOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct AnnouncementStarted<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for AnnouncementStarted<'a> {
+  type Inner = AnnouncementStarted<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> AnnouncementStarted<'a> {
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    AnnouncementStarted { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    _args: &'args AnnouncementStartedArgs
+  ) -> flatbuffers::WIPOffset<AnnouncementStarted<'bldr>> {
+    let mut builder = AnnouncementStartedBuilder::new(_fbb);
+    builder.finish()
+  }
+
+}
+
+impl flatbuffers::Verifiable for AnnouncementStarted<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct AnnouncementStartedArgs {
+}
+impl<'a> Default for AnnouncementStartedArgs {
+  #[inline]
+  fn default() -> Self {
+    AnnouncementStartedArgs {
+    }
+  }
+}
+
+pub struct AnnouncementStartedBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> AnnouncementStartedBuilder<'a, 'b> {
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> AnnouncementStartedBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    AnnouncementStartedBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<AnnouncementStarted<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for AnnouncementStarted<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("AnnouncementStarted");
+      ds.finish()
+  }
+}
+pub enum AnnouncementEndedOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct AnnouncementEnded<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for AnnouncementEnded<'a> {
+  type Inner = AnnouncementEnded<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> AnnouncementEnded<'a> {
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    AnnouncementEnded { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    _args: &'args AnnouncementEndedArgs
+  ) -> flatbuffers::WIPOffset<AnnouncementEnded<'bldr>> {
+    let mut builder = AnnouncementEndedBuilder::new(_fbb);
+    builder.finish()
+  }
+
+}
+
+impl flatbuffers::Verifiable for AnnouncementEnded<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct AnnouncementEndedArgs {
+}
+impl<'a> Default for AnnouncementEndedArgs {
+  #[inline]
+  fn default() -> Self {
+    AnnouncementEndedArgs {
+    }
+  }
+}
+
+pub struct AnnouncementEndedBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> AnnouncementEndedBuilder<'a, 'b> {
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> AnnouncementEndedBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    AnnouncementEndedBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<AnnouncementEnded<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for AnnouncementEnded<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("AnnouncementEnded");
+      ds.finish()
+  }
+}
+pub enum SleepTimerSetOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct SleepTimerSet<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SleepTimerSet<'a> {
+  type Inner = SleepTimerSet<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> SleepTimerSet<'a> {
+  pub const VT_REMAINING: flatbuffers::VOffsetT = 4;
+  pub const VT_FADE: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    SleepTimerSet { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args SleepTimerSetArgs
+  ) -> flatbuffers::WIPOffset<SleepTimerSet<'bldr>> {
+    let mut builder = SleepTimerSetBuilder::new(_fbb);
+    builder.add_fade(args.fade);
+    builder.add_remaining(args.remaining);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn remaining(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(SleepTimerSet::VT_REMAINING, Some(0.0)).unwrap()}
+  }
+  #[inline]
+  pub fn fade(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(SleepTimerSet::VT_FADE, Some(0.0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for SleepTimerSet<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<f32>("remaining", Self::VT_REMAINING, false)?
+     .visit_field::<f32>("fade", Self::VT_FADE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct SleepTimerSetArgs {
+    pub remaining: f32,
+    pub fade: f32,
+}
+impl<'a> Default for SleepTimerSetArgs {
+  #[inline]
+  fn default() -> Self {
+    SleepTimerSetArgs {
+      remaining: 0.0,
+      fade: 0.0,
+    }
+  }
+}
+
+pub struct SleepTimerSetBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> SleepTimerSetBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_remaining(&mut self, remaining: f32) {
+    self.fbb_.push_slot::<f32>(SleepTimerSet::VT_REMAINING, remaining, 0.0);
+  }
+  #[inline]
+  pub fn add_fade(&mut self, fade: f32) {
+    self.fbb_.push_slot::<f32>(SleepTimerSet::VT_FADE, fade, 0.0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> SleepTimerSetBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    SleepTimerSetBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<SleepTimerSet<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for SleepTimerSet<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("SleepTimerSet");
+      ds.field("remaining", &self.remaining());
+      ds.field("fade", &self.fade());
+      ds.finish()
+  }
+}
+pub enum SleepTimerFiredOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct SleepTimerFired<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SleepTimerFired<'a> {
+  type Inner = SleepTimerFired<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> SleepTimerFired<'a> {
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    SleepTimerFired { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    _args: &'args SleepTimerFiredArgs
+  ) -> flatbuffers::WIPOffset<SleepTimerFired<'bldr>> {
+    let mut builder = SleepTimerFiredBuilder::new(_fbb);
+    builder.finish()
+  }
+
+}
+
+impl flatbuffers::Verifiable for SleepTimerFired<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct SleepTimerFiredArgs {
+}
+impl<'a> Default for SleepTimerFiredArgs {
+  #[inline]
+  fn default() -> Self {
+    SleepTimerFiredArgs {
+    }
+  }
+}
+
+pub struct SleepTimerFiredBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> SleepTimerFiredBuilder<'a, 'b> {
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> SleepTimerFiredBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    SleepTimerFiredBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<SleepTimerFired<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for SleepTimerFired<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("SleepTimerFired");
+      ds.finish()
+  }
+}
+pub enum PlaybackFailedOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct PlaybackFailed<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for PlaybackFailed<'a> {
+  type Inner = PlaybackFailed<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> PlaybackFailed<'a> {
+  pub const VT_REASON: flatbuffers::VOffsetT = 4;
+  pub const VT_ERROR: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    PlaybackFailed { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args PlaybackFailedArgs<'args>
+  ) -> flatbuffers::WIPOffset<PlaybackFailed<'bldr>> {
+    let mut builder = PlaybackFailedBuilder::new(_fbb);
+    if let Some(x) = args.error { builder.add_error(x); }
+    if let Some(x) = args.reason { builder.add_reason(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn reason(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(PlaybackFailed::VT_REASON, None)}
+  }
+  #[inline]
+  pub fn error(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(PlaybackFailed::VT_ERROR, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for PlaybackFailed<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("reason", Self::VT_REASON, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("error", Self::VT_ERROR, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct PlaybackFailedArgs<'a> {
+    pub reason: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub error: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for PlaybackFailedArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    PlaybackFailedArgs {
+      reason: None,
+      error: None,
+    }
+  }
+}
+
+pub struct PlaybackFailedBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> PlaybackFailedBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_reason(&mut self, reason: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(PlaybackFailed::VT_REASON, reason);
+  }
+  #[inline]
+  pub fn add_error(&mut self, error: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(PlaybackFailed::VT_ERROR, error);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> PlaybackFailedBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    PlaybackFailedBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<PlaybackFailed<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for PlaybackFailed<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("PlaybackFailed");
+      ds.field("reason", &self.reason());
+      ds.field("error", &self.error());
+      ds.finish()
+  }
+}
+pub enum SpeakerCommandOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct SpeakerCommand<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SpeakerCommand<'a> {
+  type Inner = SpeakerCommand<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> SpeakerCommand<'a> {
+  pub const VT_DEVICE_ID: flatbuffers::VOffsetT = 4;
+  pub const VT_COMMAND_TYPE: flatbuffers::VOffsetT = 6;
+  pub const VT_COMMAND: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    SpeakerCommand { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args SpeakerCommandArgs<'args>
+  ) -> flatbuffers::WIPOffset<SpeakerCommand<'bldr>> {
+    let mut builder = SpeakerCommandBuilder::new(_fbb);
+    if let Some(x) = args.command { builder.add_command(x); }
+    if let Some(x) = args.device_id { builder.add_device_id(x); }
+    builder.add_command_type(args.command_type);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn device_id(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(SpeakerCommand::VT_DEVICE_ID, None)}
+  }
+  #[inline]
+  pub fn command_type(&self) -> SpeakerCommandContent {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<SpeakerCommandContent>(SpeakerCommand::VT_COMMAND_TYPE, Some(SpeakerCommandContent::NONE)).unwrap()}
+  }
+  #[inline]
+  pub fn command(&self) -> Option<flatbuffers::Table<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(SpeakerCommand::VT_COMMAND, None)}
+  }
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn command_as_set_music_volume(&self) -> Option<SetMusicVolume<'a>> {
+    if self.command_type() == SpeakerCommandContent::SetMusicVolume {
+      self.command().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { SetMusicVolume::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn command_as_toggle_pause(&self) -> Option<TogglePause<'a>> {
+    if self.command_type() == SpeakerCommandContent::TogglePause {
+      self.command().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { TogglePause::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn command_as_play(&self) -> Option<Play<'a>> {
+    if self.command_type() == SpeakerCommandContent::Play {
+      self.command().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { Play::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn command_as_stop(&self) -> Option<Stop<'a>> {
+    if self.command_type() == SpeakerCommandContent::Stop {
+      self.command().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { Stop::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn command_as_seek(&self) -> Option<Seek<'a>> {
+    if self.command_type() == SpeakerCommandContent::Seek {
+      self.command().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { Seek::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn command_as_preload(&self) -> Option<Preload<'a>> {
+    if self.command_type() == SpeakerCommandContent::Preload {
+      self.command().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { Preload::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn command_as_set_crossfade(&self) -> Option<SetCrossfade<'a>> {
+    if self.command_type() == SpeakerCommandContent::SetCrossfade {
+      self.command().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { SetCrossfade::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn command_as_create_speaker_group(&self) -> Option<CreateSpeakerGroup<'a>> {
+    if self.command_type() == SpeakerCommandContent::CreateSpeakerGroup {
+      self.command().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { CreateSpeakerGroup::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn command_as_dissolve_speaker_group(&self) -> Option<DissolveSpeakerGroup<'a>> {
+    if self.command_type() == SpeakerCommandContent::DissolveSpeakerGroup {
+      self.command().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { DissolveSpeakerGroup::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn command_as_play_announcement(&self) -> Option<PlayAnnouncement<'a>> {
+    if self.command_type() == SpeakerCommandContent::PlayAnnouncement {
+      self.command().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { PlayAnnouncement::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn command_as_set_ducking(&self) -> Option<SetDucking<'a>> {
+    if self.command_type() == SpeakerCommandContent::SetDucking {
+      self.command().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { SetDucking::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn command_as_update_speaker_info(&self) -> Option<UpdateSpeakerInfo<'a>> {
+    if self.command_type() == SpeakerCommandContent::UpdateSpeakerInfo {
+      self.command().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { UpdateSpeakerInfo::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn command_as_reload_device_filter(&self) -> Option<ReloadDeviceFilter<'a>> {
+    if self.command_type() == SpeakerCommandContent::ReloadDeviceFilter {
+      self.command().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { ReloadDeviceFilter::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn command_as_set_hardware_volume(&self) -> Option<SetHardwareVolume<'a>> {
+    if self.command_type() == SpeakerCommandContent::SetHardwareVolume {
+      self.command().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { SetHardwareVolume::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn command_as_set_mute(&self) -> Option<SetMute<'a>> {
+    if self.command_type() == SpeakerCommandContent::SetMute {
+      self.command().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { SetMute::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn command_as_set_speed(&self) -> Option<SetSpeed<'a>> {
+    if self.command_type() == SpeakerCommandContent::SetSpeed {
+      self.command().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { SetSpeed::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn command_as_seek_relative(&self) -> Option<SeekRelative<'a>> {
+    if self.command_type() == SpeakerCommandContent::SeekRelative {
+      self.command().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { SeekRelative::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn command_as_set_audio_filter(&self) -> Option<SetAudioFilter<'a>> {
+    if self.command_type() == SpeakerCommandContent::SetAudioFilter {
+      self.command().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { SetAudioFilter::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn command_as_set_sleep_timer(&self) -> Option<SetSleepTimer<'a>> {
+    if self.command_type() == SpeakerCommandContent::SetSleepTimer {
+      self.command().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { SetSleepTimer::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn command_as_cancel_sleep_timer(&self) -> Option<CancelSleepTimer<'a>> {
+    if self.command_type() == SpeakerCommandContent::CancelSleepTimer {
+      self.command().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { CancelSleepTimer::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+}
+
+impl flatbuffers::Verifiable for SpeakerCommand<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("device_id", Self::VT_DEVICE_ID, false)?
+     .visit_union::<SpeakerCommandContent, _>("command_type", Self::VT_COMMAND_TYPE, "command", Self::VT_COMMAND, false, |key, v, pos| {
+        match key {
+          SpeakerCommandContent::SetMusicVolume => v.verify_union_variant::<flatbuffers::ForwardsUOffset<SetMusicVolume>>("SpeakerCommandContent::SetMusicVolume", pos),
+          SpeakerCommandContent::TogglePause => v.verify_union_variant::<flatbuffers::ForwardsUOffset<TogglePause>>("SpeakerCommandContent::TogglePause", pos),
+          SpeakerCommandContent::Play => v.verify_union_variant::<flatbuffers::ForwardsUOffset<Play>>("SpeakerCommandContent::Play", pos),
+          SpeakerCommandContent::Stop => v.verify_union_variant::<flatbuffers::ForwardsUOffset<Stop>>("SpeakerCommandContent::Stop", pos),
+          SpeakerCommandContent::Seek => v.verify_union_variant::<flatbuffers::ForwardsUOffset<Seek>>("SpeakerCommandContent::Seek", pos),
+          SpeakerCommandContent::Preload => v.verify_union_variant::<flatbuffers::ForwardsUOffset<Preload>>("SpeakerCommandContent::Preload", pos),
+          SpeakerCommandContent::SetCrossfade => v.verify_union_variant::<flatbuffers::ForwardsUOffset<SetCrossfade>>("SpeakerCommandContent::SetCrossfade", pos),
+          SpeakerCommandContent::CreateSpeakerGroup => v.verify_union_variant::<flatbuffers::ForwardsUOffset<CreateSpeakerGroup>>("SpeakerCommandContent::CreateSpeakerGroup", pos),
+          SpeakerCommandContent::DissolveSpeakerGroup => v.verify_union_variant::<flatbuffers::ForwardsUOffset<DissolveSpeakerGroup>>("SpeakerCommandContent::DissolveSpeakerGroup", pos),
+          SpeakerCommandContent::PlayAnnouncement => v.verify_union_variant::<flatbuffers::ForwardsUOffset<PlayAnnouncement>>("SpeakerCommandContent::PlayAnnouncement", pos),
+          SpeakerCommandContent::SetDucking => v.verify_union_variant::<flatbuffers::ForwardsUOffset<SetDucking>>("SpeakerCommandContent::SetDucking", pos),
+          SpeakerCommandContent::UpdateSpeakerInfo => v.verify_union_variant::<flatbuffers::ForwardsUOffset<UpdateSpeakerInfo>>("SpeakerCommandContent::UpdateSpeakerInfo", pos),
+          SpeakerCommandContent::ReloadDeviceFilter => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ReloadDeviceFilter>>("SpeakerCommandContent::ReloadDeviceFilter", pos),
+          SpeakerCommandContent::SetHardwareVolume => v.verify_union_variant::<flatbuffers::ForwardsUOffset<SetHardwareVolume>>("SpeakerCommandContent::SetHardwareVolume", pos),
+          SpeakerCommandContent::SetMute => v.verify_union_variant::<flatbuffers::ForwardsUOffset<SetMute>>("SpeakerCommandContent::SetMute", pos),
+          SpeakerCommandContent::SetSpeed => v.verify_union_variant::<flatbuffers::ForwardsUOffset<SetSpeed>>("SpeakerCommandContent::SetSpeed", pos),
+          SpeakerCommandContent::SeekRelative => v.verify_union_variant::<flatbuffers::ForwardsUOffset<SeekRelative>>("SpeakerCommandContent::SeekRelative", pos),
+          SpeakerCommandContent::SetAudioFilter => v.verify_union_variant::<flatbuffers::ForwardsUOffset<SetAudioFilter>>("SpeakerCommandContent::SetAudioFilter", pos),
+          SpeakerCommandContent::SetSleepTimer => v.verify_union_variant::<flatbuffers::ForwardsUOffset<SetSleepTimer>>("SpeakerCommandContent::SetSleepTimer", pos),
+          SpeakerCommandContent::CancelSleepTimer => v.verify_union_variant::<flatbuffers::ForwardsUOffset<CancelSleepTimer>>("SpeakerCommandContent::CancelSleepTimer", pos),
+          _ => Ok(()),
+        }
+     })?
+     .finish();
+    Ok(())
+  }
+}
+pub struct SpeakerCommandArgs<'a> {
+    pub device_id: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub command_type: SpeakerCommandContent,
+    pub command: Option<flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>>,
+}
+impl<'a> Default for SpeakerCommandArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    SpeakerCommandArgs {
+      device_id: None,
+      command_type: SpeakerCommandContent::NONE,
+      command: None,
+    }
+  }
+}
+
+pub struct SpeakerCommandBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> SpeakerCommandBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_device_id(&mut self, device_id: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SpeakerCommand::VT_DEVICE_ID, device_id);
+  }
+  #[inline]
+  pub fn add_command_type(&mut self, command_type: SpeakerCommandContent) {
+    self.fbb_.push_slot::<SpeakerCommandContent>(SpeakerCommand::VT_COMMAND_TYPE, command_type, SpeakerCommandContent::NONE);
+  }
+  #[inline]
+  pub fn add_command(&mut self, command: flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SpeakerCommand::VT_COMMAND, command);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> SpeakerCommandBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    SpeakerCommandBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<SpeakerCommand<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for SpeakerCommand<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("SpeakerCommand");
+      ds.field("device_id", &self.device_id());
+      ds.field("command_type", &self.command_type());
+      match self.command_type() {
+        SpeakerCommandContent::SetMusicVolume => {
+          if let Some(x) = self.command_as_set_music_volume() {
+            ds.field("command", &x)
+          } else {
+            ds.field("command", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        SpeakerCommandContent::TogglePause => {
+          if let Some(x) = self.command_as_toggle_pause() {
+            ds.field("command", &x)
+          } else {
+            ds.field("command", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        SpeakerCommandContent::Play => {
+          if let Some(x) = self.command_as_play() {
+            ds.field("command", &x)
+          } else {
+            ds.field("command", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        SpeakerCommandContent::Stop => {
+          if let Some(x) = self.command_as_stop() {
+            ds.field("command", &x)
+          } else {
+            ds.field("command", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        SpeakerCommandContent::Seek => {
+          if let Some(x) = self.command_as_seek() {
+            ds.field("command", &x)
+          } else {
+            ds.field("command", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        SpeakerCommandContent::Preload => {
+          if let Some(x) = self.command_as_preload() {
+            ds.field("command", &x)
+          } else {
+            ds.field("command", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        SpeakerCommandContent::SetCrossfade => {
+          if let Some(x) = self.command_as_set_crossfade() {
+            ds.field("command", &x)
+          } else {
+            ds.field("command", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        SpeakerCommandContent::CreateSpeakerGroup => {
+          if let Some(x) = self.command_as_create_speaker_group() {
+            ds.field("command", &x)
+          } else {
+            ds.field("command", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        SpeakerCommandContent::DissolveSpeakerGroup => {
+          if let Some(x) = self.command_as_dissolve_speaker_group() {
+            ds.field("command", &x)
+          } else {
+            ds.field("command", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        SpeakerCommandContent::PlayAnnouncement => {
+          if let Some(x) = self.command_as_play_announcement() {
+            ds.field("command", &x)
+          } else {
+            ds.field("command", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        SpeakerCommandContent::SetDucking => {
+          if let Some(x) = self.command_as_set_ducking() {
+            ds.field("command", &x)
+          } else {
+            ds.field("command", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        SpeakerCommandContent::UpdateSpeakerInfo => {
+          if let Some(x) = self.command_as_update_speaker_info() {
+            ds.field("command", &x)
+          } else {
+            ds.field("command", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        SpeakerCommandContent::ReloadDeviceFilter => {
+          if let Some(x) = self.command_as_reload_device_filter() {
+            ds.field("command", &x)
+          } else {
+            ds.field("command", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        SpeakerCommandContent::SetHardwareVolume => {
+          if let Some(x) = self.command_as_set_hardware_volume() {
+            ds.field("command", &x)
+          } else {
+            ds.field("command", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        SpeakerCommandContent::SetMute => {
+          if let Some(x) = self.command_as_set_mute() {
+            ds.field("command", &x)
+          } else {
+            ds.field("command", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        SpeakerCommandContent::SetSpeed => {
+          if let Some(x) = self.command_as_set_speed() {
+            ds.field("command", &x)
+          } else {
+            ds.field("command", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        SpeakerCommandContent::SeekRelative => {
+          if let Some(x) = self.command_as_seek_relative() {
+            ds.field("command", &x)
+          } else {
+            ds.field("command", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        SpeakerCommandContent::SetAudioFilter => {
+          if let Some(x) = self.command_as_set_audio_filter() {
+            ds.field("command", &x)
+          } else {
+            ds.field("command", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        SpeakerCommandContent::SetSleepTimer => {
+          if let Some(x) = self.command_as_set_sleep_timer() {
+            ds.field("command", &x)
+          } else {
+            ds.field("command", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        SpeakerCommandContent::CancelSleepTimer => {
+          if let Some(x) = self.command_as_cancel_sleep_timer() {
+            ds.field("command", &x)
+          } else {
+            ds.field("command", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        _ => {
+          let x: Option<()> = None;
+          ds.field("command", &x)
+        },
+      };
+      ds.finish()
+  }
+}
+pub enum SpeakerQueryOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct SpeakerQuery<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SpeakerQuery<'a> {
+  type Inner = SpeakerQuery<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> SpeakerQuery<'a> {
+  pub const VT_DEVICE_ID: flatbuffers::VOffsetT = 4;
+  pub const VT_QUERY_TYPE: flatbuffers::VOffsetT = 6;
+  pub const VT_QUERY: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    SpeakerQuery { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args SpeakerQueryArgs<'args>
+  ) -> flatbuffers::WIPOffset<SpeakerQuery<'bldr>> {
+    let mut builder = SpeakerQueryBuilder::new(_fbb);
+    if let Some(x) = args.query { builder.add_query(x); }
+    if let Some(x) = args.device_id { builder.add_device_id(x); }
+    builder.add_query_type(args.query_type);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn device_id(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(SpeakerQuery::VT_DEVICE_ID, None)}
+  }
+  #[inline]
+  pub fn query_type(&self) -> SpeakerQueryContent {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<SpeakerQueryContent>(SpeakerQuery::VT_QUERY_TYPE, Some(SpeakerQueryContent::NONE)).unwrap()}
+  }
+  #[inline]
+  pub fn query(&self) -> Option<flatbuffers::Table<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(SpeakerQuery::VT_QUERY, None)}
+  }
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn query_as_query_music_volume(&self) -> Option<QueryMusicVolume<'a>> {
+    if self.query_type() == SpeakerQueryContent::QueryMusicVolume {
+      self.query().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { QueryMusicVolume::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn query_as_query_pause(&self) -> Option<QueryPause<'a>> {
+    if self.query_type() == SpeakerQueryContent::QueryPause {
+      self.query().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { QueryPause::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn query_as_query_play(&self) -> Option<QueryPlay<'a>> {
+    if self.query_type() == SpeakerQueryContent::QueryPlay {
+      self.query().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { QueryPlay::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn query_as_query_seek(&self) -> Option<QuerySeek<'a>> {
+    if self.query_type() == SpeakerQueryContent::QuerySeek {
+      self.query().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { QuerySeek::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn query_as_query_duration(&self) -> Option<QueryDuration<'a>> {
+    if self.query_type() == SpeakerQueryContent::QueryDuration {
+      self.query().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { QueryDuration::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn query_as_query_hardware_volume(&self) -> Option<QueryHardwareVolume<'a>> {
+    if self.query_type() == SpeakerQueryContent::QueryHardwareVolume {
+      self.query().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { QueryHardwareVolume::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn query_as_query_sleep_timer(&self) -> Option<QuerySleepTimer<'a>> {
+    if self.query_type() == SpeakerQueryContent::QuerySleepTimer {
+      self.query().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { QuerySleepTimer::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+}
+
+impl flatbuffers::Verifiable for SpeakerQuery<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("device_id", Self::VT_DEVICE_ID, false)?
+     .visit_union::<SpeakerQueryContent, _>("query_type", Self::VT_QUERY_TYPE, "query", Self::VT_QUERY, false, |key, v, pos| {
+        match key {
+          SpeakerQueryContent::QueryMusicVolume => v.verify_union_variant::<flatbuffers::ForwardsUOffset<QueryMusicVolume>>("SpeakerQueryContent::QueryMusicVolume", pos),
+          SpeakerQueryContent::QueryPause => v.verify_union_variant::<flatbuffers::ForwardsUOffset<QueryPause>>("SpeakerQueryContent::QueryPause", pos),
+          SpeakerQueryContent::QueryPlay => v.verify_union_variant::<flatbuffers::ForwardsUOffset<QueryPlay>>("SpeakerQueryContent::QueryPlay", pos),
+          SpeakerQueryContent::QuerySeek => v.verify_union_variant::<flatbuffers::ForwardsUOffset<QuerySeek>>("SpeakerQueryContent::QuerySeek", pos),
+          SpeakerQueryContent::QueryDuration => v.verify_union_variant::<flatbuffers::ForwardsUOffset<QueryDuration>>("SpeakerQueryContent::QueryDuration", pos),
+          SpeakerQueryContent::QueryHardwareVolume => v.verify_union_variant::<flatbuffers::ForwardsUOffset<QueryHardwareVolume>>("SpeakerQueryContent::QueryHardwareVolume", pos),
+          SpeakerQueryContent::QuerySleepTimer => v.verify_union_variant::<flatbuffers::ForwardsUOffset<QuerySleepTimer>>("SpeakerQueryContent::QuerySleepTimer", pos),
+          _ => Ok(()),
+        }
+     })?
+     .finish();
+    Ok(())
+  }
+}
+pub struct SpeakerQueryArgs<'a> {
+    pub device_id: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub query_type: SpeakerQueryContent,
+    pub query: Option<flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>>,
+}
+impl<'a> Default for SpeakerQueryArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    SpeakerQueryArgs {
+      device_id: None,
+      query_type: SpeakerQueryContent::NONE,
+      query: None,
+    }
+  }
+}
+
+pub struct SpeakerQueryBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> SpeakerQueryBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_device_id(&mut self, device_id: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SpeakerQuery::VT_DEVICE_ID, device_id);
+  }
+  #[inline]
+  pub fn add_query_type(&mut self, query_type: SpeakerQueryContent) {
+    self.fbb_.push_slot::<SpeakerQueryContent>(SpeakerQuery::VT_QUERY_TYPE, query_type, SpeakerQueryContent::NONE);
+  }
+  #[inline]
+  pub fn add_query(&mut self, query: flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SpeakerQuery::VT_QUERY, query);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> SpeakerQueryBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    SpeakerQueryBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<SpeakerQuery<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for SpeakerQuery<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("SpeakerQuery");
+      ds.field("device_id", &self.device_id());
+      ds.field("query_type", &self.query_type());
+      match self.query_type() {
+        SpeakerQueryContent::QueryMusicVolume => {
+          if let Some(x) = self.query_as_query_music_volume() {
+            ds.field("query", &x)
+          } else {
+            ds.field("query", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        SpeakerQueryContent::QueryPause => {
+          if let Some(x) = self.query_as_query_pause() {
+            ds.field("query", &x)
+          } else {
+            ds.field("query", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        SpeakerQueryContent::QueryPlay => {
+          if let Some(x) = self.query_as_query_play() {
+            ds.field("query", &x)
+          } else {
+            ds.field("query", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        SpeakerQueryContent::QuerySeek => {
+          if let Some(x) = self.query_as_query_seek() {
+            ds.field("query", &x)
+          } else {
+            ds.field("query", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        SpeakerQueryContent::QueryDuration => {
+          if let Some(x) = self.query_as_query_duration() {
+            ds.field("query", &x)
+          } else {
+            ds.field("query", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        SpeakerQueryContent::QueryHardwareVolume => {
+          if let Some(x) = self.query_as_query_hardware_volume() {
+            ds.field("query", &x)
+          } else {
+            ds.field("query", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        SpeakerQueryContent::QuerySleepTimer => {
+          if let Some(x) = self.query_as_query_sleep_timer() {
+            ds.field("query", &x)
+          } else {
+            ds.field("query", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        _ => {
+          let x: Option<()> = None;
+          ds.field("query", &x)
+        },
+      };
+      ds.finish()
+  }
+}
+pub enum SpeakerListQueryOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct SpeakerListQuery<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SpeakerListQuery<'a> {
+  type Inner = SpeakerListQuery<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> SpeakerListQuery<'a> {
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    SpeakerListQuery { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    _args: &'args SpeakerListQueryArgs
+  ) -> flatbuffers::WIPOffset<SpeakerListQuery<'bldr>> {
+    let mut builder = SpeakerListQueryBuilder::new(_fbb);
+    builder.finish()
+  }
+
+}
+
+impl flatbuffers::Verifiable for SpeakerListQuery<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct SpeakerListQueryArgs {
+}
+impl<'a> Default for SpeakerListQueryArgs {
+  #[inline]
+  fn default() -> Self {
+    SpeakerListQueryArgs {
+    }
+  }
+}
+
+pub struct SpeakerListQueryBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> SpeakerListQueryBuilder<'a, 'b> {
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> SpeakerListQueryBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    SpeakerListQueryBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<SpeakerListQuery<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for SpeakerListQuery<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("SpeakerListQuery");
+      ds.finish()
+  }
+}
+pub enum SpeakerEventOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct SpeakerEvent<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SpeakerEvent<'a> {
+  type Inner = SpeakerEvent<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> SpeakerEvent<'a> {
+  pub const VT_DEVICE_ID: flatbuffers::VOffsetT = 4;
+  pub const VT_EVENT_TYPE: flatbuffers::VOffsetT = 6;
+  pub const VT_EVENT: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    SpeakerEvent { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args SpeakerEventArgs<'args>
+  ) -> flatbuffers::WIPOffset<SpeakerEvent<'bldr>> {
+    let mut builder = SpeakerEventBuilder::new(_fbb);
+    if let Some(x) = args.event { builder.add_event(x); }
+    if let Some(x) = args.device_id { builder.add_device_id(x); }
+    builder.add_event_type(args.event_type);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn device_id(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(SpeakerEvent::VT_DEVICE_ID, None)}
+  }
+  #[inline]
+  pub fn event_type(&self) -> SpeakerEventContent {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<SpeakerEventContent>(SpeakerEvent::VT_EVENT_TYPE, Some(SpeakerEventContent::NONE)).unwrap()}
+  }
+  #[inline]
+  pub fn event(&self) -> Option<flatbuffers::Table<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(SpeakerEvent::VT_EVENT, None)}
+  }
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn event_as_music_volume_changed(&self) -> Option<MusicVolumeChanged<'a>> {
+    if self.event_type() == SpeakerEventContent::MusicVolumeChanged {
+      self.event().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { MusicVolumeChanged::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn event_as_play_started(&self) -> Option<PlayStarted<'a>> {
+    if self.event_type() == SpeakerEventContent::PlayStarted {
+      self.event().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { PlayStarted::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn event_as_play_stopped(&self) -> Option<PlayStopped<'a>> {
+    if self.event_type() == SpeakerEventContent::PlayStopped {
+      self.event().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { PlayStopped::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn event_as_seek_changed(&self) -> Option<SeekChanged<'a>> {
+    if self.event_type() == SpeakerEventContent::SeekChanged {
+      self.event().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { SeekChanged::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn event_as_duration_changed(&self) -> Option<DurationChanged<'a>> {
+    if self.event_type() == SpeakerEventContent::DurationChanged {
+      self.event().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { DurationChanged::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn event_as_file_ended(&self) -> Option<FileEnded<'a>> {
+    if self.event_type() == SpeakerEventContent::FileEnded {
+      self.event().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { FileEnded::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn event_as_pause_changed(&self) -> Option<PauseChanged<'a>> {
+    if self.event_type() == SpeakerEventContent::PauseChanged {
+      self.event().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { PauseChanged::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn event_as_playback_failed(&self) -> Option<PlaybackFailed<'a>> {
+    if self.event_type() == SpeakerEventContent::PlaybackFailed {
+      self.event().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { PlaybackFailed::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn event_as_speaker_group_changed(&self) -> Option<SpeakerGroupChanged<'a>> {
+    if self.event_type() == SpeakerEventContent::SpeakerGroupChanged {
+      self.event().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { SpeakerGroupChanged::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn event_as_announcement_started(&self) -> Option<AnnouncementStarted<'a>> {
+    if self.event_type() == SpeakerEventContent::AnnouncementStarted {
+      self.event().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { AnnouncementStarted::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn event_as_announcement_ended(&self) -> Option<AnnouncementEnded<'a>> {
+    if self.event_type() == SpeakerEventContent::AnnouncementEnded {
+      self.event().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { AnnouncementEnded::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn event_as_hardware_volume_changed(&self) -> Option<HardwareVolumeChanged<'a>> {
+    if self.event_type() == SpeakerEventContent::HardwareVolumeChanged {
+      self.event().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { HardwareVolumeChanged::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn event_as_mute_changed(&self) -> Option<MuteChanged<'a>> {
+    if self.event_type() == SpeakerEventContent::MuteChanged {
+      self.event().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { MuteChanged::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn event_as_speed_changed(&self) -> Option<SpeedChanged<'a>> {
+    if self.event_type() == SpeakerEventContent::SpeedChanged {
+      self.event().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { SpeedChanged::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn event_as_audio_filter_changed(&self) -> Option<AudioFilterChanged<'a>> {
+    if self.event_type() == SpeakerEventContent::AudioFilterChanged {
+      self.event().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { AudioFilterChanged::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn event_as_now_playing(&self) -> Option<NowPlaying<'a>> {
+    if self.event_type() == SpeakerEventContent::NowPlaying {
+      self.event().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { NowPlaying::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn event_as_sleep_timer_set(&self) -> Option<SleepTimerSet<'a>> {
+    if self.event_type() == SpeakerEventContent::SleepTimerSet {
+      self.event().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { SleepTimerSet::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn event_as_sleep_timer_fired(&self) -> Option<SleepTimerFired<'a>> {
+    if self.event_type() == SpeakerEventContent::SleepTimerFired {
+      self.event().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { SleepTimerFired::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+}
+
+impl flatbuffers::Verifiable for SpeakerEvent<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("device_id", Self::VT_DEVICE_ID, false)?
+     .visit_union::<SpeakerEventContent, _>("event_type", Self::VT_EVENT_TYPE, "event", Self::VT_EVENT, false, |key, v, pos| {
+        match key {
+          SpeakerEventContent::MusicVolumeChanged => v.verify_union_variant::<flatbuffers::ForwardsUOffset<MusicVolumeChanged>>("SpeakerEventContent::MusicVolumeChanged", pos),
+          SpeakerEventContent::PlayStarted => v.verify_union_variant::<flatbuffers::ForwardsUOffset<PlayStarted>>("SpeakerEventContent::PlayStarted", pos),
+          SpeakerEventContent::PlayStopped => v.verify_union_variant::<flatbuffers::ForwardsUOffset<PlayStopped>>("SpeakerEventContent::PlayStopped", pos),
+          SpeakerEventContent::SeekChanged => v.verify_union_variant::<flatbuffers::ForwardsUOffset<SeekChanged>>("SpeakerEventContent::SeekChanged", pos),
+          SpeakerEventContent::DurationChanged => v.verify_union_variant::<flatbuffers::ForwardsUOffset<DurationChanged>>("SpeakerEventContent::DurationChanged", pos),
+          SpeakerEventContent::FileEnded => v.verify_union_variant::<flatbuffers::ForwardsUOffset<FileEnded>>("SpeakerEventContent::FileEnded", pos),
+          SpeakerEventContent::PauseChanged => v.verify_union_variant::<flatbuffers::ForwardsUOffset<PauseChanged>>("SpeakerEventContent::PauseChanged", pos),
+          SpeakerEventContent::PlaybackFailed => v.verify_union_variant::<flatbuffers::ForwardsUOffset<PlaybackFailed>>("SpeakerEventContent::PlaybackFailed", pos),
+          SpeakerEventContent::SpeakerGroupChanged => v.verify_union_variant::<flatbuffers::ForwardsUOffset<SpeakerGroupChanged>>("SpeakerEventContent::SpeakerGroupChanged", pos),
+          SpeakerEventContent::AnnouncementStarted => v.verify_union_variant::<flatbuffers::ForwardsUOffset<AnnouncementStarted>>("SpeakerEventContent::AnnouncementStarted", pos),
+          SpeakerEventContent::AnnouncementEnded => v.verify_union_variant::<flatbuffers::ForwardsUOffset<AnnouncementEnded>>("SpeakerEventContent::AnnouncementEnded", pos),
+          SpeakerEventContent::HardwareVolumeChanged => v.verify_union_variant::<flatbuffers::ForwardsUOffset<HardwareVolumeChanged>>("SpeakerEventContent::HardwareVolumeChanged", pos),
+          SpeakerEventContent::MuteChanged => v.verify_union_variant::<flatbuffers::ForwardsUOffset<MuteChanged>>("SpeakerEventContent::MuteChanged", pos),
+          SpeakerEventContent::SpeedChanged => v.verify_union_variant::<flatbuffers::ForwardsUOffset<SpeedChanged>>("SpeakerEventContent::SpeedChanged", pos),
+          SpeakerEventContent::AudioFilterChanged => v.verify_union_variant::<flatbuffers::ForwardsUOffset<AudioFilterChanged>>("SpeakerEventContent::AudioFilterChanged", pos),
+          SpeakerEventContent::NowPlaying => v.verify_union_variant::<flatbuffers::ForwardsUOffset<NowPlaying>>("SpeakerEventContent::NowPlaying", pos),
+          SpeakerEventContent::SleepTimerSet => v.verify_union_variant::<flatbuffers::ForwardsUOffset<SleepTimerSet>>("SpeakerEventContent::SleepTimerSet", pos),
+          SpeakerEventContent::SleepTimerFired => v.verify_union_variant::<flatbuffers::ForwardsUOffset<SleepTimerFired>>("SpeakerEventContent::SleepTimerFired", pos),
+          _ => Ok(()),
+        }
+     })?
+     .finish();
+    Ok(())
+  }
+}
+pub struct SpeakerEventArgs<'a> {
+    pub device_id: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub event_type: SpeakerEventContent,
+    pub event: Option<flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>>,
+}
+impl<'a> Default for SpeakerEventArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    SpeakerEventArgs {
+      device_id: None,
+      event_type: SpeakerEventContent::NONE,
+      event: None,
+    }
+  }
+}
+
+pub struct SpeakerEventBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> SpeakerEventBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_device_id(&mut self, device_id: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SpeakerEvent::VT_DEVICE_ID, device_id);
+  }
+  #[inline]
+  pub fn add_event_type(&mut self, event_type: SpeakerEventContent) {
+    self.fbb_.push_slot::<SpeakerEventContent>(SpeakerEvent::VT_EVENT_TYPE, event_type, SpeakerEventContent::NONE);
+  }
+  #[inline]
+  pub fn add_event(&mut self, event: flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SpeakerEvent::VT_EVENT, event);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> SpeakerEventBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    SpeakerEventBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<SpeakerEvent<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for SpeakerEvent<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("SpeakerEvent");
+      ds.field("device_id", &self.device_id());
+      ds.field("event_type", &self.event_type());
+      match self.event_type() {
+        SpeakerEventContent::MusicVolumeChanged => {
+          if let Some(x) = self.event_as_music_volume_changed() {
+            ds.field("event", &x)
+          } else {
+            ds.field("event", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        SpeakerEventContent::PlayStarted => {
+          if let Some(x) = self.event_as_play_started() {
+            ds.field("event", &x)
+          } else {
+            ds.field("event", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        SpeakerEventContent::PlayStopped => {
+          if let Some(x) = self.event_as_play_stopped() {
+            ds.field("event", &x)
+          } else {
+            ds.field("event", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        SpeakerEventContent::SeekChanged => {
+          if let Some(x) = self.event_as_seek_changed() {
+            ds.field("event", &x)
+          } else {
+            ds.field("event", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        SpeakerEventContent::DurationChanged => {
+          if let Some(x) = self.event_as_duration_changed() {
+            ds.field("event", &x)
+          } else {
+            ds.field("event", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        SpeakerEventContent::FileEnded => {
+          if let Some(x) = self.event_as_file_ended() {
+            ds.field("event", &x)
+          } else {
+            ds.field("event", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        SpeakerEventContent::PauseChanged => {
+          if let Some(x) = self.event_as_pause_changed() {
+            ds.field("event", &x)
+          } else {
+            ds.field("event", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        SpeakerEventContent::PlaybackFailed => {
+          if let Some(x) = self.event_as_playback_failed() {
+            ds.field("event", &x)
+          } else {
+            ds.field("event", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        SpeakerEventContent::SpeakerGroupChanged => {
+          if let Some(x) = self.event_as_speaker_group_changed() {
+            ds.field("event", &x)
+          } else {
+            ds.field("event", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        SpeakerEventContent::AnnouncementStarted => {
+          if let Some(x) = self.event_as_announcement_started() {
+            ds.field("event", &x)
+          } else {
+            ds.field("event", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        SpeakerEventContent::AnnouncementEnded => {
+          if let Some(x) = self.event_as_announcement_ended() {
+            ds.field("event", &x)
+          } else {
+            ds.field("event", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        SpeakerEventContent::HardwareVolumeChanged => {
+          if let Some(x) = self.event_as_hardware_volume_changed() {
+            ds.field("event", &x)
+          } else {
+            ds.field("event", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        SpeakerEventContent::MuteChanged => {
+          if let Some(x) = self.event_as_mute_changed() {
+            ds.field("event", &x)
+          } else {
+            ds.field("event", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        SpeakerEventContent::SpeedChanged => {
+          if let Some(x) = self.event_as_speed_changed() {
+            ds.field("event", &x)
+          } else {
+            ds.field("event", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        SpeakerEventContent::AudioFilterChanged => {
+          if let Some(x) = self.event_as_audio_filter_changed() {
+            ds.field("event", &x)
+          } else {
+            ds.field("event", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        SpeakerEventContent::NowPlaying => {
+          if let Some(x) = self.event_as_now_playing() {
+            ds.field("event", &x)
+          } else {
+            ds.field("event", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        SpeakerEventContent::SleepTimerSet => {
+          if let Some(x) = self.event_as_sleep_timer_set() {
+            ds.field("event", &x)
+          } else {
+            ds.field("event", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        SpeakerEventContent::SleepTimerFired => {
+          if let Some(x) = self.event_as_sleep_timer_fired() {
+            ds.field("event", &x)
+          } else {
+            ds.field("event", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        _ => {
+          let x: Option<()> = None;
+          ds.field("event", &x)
+        },
+      };
+      ds.finish()
+  }
+}
+pub enum SpeakerInfoOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct SpeakerInfo<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SpeakerInfo<'a> {
+  type Inner = SpeakerInfo<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> SpeakerInfo<'a> {
+  pub const VT_DEVICE_ID: flatbuffers::VOffsetT = 4;
+  pub const VT_NAME: flatbuffers::VOffsetT = 6;
+  pub const VT_ROOM: flatbuffers::VOffsetT = 8;
+  pub const VT_ICON: flatbuffers::VOffsetT = 10;
+  pub const VT_DEFAULT_VOLUME: flatbuffers::VOffsetT = 12;
+  pub const VT_HIDDEN: flatbuffers::VOffsetT = 14;
+  pub const VT_MUSIC_VOLUME: flatbuffers::VOffsetT = 16;
+  pub const VT_PLAYING: flatbuffers::VOffsetT = 18;
+  pub const VT_PAUSED: flatbuffers::VOffsetT = 20;
+  pub const VT_URL: flatbuffers::VOffsetT = 22;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    SpeakerInfo { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args SpeakerInfoArgs<'args>
+  ) -> flatbuffers::WIPOffset<SpeakerInfo<'bldr>> {
+    let mut builder = SpeakerInfoBuilder::new(_fbb);
+    if let Some(x) = args.url { builder.add_url(x); }
+    builder.add_music_volume(args.music_volume);
+    builder.add_default_volume(args.default_volume);
+    if let Some(x) = args.icon { builder.add_icon(x); }
+    if let Some(x) = args.room { builder.add_room(x); }
+    if let Some(x) = args.name { builder.add_name(x); }
+    if let Some(x) = args.device_id { builder.add_device_id(x); }
+    builder.add_paused(args.paused);
+    builder.add_playing(args.playing);
+    builder.add_hidden(args.hidden);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn device_id(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(SpeakerInfo::VT_DEVICE_ID, None)}
+  }
+  #[inline]
+  pub fn name(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(SpeakerInfo::VT_NAME, None)}
+  }
+  #[inline]
+  pub fn room(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(SpeakerInfo::VT_ROOM, None)}
+  }
+  #[inline]
+  pub fn icon(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(SpeakerInfo::VT_ICON, None)}
+  }
+  #[inline]
+  pub fn default_volume(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(SpeakerInfo::VT_DEFAULT_VOLUME, Some(-1.0)).unwrap()}
+  }
+  #[inline]
+  pub fn hidden(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(SpeakerInfo::VT_HIDDEN, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn music_volume(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(SpeakerInfo::VT_MUSIC_VOLUME, Some(0.0)).unwrap()}
+  }
+  #[inline]
+  pub fn playing(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(SpeakerInfo::VT_PLAYING, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn paused(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(SpeakerInfo::VT_PAUSED, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn url(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(SpeakerInfo::VT_URL, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for SpeakerInfo<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("device_id", Self::VT_DEVICE_ID, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("room", Self::VT_ROOM, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("icon", Self::VT_ICON, false)?
+     .visit_field::<f32>("default_volume", Self::VT_DEFAULT_VOLUME, false)?
+     .visit_field::<bool>("hidden", Self::VT_HIDDEN, false)?
+     .visit_field::<f32>("music_volume", Self::VT_MUSIC_VOLUME, false)?
+     .visit_field::<bool>("playing", Self::VT_PLAYING, false)?
+     .visit_field::<bool>("paused", Self::VT_PAUSED, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("url", Self::VT_URL, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct SpeakerInfoArgs<'a> {
+    pub device_id: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub room: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub icon: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub default_volume: f32,
+    pub hidden: bool,
+    pub music_volume: f32,
+    pub playing: bool,
+    pub paused: bool,
+    pub url: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for SpeakerInfoArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    SpeakerInfoArgs {
+      device_id: None,
+      name: None,
+      room: None,
+      icon: None,
+      default_volume: -1.0,
+      hidden: false,
+      music_volume: 0.0,
+      playing: false,
+      paused: false,
+      url: None,
+    }
+  }
+}
+
+pub struct SpeakerInfoBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> SpeakerInfoBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_device_id(&mut self, device_id: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SpeakerInfo::VT_DEVICE_ID, device_id);
+  }
+  #[inline]
+  pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SpeakerInfo::VT_NAME, name);
+  }
+  #[inline]
+  pub fn add_room(&mut self, room: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SpeakerInfo::VT_ROOM, room);
+  }
+  #[inline]
+  pub fn add_icon(&mut self, icon: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SpeakerInfo::VT_ICON, icon);
+  }
+  #[inline]
+  pub fn add_default_volume(&mut self, default_volume: f32) {
+    self.fbb_.push_slot::<f32>(SpeakerInfo::VT_DEFAULT_VOLUME, default_volume, -1.0);
+  }
+  #[inline]
+  pub fn add_hidden(&mut self, hidden: bool) {
+    self.fbb_.push_slot::<bool>(SpeakerInfo::VT_HIDDEN, hidden, false);
+  }
+  #[inline]
+  pub fn add_music_volume(&mut self, music_volume: f32) {
+    self.fbb_.push_slot::<f32>(SpeakerInfo::VT_MUSIC_VOLUME, music_volume, 0.0);
+  }
+  #[inline]
+  pub fn add_playing(&mut self, playing: bool) {
+    self.fbb_.push_slot::<bool>(SpeakerInfo::VT_PLAYING, playing, false);
+  }
+  #[inline]
+  pub fn add_paused(&mut self, paused: bool) {
+    self.fbb_.push_slot::<bool>(SpeakerInfo::VT_PAUSED, paused, false);
+  }
+  #[inline]
+  pub fn add_url(&mut self, url: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SpeakerInfo::VT_URL, url);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> SpeakerInfoBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    SpeakerInfoBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<SpeakerInfo<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for SpeakerInfo<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("SpeakerInfo");
+      ds.field("device_id", &self.device_id());
+      ds.field("name", &self.name());
+      ds.field("room", &self.room());
+      ds.field("icon", &self.icon());
+      ds.field("default_volume", &self.default_volume());
+      ds.field("hidden", &self.hidden());
+      ds.field("music_volume", &self.music_volume());
+      ds.field("playing", &self.playing());
+      ds.field("paused", &self.paused());
+      ds.field("url", &self.url());
+      ds.finish()
+  }
+}
+pub enum SpeakerListEventOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct SpeakerListEvent<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SpeakerListEvent<'a> {
+  type Inner = SpeakerListEvent<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> SpeakerListEvent<'a> {
+  pub const VT_DEVICE_IDS: flatbuffers::VOffsetT = 4;
+  pub const VT_SPEAKERS: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    SpeakerListEvent { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args SpeakerListEventArgs<'args>
+  ) -> flatbuffers::WIPOffset<SpeakerListEvent<'bldr>> {
+    let mut builder = SpeakerListEventBuilder::new(_fbb);
+    if let Some(x) = args.speakers { builder.add_speakers(x); }
+    if let Some(x) = args.device_ids { builder.add_device_ids(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn device_ids(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>(SpeakerListEvent::VT_DEVICE_IDS, None)}
+  }
+  #[inline]
+  pub fn speakers(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<SpeakerInfo<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<SpeakerInfo>>>>(SpeakerListEvent::VT_SPEAKERS, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for SpeakerListEvent<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>>>("device_ids", Self::VT_DEVICE_IDS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<SpeakerInfo>>>>("speakers", Self::VT_SPEAKERS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct SpeakerListEventArgs<'a> {
+    pub device_ids: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>,
+    pub speakers: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<SpeakerInfo<'a>>>>>,
+}
+impl<'a> Default for SpeakerListEventArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    SpeakerListEventArgs {
+      device_ids: None,
+      speakers: None,
+    }
+  }
+}
+
+pub struct SpeakerListEventBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> SpeakerListEventBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_device_ids(&mut self, device_ids: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SpeakerListEvent::VT_DEVICE_IDS, device_ids);
+  }
+  #[inline]
+  pub fn add_speakers(&mut self, speakers: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<SpeakerInfo<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SpeakerListEvent::VT_SPEAKERS, speakers);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> SpeakerListEventBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    SpeakerListEventBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<SpeakerListEvent<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for SpeakerListEvent<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("SpeakerListEvent");
+      ds.field("device_ids", &self.device_ids());
+      ds.field("speakers", &self.speakers());
+      ds.finish()
+  }
+}
@@ -0,0 +1,688 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+
+// @generated
+
+use crate::msg_speakers_generated::*;
+use crate::msg_echo_generated::*;
+use crate::msg_error_generated::*;
+use crate::msg_print_generated::*;
+use crate::msg_playlists_generated::*;
+use crate::msg_alarms_generated::*;
+use core::mem;
+use core::cmp::Ordering;
+
+extern crate flatbuffers;
+use self::flatbuffers::{EndianScalar, Follow};
+
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_MESSAGE_CONTENT: u8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_MESSAGE_CONTENT: u8 = 14;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_MESSAGE_CONTENT: [MessageContent; 15] = [
+  MessageContent::NONE,
+  MessageContent::Print,
+  MessageContent::Echo,
+  MessageContent::SpeakerCommand,
+  MessageContent::SpeakerQuery,
+  MessageContent::SpeakerEvent,
+  MessageContent::SpeakerListQuery,
+  MessageContent::SpeakerListEvent,
+  MessageContent::PlaylistCommand,
+  MessageContent::PlaylistQuery,
+  MessageContent::PlaylistEvent,
+  MessageContent::Error,
+  MessageContent::AlarmCommand,
+  MessageContent::AlarmQuery,
+  MessageContent::AlarmEvent,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct MessageContent(pub u8);
+#[allow(non_upper_case_globals)]
+impl MessageContent {
+  pub const NONE: Self = Self(0);
+  pub const Print: Self = Self(1);
+  pub const Echo: Self = Self(2);
+  pub const SpeakerCommand: Self = Self(3);
+  pub const SpeakerQuery: Self = Self(4);
+  pub const SpeakerEvent: Self = Self(5);
+  pub const SpeakerListQuery: Self = Self(6);
+  pub const SpeakerListEvent: Self = Self(7);
+  pub const PlaylistCommand: Self = Self(8);
+  pub const PlaylistQuery: Self = Self(9);
+  pub const PlaylistEvent: Self = Self(10);
+  pub const Error: Self = Self(11);
+  pub const AlarmCommand: Self = Self(12);
+  pub const AlarmQuery: Self = Self(13);
+  pub const AlarmEvent: Self = Self(14);
+
+  pub const ENUM_MIN: u8 = 0;
+  pub const ENUM_MAX: u8 = 14;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::NONE,
+    Self::Print,
+    Self::Echo,
+    Self::SpeakerCommand,
+    Self::SpeakerQuery,
+    Self::SpeakerEvent,
+    Self::SpeakerListQuery,
+    Self::SpeakerListEvent,
+    Self::PlaylistCommand,
+    Self::PlaylistQuery,
+    Self::PlaylistEvent,
+    Self::Error,
+    Self::AlarmCommand,
+    Self::AlarmQuery,
+    Self::AlarmEvent,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::NONE => Some("NONE"),
+      Self::Print => Some("Print"),
+      Self::Echo => Some("Echo"),
+      Self::SpeakerCommand => Some("SpeakerCommand"),
+      Self::SpeakerQuery => Some("SpeakerQuery"),
+      Self::SpeakerEvent => Some("SpeakerEvent"),
+      Self::SpeakerListQuery => Some("SpeakerListQuery"),
+      Self::SpeakerListEvent => Some("SpeakerListEvent"),
+      Self::PlaylistCommand => Some("PlaylistCommand"),
+      Self::PlaylistQuery => Some("PlaylistQuery"),
+      Self::PlaylistEvent => Some("PlaylistEvent"),
+      Self::Error => Some("Error"),
+      Self::AlarmCommand => Some("AlarmCommand"),
+      Self::AlarmQuery => Some("AlarmQuery"),
+      Self::AlarmEvent => Some("AlarmEvent"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for MessageContent {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for MessageContent {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = flatbuffers::read_scalar_at::<u8>(buf, loc);
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for MessageContent {
+    type Output = MessageContent;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<u8>(dst, self.0);
+    }
+}
+
+impl flatbuffers::EndianScalar for MessageContent {
+  type Scalar = u8;
+  #[inline]
+  fn to_little_endian(self) -> u8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: u8) -> Self {
+    let b = u8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for MessageContent {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    u8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for MessageContent {}
+pub struct MessageContentUnionTableOffset {}
+
+pub enum MessageOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Message<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Message<'a> {
+  type Inner = Message<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> Message<'a> {
+  pub const VT_TIMESTAMP: flatbuffers::VOffsetT = 4;
+  pub const VT_CONTENT_TYPE: flatbuffers::VOffsetT = 6;
+  pub const VT_CONTENT: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Message { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args MessageArgs
+  ) -> flatbuffers::WIPOffset<Message<'bldr>> {
+    let mut builder = MessageBuilder::new(_fbb);
+    builder.add_timestamp(args.timestamp);
+    if let Some(x) = args.content { builder.add_content(x); }
+    builder.add_content_type(args.content_type);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn timestamp(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(Message::VT_TIMESTAMP, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn content_type(&self) -> MessageContent {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<MessageContent>(Message::VT_CONTENT_TYPE, Some(MessageContent::NONE)).unwrap()}
+  }
+  #[inline]
+  pub fn content(&self) -> Option<flatbuffers::Table<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(Message::VT_CONTENT, None)}
+  }
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn content_as_print(&self) -> Option<Print<'a>> {
+    if self.content_type() == MessageContent::Print {
+      self.content().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { Print::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn content_as_echo(&self) -> Option<Echo<'a>> {
+    if self.content_type() == MessageContent::Echo {
+      self.content().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { Echo::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn content_as_speaker_command(&self) -> Option<SpeakerCommand<'a>> {
+    if self.content_type() == MessageContent::SpeakerCommand {
+      self.content().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { SpeakerCommand::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn content_as_speaker_query(&self) -> Option<SpeakerQuery<'a>> {
+    if self.content_type() == MessageContent::SpeakerQuery {
+      self.content().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { SpeakerQuery::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn content_as_speaker_event(&self) -> Option<SpeakerEvent<'a>> {
+    if self.content_type() == MessageContent::SpeakerEvent {
+      self.content().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { SpeakerEvent::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn content_as_speaker_list_query(&self) -> Option<SpeakerListQuery<'a>> {
+    if self.content_type() == MessageContent::SpeakerListQuery {
+      self.content().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { SpeakerListQuery::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn content_as_speaker_list_event(&self) -> Option<SpeakerListEvent<'a>> {
+    if self.content_type() == MessageContent::SpeakerListEvent {
+      self.content().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { SpeakerListEvent::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn content_as_playlist_command(&self) -> Option<PlaylistCommand<'a>> {
+    if self.content_type() == MessageContent::PlaylistCommand {
+      self.content().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { PlaylistCommand::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn content_as_playlist_query(&self) -> Option<PlaylistQuery<'a>> {
+    if self.content_type() == MessageContent::PlaylistQuery {
+      self.content().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { PlaylistQuery::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn content_as_playlist_event(&self) -> Option<PlaylistEvent<'a>> {
+    if self.content_type() == MessageContent::PlaylistEvent {
+      self.content().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { PlaylistEvent::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn content_as_error(&self) -> Option<Error<'a>> {
+    if self.content_type() == MessageContent::Error {
+      self.content().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { Error::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn content_as_alarm_command(&self) -> Option<AlarmCommand<'a>> {
+    if self.content_type() == MessageContent::AlarmCommand {
+      self.content().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { AlarmCommand::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn content_as_alarm_query(&self) -> Option<AlarmQuery<'a>> {
+    if self.content_type() == MessageContent::AlarmQuery {
+      self.content().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { AlarmQuery::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn content_as_alarm_event(&self) -> Option<AlarmEvent<'a>> {
+    if self.content_type() == MessageContent::AlarmEvent {
+      self.content().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { AlarmEvent::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+}
+
+impl flatbuffers::Verifiable for Message<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u64>("timestamp", Self::VT_TIMESTAMP, false)?
+     .visit_union::<MessageContent, _>("content_type", Self::VT_CONTENT_TYPE, "content", Self::VT_CONTENT, false, |key, v, pos| {
+        match key {
+          MessageContent::Print => v.verify_union_variant::<flatbuffers::ForwardsUOffset<Print>>("MessageContent::Print", pos),
+          MessageContent::Echo => v.verify_union_variant::<flatbuffers::ForwardsUOffset<Echo>>("MessageContent::Echo", pos),
+          MessageContent::SpeakerCommand => v.verify_union_variant::<flatbuffers::ForwardsUOffset<SpeakerCommand>>("MessageContent::SpeakerCommand", pos),
+          MessageContent::SpeakerQuery => v.verify_union_variant::<flatbuffers::ForwardsUOffset<SpeakerQuery>>("MessageContent::SpeakerQuery", pos),
+          MessageContent::SpeakerEvent => v.verify_union_variant::<flatbuffers::ForwardsUOffset<SpeakerEvent>>("MessageContent::SpeakerEvent", pos),
+          MessageContent::SpeakerListQuery => v.verify_union_variant::<flatbuffers::ForwardsUOffset<SpeakerListQuery>>("MessageContent::SpeakerListQuery", pos),
+          MessageContent::SpeakerListEvent => v.verify_union_variant::<flatbuffers::ForwardsUOffset<SpeakerListEvent>>("MessageContent::SpeakerListEvent", pos),
+          MessageContent::PlaylistCommand => v.verify_union_variant::<flatbuffers::ForwardsUOffset<PlaylistCommand>>("MessageContent::PlaylistCommand", pos),
+          MessageContent::PlaylistQuery => v.verify_union_variant::<flatbuffers::ForwardsUOffset<PlaylistQuery>>("MessageContent::PlaylistQuery", pos),
+          MessageContent::PlaylistEvent => v.verify_union_variant::<flatbuffers::ForwardsUOffset<PlaylistEvent>>("MessageContent::PlaylistEvent", pos),
+          MessageContent::Error => v.verify_union_variant::<flatbuffers::ForwardsUOffset<Error>>("MessageContent::Error", pos),
+          MessageContent::AlarmCommand => v.verify_union_variant::<flatbuffers::ForwardsUOffset<AlarmCommand>>("MessageContent::AlarmCommand", pos),
+          MessageContent::AlarmQuery => v.verify_union_variant::<flatbuffers::ForwardsUOffset<AlarmQuery>>("MessageContent::AlarmQuery", pos),
+          MessageContent::AlarmEvent => v.verify_union_variant::<flatbuffers::ForwardsUOffset<AlarmEvent>>("MessageContent::AlarmEvent", pos),
+          _ => Ok(()),
+        }
+     })?
+     .finish();
+    Ok(())
+  }
+}
+pub struct MessageArgs {
+    pub timestamp: u64,
+    pub content_type: MessageContent,
+    pub content: Option<flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>>,
+}
+impl<'a> Default for MessageArgs {
+  #[inline]
+  fn default() -> Self {
+    MessageArgs {
+      timestamp: 0,
+      content_type: MessageContent::NONE,
+      content: None,
+    }
+  }
+}
+
+pub struct MessageBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> MessageBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_timestamp(&mut self, timestamp: u64) {
+    self.fbb_.push_slot::<u64>(Message::VT_TIMESTAMP, timestamp, 0);
+  }
+  #[inline]
+  pub fn add_content_type(&mut self, content_type: MessageContent) {
+    self.fbb_.push_slot::<MessageContent>(Message::VT_CONTENT_TYPE, content_type, MessageContent::NONE);
+  }
+  #[inline]
+  pub fn add_content(&mut self, content: flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Message::VT_CONTENT, content);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> MessageBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    MessageBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Message<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Message<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Message");
+      ds.field("timestamp", &self.timestamp());
+      ds.field("content_type", &self.content_type());
+      match self.content_type() {
+        MessageContent::Print => {
+          if let Some(x) = self.content_as_print() {
+            ds.field("content", &x)
+          } else {
+            ds.field("content", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        MessageContent::Echo => {
+          if let Some(x) = self.content_as_echo() {
+            ds.field("content", &x)
+          } else {
+            ds.field("content", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        MessageContent::SpeakerCommand => {
+          if let Some(x) = self.content_as_speaker_command() {
+            ds.field("content", &x)
+          } else {
+            ds.field("content", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        MessageContent::SpeakerQuery => {
+          if let Some(x) = self.content_as_speaker_query() {
+            ds.field("content", &x)
+          } else {
+            ds.field("content", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        MessageContent::SpeakerEvent => {
+          if let Some(x) = self.content_as_speaker_event() {
+            ds.field("content", &x)
+          } else {
+            ds.field("content", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        MessageContent::SpeakerListQuery => {
+          if let Some(x) = self.content_as_speaker_list_query() {
+            ds.field("content", &x)
+          } else {
+            ds.field("content", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        MessageContent::SpeakerListEvent => {
+          if let Some(x) = self.content_as_speaker_list_event() {
+            ds.field("content", &x)
+          } else {
+            ds.field("content", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        MessageContent::PlaylistCommand => {
+          if let Some(x) = self.content_as_playlist_command() {
+            ds.field("content", &x)
+          } else {
+            ds.field("content", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        MessageContent::PlaylistQuery => {
+          if let Some(x) = self.content_as_playlist_query() {
+            ds.field("content", &x)
+          } else {
+            ds.field("content", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        MessageContent::PlaylistEvent => {
+          if let Some(x) = self.content_as_playlist_event() {
+            ds.field("content", &x)
+          } else {
+            ds.field("content", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        MessageContent::Error => {
+          if let Some(x) = self.content_as_error() {
+            ds.field("content", &x)
+          } else {
+            ds.field("content", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        MessageContent::AlarmCommand => {
+          if let Some(x) = self.content_as_alarm_command() {
+            ds.field("content", &x)
+          } else {
+            ds.field("content", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        MessageContent::AlarmQuery => {
+          if let Some(x) = self.content_as_alarm_query() {
+            ds.field("content", &x)
+          } else {
+            ds.field("content", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        MessageContent::AlarmEvent => {
+          if let Some(x) = self.content_as_alarm_event() {
+            ds.field("content", &x)
+          } else {
+            ds.field("content", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        _ => {
+          let x: Option<()> = None;
+          ds.field("content", &x)
+        },
+      };
+      ds.finish()
+  }
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a `Message`
+/// and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_message_unchecked`.
+pub fn root_as_message(buf: &[u8]) -> Result<Message, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root::<Message>(buf)
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a size prefixed
+/// `Message` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `size_prefixed_root_as_message_unchecked`.
+pub fn size_prefixed_root_as_message(buf: &[u8]) -> Result<Message, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root::<Message>(buf)
+}
+#[inline]
+/// Verifies, with the given options, that a buffer of bytes
+/// contains a `Message` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_message_unchecked`.
+pub fn root_as_message_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<Message<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root_with_opts::<Message<'b>>(opts, buf)
+}
+#[inline]
+/// Verifies, with the given verifier options, that a buffer of
+/// bytes contains a size prefixed `Message` and returns
+/// it. Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_message_unchecked`.
+pub fn size_prefixed_root_as_message_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<Message<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root_with_opts::<Message<'b>>(opts, buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a Message and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid `Message`.
+pub unsafe fn root_as_message_unchecked(buf: &[u8]) -> Message {
+  flatbuffers::root_unchecked::<Message>(buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a size prefixed Message and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid size prefixed `Message`.
+pub unsafe fn size_prefixed_root_as_message_unchecked(buf: &[u8]) -> Message {
+  flatbuffers::size_prefixed_root_unchecked::<Message>(buf)
+}
+#[inline]
+pub fn finish_message_buffer<'a, 'b>(
+    fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    root: flatbuffers::WIPOffset<Message<'a>>) {
+  fbb.finish(root, None);
+}
+
+#[inline]
+pub fn finish_size_prefixed_message_buffer<'a, 'b>(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>, root: flatbuffers::WIPOffset<Message<'a>>) {
+  fbb.finish_size_prefixed(root, None);
+}
@@ -1,29 +1,34 @@
 extern crate flatbuffers;
 
-#[allow(dead_code, unused_imports)]
+#[allow(dead_code, unused_imports, warnings, clippy::all)]
 #[path = "./schemas/msg_playlists_generated.rs"]
 mod msg_playlists_generated;
 
-#[allow(dead_code, unused_imports)]
+#[allow(dead_code, unused_imports, warnings, clippy::all)]
 #[path = "./schemas/msg_speakers_generated.rs"]
 mod msg_speakers_generated;
 
-#[allow(dead_code, unused_imports)]
+#[allow(dead_code, unused_imports, warnings, clippy::all)]
 #[path = "./schemas/msg_echo_generated.rs"]
 mod msg_echo_generated;
 
-#[allow(dead_code, unused_imports)]
+#[allow(dead_code, unused_imports, warnings, clippy::all)]
 #[path = "./schemas/msg_print_generated.rs"]
 mod msg_print_generated;
 
-#[allow(dead_code, unused_imports)]
+#[allow(dead_code, unused_imports, warnings, clippy::all)]
 #[path = "./schemas/msg_error_generated.rs"]
 mod msg_error_generated;
 
-#[allow(dead_code, unused_imports)]
+#[allow(dead_code, unused_imports, warnings, clippy::all)]
+#[path = "./schemas/msg_alarms_generated.rs"]
+mod msg_alarms_generated;
+
+#[allow(dead_code, unused_imports, warnings, clippy::all)]
 #[path = "./schemas/root_generated.rs"]
 mod root_generated;
 
+pub use msg_alarms_generated::*;
 pub use msg_echo_generated::*;
 pub use msg_error_generated::*;
 pub use msg_playlists_generated::*;
@@ -76,7 +81,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
 fn process_message(message: &Message) -> String {
     let message_type = message.content_type();
-    const MAX_MESSAGE_CONTENT: u8 = MessageContent::ENUM_MAX as u8 + 1;
+    const MAX_MESSAGE_CONTENT: u8 = MessageContent::ENUM_MAX + 1;
 
     match message_type {
         MessageContent::NONE => "NONE".to_string(),
@@ -99,7 +104,14 @@ fn process_message(message: &Message) -> String {
 
         MessageContent::Error => {
             if let Some(error_message) = message.content_as_error() {
-                format_message_content("Error", error_message.message())
+                let mut formatted = format_message_content("Error", error_message.message());
+                if let Some(device_id) = error_message.device_id() {
+                    formatted.push_str(&format!(" (device: {})", device_id));
+                }
+                if error_message.index() >= 0 {
+                    formatted.push_str(&format!(" (index: {})", error_message.index()));
+                }
+                formatted
             } else {
                 error_message("Error", "content_as_error was None")
             }
@@ -115,7 +127,16 @@ fn process_message(message: &Message) -> String {
             if let Some(speaker_list_event) = message.content_as_speaker_list_event() {
                 if let Some(device_ids) = speaker_list_event.device_ids() {
                     let ids: Vec<&str> = device_ids.iter().collect();
-                    format!("SpeakerListEvent: device_ids={:?}", ids)
+                    let names: Vec<&str> = speaker_list_event
+                        .speakers()
+                        .map(|speakers| {
+                            speakers
+                                .iter()
+                                .map(|speaker| speaker.name().unwrap_or("{NO NAME}"))
+                                .collect()
+                        })
+                        .unwrap_or_default();
+                    format!("SpeakerListEvent: device_ids={:?}, names={:?}", ids, names)
                 } else {
                     error_message("SpeakerListEvent", "device_ids was None")
                 }
@@ -128,6 +149,10 @@ fn process_message(message: &Message) -> String {
         MessageContent::PlaylistQuery => handle_playlists_query(message),
         MessageContent::PlaylistEvent => handle_playlists_event(message),
 
+        MessageContent::AlarmCommand => handle_alarm_command(message),
+        MessageContent::AlarmQuery => handle_alarm_query(message),
+        MessageContent::AlarmEvent => handle_alarm_event(message),
+
         // Ensure exhaustive matching
         MessageContent(MAX_MESSAGE_CONTENT..=u8::MAX) => "UNKNOWN MessageContent".to_string(),
     }
@@ -144,7 +169,7 @@ fn error_message(content_type: &str, error: &str) -> String {
 
 fn handle_speaker_command(message: &Message) -> String {
     if let Some(speaker_command) = message.content_as_speaker_command() {
-        const MAX_SPEAKER_COMMAND_CONTENT: u8 = SpeakerCommandContent::ENUM_MAX as u8 + 1;
+        const MAX_SPEAKER_COMMAND_CONTENT: u8 = SpeakerCommandContent::ENUM_MAX + 1;
 
         match speaker_command.command_type() {
             SpeakerCommandContent::NONE => "SpeakerCommand: NONE".to_string(),
@@ -171,6 +196,155 @@ fn handle_speaker_command(message: &Message) -> String {
                 }
             }
 
+            SpeakerCommandContent::Preload => {
+                if let Some(preload) = speaker_command.command_as_preload() {
+                    format!("Preload: content={:?}", preload.content_type())
+                } else {
+                    error_message("Preload", "command_as_preload was None")
+                }
+            }
+
+            SpeakerCommandContent::SetCrossfade => {
+                if let Some(set_crossfade) = speaker_command.command_as_set_crossfade() {
+                    format!("SetCrossfade: duration={}", set_crossfade.duration())
+                } else {
+                    error_message("SetCrossfade", "command_as_set_crossfade was None")
+                }
+            }
+
+            SpeakerCommandContent::CreateSpeakerGroup => {
+                if let Some(create_speaker_group) =
+                    speaker_command.command_as_create_speaker_group()
+                {
+                    let device_ids: Vec<&str> = create_speaker_group
+                        .device_ids()
+                        .map(|device_ids| device_ids.iter().collect())
+                        .unwrap_or_default();
+                    format!("CreateSpeakerGroup: device_ids={:?}", device_ids)
+                } else {
+                    error_message(
+                        "CreateSpeakerGroup",
+                        "command_as_create_speaker_group was None",
+                    )
+                }
+            }
+
+            SpeakerCommandContent::DissolveSpeakerGroup => "DissolveSpeakerGroup".to_string(),
+
+            SpeakerCommandContent::ReloadDeviceFilter => "ReloadDeviceFilter".to_string(),
+
+            SpeakerCommandContent::SetHardwareVolume => {
+                if let Some(set_hardware_volume) = speaker_command.command_as_set_hardware_volume()
+                {
+                    format!("SetHardwareVolume: volume={}", set_hardware_volume.volume())
+                } else {
+                    error_message(
+                        "SetHardwareVolume",
+                        "command_as_set_hardware_volume was None",
+                    )
+                }
+            }
+
+            SpeakerCommandContent::SetMute => {
+                if let Some(set_mute) = speaker_command.command_as_set_mute() {
+                    format!("SetMute: muted={}", set_mute.muted())
+                } else {
+                    error_message("SetMute", "command_as_set_mute was None")
+                }
+            }
+
+            SpeakerCommandContent::SetSpeed => {
+                if let Some(set_speed) = speaker_command.command_as_set_speed() {
+                    format!("SetSpeed: speed={}", set_speed.speed())
+                } else {
+                    error_message("SetSpeed", "command_as_set_speed was None")
+                }
+            }
+
+            SpeakerCommandContent::SeekRelative => {
+                if let Some(seek_relative) = speaker_command.command_as_seek_relative() {
+                    format!("SeekRelative: offset={}", seek_relative.offset())
+                } else {
+                    error_message("SeekRelative", "command_as_seek_relative was None")
+                }
+            }
+
+            SpeakerCommandContent::SetAudioFilter => {
+                if let Some(set_audio_filter) = speaker_command.command_as_set_audio_filter() {
+                    format!(
+                        "SetAudioFilter: preset={}",
+                        set_audio_filter.preset().unwrap_or("{NO PRESET}")
+                    )
+                } else {
+                    error_message("SetAudioFilter", "command_as_set_audio_filter was None")
+                }
+            }
+
+            SpeakerCommandContent::SetSleepTimer => {
+                if let Some(set_sleep_timer) = speaker_command.command_as_set_sleep_timer() {
+                    format!(
+                        "SetSleepTimer: duration={}, fade={}",
+                        set_sleep_timer.duration(),
+                        set_sleep_timer.fade()
+                    )
+                } else {
+                    error_message("SetSleepTimer", "command_as_set_sleep_timer was None")
+                }
+            }
+
+            SpeakerCommandContent::CancelSleepTimer => "CancelSleepTimer".to_string(),
+
+            SpeakerCommandContent::PlayAnnouncement => {
+                if let Some(play_announcement) = speaker_command.command_as_play_announcement() {
+                    if let Some(play_youtube) = play_announcement.content_as_play_youtube() {
+                        let url = play_youtube.url().unwrap_or("{NO URL}");
+                        format!("PlayAnnouncement: url={}", url)
+                    } else if let Some(play_local_file) =
+                        play_announcement.content_as_play_local_file()
+                    {
+                        let path = play_local_file.path().unwrap_or("{NO PATH}");
+                        format!("PlayAnnouncement: path={}", path)
+                    } else if let Some(play_stream) = play_announcement.content_as_play_stream() {
+                        let url = play_stream.url().unwrap_or("{NO URL}");
+                        format!("PlayAnnouncement: stream={}", url)
+                    } else if let Some(play_dlna) = play_announcement.content_as_play_dlna() {
+                        let url = play_dlna.url().unwrap_or("{NO URL}");
+                        format!("PlayAnnouncement: dlna={}", url)
+                    } else {
+                        // We can't print raw WAV data
+                        "PlayAnnouncement: [RAW WAV DATA]".to_string()
+                    }
+                } else {
+                    error_message("PlayAnnouncement", "command_as_play_announcement was None")
+                }
+            }
+
+            SpeakerCommandContent::SetDucking => {
+                if let Some(set_ducking) = speaker_command.command_as_set_ducking() {
+                    format!("SetDucking: amount={}", set_ducking.amount())
+                } else {
+                    error_message("SetDucking", "command_as_set_ducking was None")
+                }
+            }
+
+            SpeakerCommandContent::UpdateSpeakerInfo => {
+                if let Some(update) = speaker_command.command_as_update_speaker_info() {
+                    format!(
+                        "UpdateSpeakerInfo: name={}, room={}, icon={}, default_volume={}, hidden={}",
+                        update.name().unwrap_or("{NO NAME}"),
+                        update.room().unwrap_or("{NO ROOM}"),
+                        update.icon().unwrap_or("{NO ICON}"),
+                        update.default_volume(),
+                        update.hidden()
+                    )
+                } else {
+                    error_message(
+                        "UpdateSpeakerInfo",
+                        "command_as_update_speaker_info was None",
+                    )
+                }
+            }
+
             // Ensure exhaustive matching
             SpeakerCommandContent(MAX_SPEAKER_COMMAND_CONTENT..=u8::MAX) => {
                 "UNKNOWN SpeakerCommandContent".to_string()
@@ -183,7 +357,7 @@ fn handle_speaker_command(message: &Message) -> String {
 
 fn handle_play_command(speaker_command: &SpeakerCommand) -> String {
     if let Some(play) = speaker_command.command_as_play() {
-        const MAX_PLAY_CONTENT: u8 = PlayContent::ENUM_MAX as u8 + 1;
+        const MAX_PLAY_CONTENT: u8 = PlayContent::ENUM_MAX + 1;
 
         match play.content_type() {
             PlayContent::NONE => "PlayContent: NONE".to_string(),
@@ -202,6 +376,33 @@ fn handle_play_command(speaker_command: &SpeakerCommand) -> String {
                 "PlayWav: [RAW WAV DATA]".to_string()
             }
 
+            PlayContent::PlayLocalFile => {
+                if let Some(play_local_file) = play.content_as_play_local_file() {
+                    let path = play_local_file.path().unwrap_or("{NO PATH}");
+                    format!("PlayLocalFile: path={}", path)
+                } else {
+                    error_message("PlayLocalFile", "content_as_play_local_file was None")
+                }
+            }
+
+            PlayContent::PlayStream => {
+                if let Some(play_stream) = play.content_as_play_stream() {
+                    let url = play_stream.url().unwrap_or("{NO URL}");
+                    format!("PlayStream: url={}", url)
+                } else {
+                    error_message("PlayStream", "content_as_play_stream was None")
+                }
+            }
+
+            PlayContent::PlayDlna => {
+                if let Some(play_dlna) = play.content_as_play_dlna() {
+                    let url = play_dlna.url().unwrap_or("{NO URL}");
+                    format!("PlayDlna: url={}", url)
+                } else {
+                    error_message("PlayDlna", "content_as_play_dlna was None")
+                }
+            }
+
             // Ensure exhaustive matching
             PlayContent(MAX_PLAY_CONTENT..=u8::MAX) => "UNKNOWN PlayContent".to_string(),
         }
@@ -212,7 +413,7 @@ fn handle_play_command(speaker_command: &SpeakerCommand) -> String {
 
 fn handle_speaker_query(message: &Message) -> String {
     if let Some(speaker_query) = message.content_as_speaker_query() {
-        const MAX_SPEAKER_QUERY_CONTENT: u8 = SpeakerQueryContent::ENUM_MAX as u8 + 1;
+        const MAX_SPEAKER_QUERY_CONTENT: u8 = SpeakerQueryContent::ENUM_MAX + 1;
 
         match speaker_query.query_type() {
             SpeakerQueryContent::NONE => "SpeakerQuery: NONE".to_string(),
@@ -227,6 +428,10 @@ fn handle_speaker_query(message: &Message) -> String {
 
             SpeakerQueryContent::QueryDuration => "QueryDuration".to_string(),
 
+            SpeakerQueryContent::QueryHardwareVolume => "QueryHardwareVolume".to_string(),
+
+            SpeakerQueryContent::QuerySleepTimer => "QuerySleepTimer".to_string(),
+
             // Ensure exhaustive matching
             SpeakerQueryContent(MAX_SPEAKER_QUERY_CONTENT..=u8::MAX) => {
                 "UNKNOWN SpeakerQueryContent".to_string()
@@ -239,7 +444,7 @@ fn handle_speaker_query(message: &Message) -> String {
 
 fn handle_speaker_event(message: &Message) -> String {
     if let Some(speaker_event) = message.content_as_speaker_event() {
-        const MAX_SPEAKER_EVENT_CONTENT: u8 = SpeakerEventContent::ENUM_MAX as u8 + 1;
+        const MAX_SPEAKER_EVENT_CONTENT: u8 = SpeakerEventContent::ENUM_MAX + 1;
 
         match speaker_event.event_type() {
             SpeakerEventContent::NONE => "SpeakerEvent: NONE".to_string(),
@@ -258,7 +463,18 @@ fn handle_speaker_event(message: &Message) -> String {
                 }
             }
 
-            SpeakerEventContent::PlayStarted => "PlayStarted".to_string(),
+            SpeakerEventContent::PlayStarted => {
+                if let Some(play_started) = speaker_event.event_as_play_started() {
+                    if let Some(play_youtube) = play_started.content_as_play_youtube() {
+                        let url = play_youtube.url().unwrap_or("{NO URL}");
+                        format!("PlayStarted: url={}", url)
+                    } else {
+                        "PlayStarted: [RAW WAV DATA]".to_string()
+                    }
+                } else {
+                    error_message("PlayStarted", "event_as_play_started was None")
+                }
+            }
 
             SpeakerEventContent::PlayStopped => "PlayStopped".to_string(),
 
@@ -286,6 +502,102 @@ fn handle_speaker_event(message: &Message) -> String {
                     error_message("PauseChanged", "event_as_pause_changed was None")
                 }
             }
+            SpeakerEventContent::PlaybackFailed => {
+                if let Some(playback_failed) = speaker_event.event_as_playback_failed() {
+                    format!(
+                        "PlaybackFailed: reason={}, error={}",
+                        playback_failed.reason().unwrap_or("{NO REASON}"),
+                        playback_failed.error().unwrap_or("{NO ERROR}")
+                    )
+                } else {
+                    error_message("PlaybackFailed", "event_as_playback_failed was None")
+                }
+            }
+            SpeakerEventContent::SpeakerGroupChanged => {
+                if let Some(speaker_group_changed) = speaker_event.event_as_speaker_group_changed()
+                {
+                    let device_ids: Vec<&str> = speaker_group_changed
+                        .device_ids()
+                        .map(|device_ids| device_ids.iter().collect())
+                        .unwrap_or_default();
+                    format!("SpeakerGroupChanged: device_ids={:?}", device_ids)
+                } else {
+                    error_message(
+                        "SpeakerGroupChanged",
+                        "event_as_speaker_group_changed was None",
+                    )
+                }
+            }
+            SpeakerEventContent::AnnouncementStarted => "AnnouncementStarted".to_string(),
+            SpeakerEventContent::AnnouncementEnded => "AnnouncementEnded".to_string(),
+            SpeakerEventContent::HardwareVolumeChanged => {
+                if let Some(hardware_volume_changed) =
+                    speaker_event.event_as_hardware_volume_changed()
+                {
+                    format!(
+                        "HardwareVolumeChanged: volume={}",
+                        hardware_volume_changed.volume()
+                    )
+                } else {
+                    error_message(
+                        "HardwareVolumeChanged",
+                        "event_as_hardware_volume_changed was None",
+                    )
+                }
+            }
+            SpeakerEventContent::MuteChanged => {
+                if let Some(mute_changed) = speaker_event.event_as_mute_changed() {
+                    format!("MuteChanged: muted={}", mute_changed.muted())
+                } else {
+                    error_message("MuteChanged", "event_as_mute_changed was None")
+                }
+            }
+            SpeakerEventContent::SpeedChanged => {
+                if let Some(speed_changed) = speaker_event.event_as_speed_changed() {
+                    format!("SpeedChanged: speed={}", speed_changed.speed())
+                } else {
+                    error_message("SpeedChanged", "event_as_speed_changed was None")
+                }
+            }
+            SpeakerEventContent::AudioFilterChanged => {
+                if let Some(audio_filter_changed) = speaker_event.event_as_audio_filter_changed() {
+                    format!(
+                        "AudioFilterChanged: preset={}",
+                        audio_filter_changed.preset().unwrap_or("{NO PRESET}")
+                    )
+                } else {
+                    error_message(
+                        "AudioFilterChanged",
+                        "event_as_audio_filter_changed was None",
+                    )
+                }
+            }
+            SpeakerEventContent::NowPlaying => {
+                if let Some(now_playing) = speaker_event.event_as_now_playing() {
+                    format!(
+                        "NowPlaying: title={}, stream_title={}, chapter={}/{}, chapter_title={}",
+                        now_playing.title().unwrap_or("{NO TITLE}"),
+                        now_playing.stream_title().unwrap_or("{NO STREAM TITLE}"),
+                        now_playing.chapter(),
+                        now_playing.chapters(),
+                        now_playing.chapter_title().unwrap_or("{NO CHAPTER TITLE}")
+                    )
+                } else {
+                    error_message("NowPlaying", "event_as_now_playing was None")
+                }
+            }
+            SpeakerEventContent::SleepTimerSet => {
+                if let Some(sleep_timer_set) = speaker_event.event_as_sleep_timer_set() {
+                    format!(
+                        "SleepTimerSet: remaining={}, fade={}",
+                        sleep_timer_set.remaining(),
+                        sleep_timer_set.fade()
+                    )
+                } else {
+                    error_message("SleepTimerSet", "event_as_sleep_timer_set was None")
+                }
+            }
+            SpeakerEventContent::SleepTimerFired => "SleepTimerFired".to_string(),
 
             // Ensure exhaustive matching
             SpeakerEventContent(MAX_SPEAKER_EVENT_CONTENT..=u8::MAX) => {
@@ -305,16 +617,20 @@ pub fn handle_song(song: Song) -> String {
         None => "{NO THUMBNAIL}".to_string(),
     };
     format!(
-        "Song: title={}, url={}, thumbnail_b64={}",
-        title, url, thumbnail
+        "Song: id={}, title={}, url={}, thumbnail_b64={}, one_shot={}",
+        song.id().unwrap_or("{NO ID}"),
+        title,
+        url,
+        thumbnail,
+        song.one_shot()
     )
 }
 
 pub fn handle_playlists_command(message: &Message) -> String {
     if let Some(playlists_command) = message.content_as_playlist_command() {
-        const MAX_PLAYLISTS_COMMAND_CONTENT: u8 = PlaylistCommandContent::ENUM_MAX as u8 + 1;
+        const MAX_PLAYLISTS_COMMAND_CONTENT: u8 = PlaylistCommandContent::ENUM_MAX + 1;
 
-        match playlists_command.command_type() {
+        let command = match playlists_command.command_type() {
             PlaylistCommandContent::NONE => "PlaylistCommand: NONE".to_string(),
 
             PlaylistCommandContent::AddSong => {
@@ -330,7 +646,11 @@ pub fn handle_playlists_command(message: &Message) -> String {
 
             PlaylistCommandContent::RemoveSong => {
                 if let Some(remove_song) = playlists_command.command_as_remove_song() {
-                    format!("RemoveSong: index={}", remove_song.index())
+                    format!(
+                        "RemoveSong: index={} id={}",
+                        remove_song.index(),
+                        remove_song.id().unwrap_or("{NO ID}")
+                    )
                 } else {
                     error_message("RemoveSong", "command_as_remove_song was None")
                 }
@@ -339,11 +659,12 @@ pub fn handle_playlists_command(message: &Message) -> String {
             PlaylistCommandContent::ReplaceSong => {
                 if let Some(replace_song) = playlists_command.command_as_replace_song() {
                     format!(
-                        "ReplaceSong: song={{{}}} index={}",
+                        "ReplaceSong: song={{{}}} index={} id={}",
                         replace_song
                             .song()
                             .map_or("{NO SONG}".to_string(), handle_song),
-                        replace_song.index()
+                        replace_song.index(),
+                        replace_song.id().unwrap_or("{NO ID}")
                     )
                 } else {
                     error_message("ReplaceSong", "command_as_replace_song was None")
@@ -353,20 +674,72 @@ pub fn handle_playlists_command(message: &Message) -> String {
             PlaylistCommandContent::InsertSong => {
                 if let Some(insert_song) = playlists_command.command_as_insert_song() {
                     format!(
-                        "InsertSong: song={{{}}} index={}",
+                        "InsertSong: song={{{}}} index={} before_id={}",
                         insert_song
                             .song()
                             .map_or("{NO SONG}".to_string(), handle_song),
-                        insert_song.index()
+                        insert_song.index(),
+                        insert_song.before_id().unwrap_or("{NO ID}")
                     )
                 } else {
                     error_message("InsertSong", "command_as_insert_song was None")
                 }
             }
 
+            PlaylistCommandContent::SetRepeatMode => {
+                if let Some(set_repeat_mode) = playlists_command.command_as_set_repeat_mode() {
+                    format!("SetRepeatMode: mode={:?}", set_repeat_mode.mode())
+                } else {
+                    error_message("SetRepeatMode", "command_as_set_repeat_mode was None")
+                }
+            }
+
+            PlaylistCommandContent::SetShuffle => {
+                if let Some(set_shuffle) = playlists_command.command_as_set_shuffle() {
+                    format!("SetShuffle: shuffle={}", set_shuffle.shuffle())
+                } else {
+                    error_message("SetShuffle", "command_as_set_shuffle was None")
+                }
+            }
+
+            PlaylistCommandContent::MoveSong => {
+                if let Some(move_song) = playlists_command.command_as_move_song() {
+                    format!(
+                        "MoveSong: from={} to={} id={}",
+                        move_song.from(),
+                        move_song.to(),
+                        move_song.id().unwrap_or("{NO ID}")
+                    )
+                } else {
+                    error_message("MoveSong", "command_as_move_song was None")
+                }
+            }
+
+            PlaylistCommandContent::ClearPlaylist => "ClearPlaylist".to_string(),
+
+            PlaylistCommandContent::SkipToIndex => {
+                if let Some(skip_to_index) = playlists_command.command_as_skip_to_index() {
+                    format!(
+                        "SkipToIndex: index={} id={}",
+                        skip_to_index.index(),
+                        skip_to_index.id().unwrap_or("{NO ID}")
+                    )
+                } else {
+                    error_message("SkipToIndex", "command_as_skip_to_index was None")
+                }
+            }
+
+            PlaylistCommandContent::Next => "Next".to_string(),
+
+            PlaylistCommandContent::Previous => "Previous".to_string(),
+
             PlaylistCommandContent(MAX_PLAYLISTS_COMMAND_CONTENT..=u8::MAX) => {
                 "UNKNOWN PlaylistCommandContent".to_string()
             }
+        };
+        match playlists_command.expected_revision() {
+            -1 => command,
+            revision => format!("{} expected_revision={}", command, revision),
         }
     } else {
         error_message("PlaylistCommand", "content_as_playlist_command was None")
@@ -375,7 +748,7 @@ pub fn handle_playlists_command(message: &Message) -> String {
 
 pub fn handle_playlists_query(message: &Message) -> String {
     if let Some(playlists_query) = message.content_as_playlist_query() {
-        const MAX_PLAYLISTS_QUERY_CONTENT: u8 = PlaylistQueryContent::ENUM_MAX as u8 + 1;
+        const MAX_PLAYLISTS_QUERY_CONTENT: u8 = PlaylistQueryContent::ENUM_MAX + 1;
 
         match playlists_query.query_type() {
             PlaylistQueryContent::NONE => "PlaylistQuery: NONE".to_string(),
@@ -393,7 +766,7 @@ pub fn handle_playlists_query(message: &Message) -> String {
 
 pub fn handle_playlists_event(message: &Message) -> String {
     if let Some(playlists_event) = message.content_as_playlist_event() {
-        const MAX_PLAYLISTS_EVENT_CONTENT: u8 = PlaylistEventContent::ENUM_MAX as u8 + 1;
+        const MAX_PLAYLISTS_EVENT_CONTENT: u8 = PlaylistEventContent::ENUM_MAX + 1;
 
         match playlists_event.event_type() {
             PlaylistEventContent::NONE => "PlaylistEvent: NONE".to_string(),
@@ -403,13 +776,16 @@ pub fn handle_playlists_event(message: &Message) -> String {
                     playlists_event.event_as_playlist_state_changed()
                 {
                     format!(
-                        "PlaylistStateChanged: songs=[{}]",
+                        "PlaylistStateChanged: songs=[{}] repeat={:?} shuffle={} revision={}",
                         playlist_state_changed
                             .songs()
                             .map_or("{NO SONGS}".to_string(), |songs| {
                                 let songs: Vec<String> = songs.iter().map(handle_song).collect();
                                 songs.join(", ")
-                            })
+                            }),
+                        playlist_state_changed.repeat(),
+                        playlist_state_changed.shuffle(),
+                        playlist_state_changed.revision()
                     )
                 } else {
                     error_message(
@@ -428,6 +804,126 @@ pub fn handle_playlists_event(message: &Message) -> String {
     }
 }
 
+pub fn handle_alarm(alarm: Alarm) -> String {
+    let days: Vec<u8> = alarm
+        .days()
+        .map(|days| days.iter().collect())
+        .unwrap_or_default();
+    format!(
+        "Alarm: id={}, device_id={}, days={:?}, time={}:{:02}, url={}, volume={}, ramp_volume={}, ramp_duration={}, enabled={}",
+        alarm.id().unwrap_or("{NO ID}"),
+        alarm.device_id().unwrap_or("{NO DEVICE ID}"),
+        days,
+        alarm.hour(),
+        alarm.minute(),
+        alarm.url().unwrap_or("{NO URL}"),
+        alarm.volume(),
+        alarm.ramp_volume(),
+        alarm.ramp_duration(),
+        alarm.enabled()
+    )
+}
+
+pub fn handle_alarm_command(message: &Message) -> String {
+    if let Some(alarm_command) = message.content_as_alarm_command() {
+        const MAX_ALARM_COMMAND_CONTENT: u8 = AlarmCommandContent::ENUM_MAX + 1;
+
+        match alarm_command.command_type() {
+            AlarmCommandContent::NONE => "AlarmCommand: NONE".to_string(),
+
+            AlarmCommandContent::CreateAlarm => {
+                if let Some(create_alarm) = alarm_command.command_as_create_alarm() {
+                    format!(
+                        "CreateAlarm: alarm={{{}}}",
+                        create_alarm
+                            .alarm()
+                            .map_or("{NO ALARM}".to_string(), handle_alarm)
+                    )
+                } else {
+                    error_message("CreateAlarm", "command_as_create_alarm was None")
+                }
+            }
+
+            AlarmCommandContent::DeleteAlarm => {
+                if let Some(delete_alarm) = alarm_command.command_as_delete_alarm() {
+                    format!("DeleteAlarm: id={}", delete_alarm.id().unwrap_or("{NO ID}"))
+                } else {
+                    error_message("DeleteAlarm", "command_as_delete_alarm was None")
+                }
+            }
+
+            AlarmCommandContent(MAX_ALARM_COMMAND_CONTENT..=u8::MAX) => {
+                "UNKNOWN AlarmCommandContent".to_string()
+            }
+        }
+    } else {
+        error_message("AlarmCommand", "content_as_alarm_command was None")
+    }
+}
+
+pub fn handle_alarm_query(message: &Message) -> String {
+    if let Some(alarm_query) = message.content_as_alarm_query() {
+        const MAX_ALARM_QUERY_CONTENT: u8 = AlarmQueryContent::ENUM_MAX + 1;
+
+        match alarm_query.query_type() {
+            AlarmQueryContent::NONE => "AlarmQuery: NONE".to_string(),
+
+            AlarmQueryContent::QueryAlarms => "QueryAlarms".to_string(),
+
+            AlarmQueryContent(MAX_ALARM_QUERY_CONTENT..=u8::MAX) => {
+                "UNKNOWN AlarmQueryContent".to_string()
+            }
+        }
+    } else {
+        error_message("AlarmQuery", "content_as_alarm_query was None")
+    }
+}
+
+pub fn handle_alarm_event(message: &Message) -> String {
+    if let Some(alarm_event) = message.content_as_alarm_event() {
+        const MAX_ALARM_EVENT_CONTENT: u8 = AlarmEventContent::ENUM_MAX + 1;
+
+        match alarm_event.event_type() {
+            AlarmEventContent::NONE => "AlarmEvent: NONE".to_string(),
+
+            AlarmEventContent::AlarmsChanged => {
+                if let Some(alarms_changed) = alarm_event.event_as_alarms_changed() {
+                    format!(
+                        "AlarmsChanged: alarms=[{}]",
+                        alarms_changed
+                            .alarms()
+                            .map_or("{NO ALARMS}".to_string(), |alarms| {
+                                let alarms: Vec<String> = alarms.iter().map(handle_alarm).collect();
+                                alarms.join(", ")
+                            })
+                    )
+                } else {
+                    error_message("AlarmsChanged", "event_as_alarms_changed was None")
+                }
+            }
+
+            AlarmEventContent::AlarmFired => {
+                if let Some(alarm_fired) = alarm_event.event_as_alarm_fired() {
+                    format!(
+                        "AlarmFired: alarm={{{}}}",
+                        alarm_fired
+                            .alarm()
+                            .map_or("{NO ALARM}".to_string(), handle_alarm)
+                    )
+                } else {
+                    error_message("AlarmFired", "event_as_alarm_fired was None")
+                }
+            }
+
+            AlarmEventContent(MAX_ALARM_EVENT_CONTENT..=u8::MAX) => {
+                "UNKNOWN AlarmEventContent".to_string()
+            }
+        }
+    } else {
+        error_message("AlarmEvent", "content_as_alarm_event was None")
+    }
+}
+
 fn send_echo_message(
     nc: &nats::Connection,
     timestamp: u64,
@@ -27,6 +27,8 @@ impl<'a> flatbuffers::Follow<'a> for Error<'a> {
 impl<'a> Error<'a> {
   pub const VT_FROM: flatbuffers::VOffsetT = 4;
   pub const VT_MESSAGE: flatbuffers::VOffsetT = 6;
+  pub const VT_DEVICE_ID: flatbuffers::VOffsetT = 8;
+  pub const VT_INDEX: flatbuffers::VOffsetT = 10;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -38,6 +40,8 @@ impl<'a> Error<'a> {
     args: &'args ErrorArgs<'args>
   ) -> flatbuffers::WIPOffset<Error<'bldr>> {
     let mut builder = ErrorBuilder::new(_fbb);
+    builder.add_index(args.index);
+    if let Some(x) = args.device_id { builder.add_device_id(x); }
     if let Some(x) = args.message { builder.add_message(x); }
     if let Some(x) = args.from { builder.add_from(x); }
     builder.finish()
@@ -58,6 +62,20 @@ impl<'a> Error<'a> {
     // which contains a valid value in this slot
     unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Error::VT_MESSAGE, None)}
   }
+  #[inline]
+  pub fn device_id(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Error::VT_DEVICE_ID, None)}
+  }
+  #[inline]
+  pub fn index(&self) -> i32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i32>(Error::VT_INDEX, Some(-1)).unwrap()}
+  }
 }
 
 impl flatbuffers::Verifiable for Error<'_> {
@@ -69,6 +87,8 @@ impl flatbuffers::Verifiable for Error<'_> {
     v.visit_table(pos)?
      .visit_field::<flatbuffers::ForwardsUOffset<&str>>("from", Self::VT_FROM, false)?
      .visit_field::<flatbuffers::ForwardsUOffset<&str>>("message", Self::VT_MESSAGE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("device_id", Self::VT_DEVICE_ID, false)?
+     .visit_field::<i32>("index", Self::VT_INDEX, false)?
      .finish();
     Ok(())
   }
@@ -76,6 +96,8 @@ impl flatbuffers::Verifiable for Error<'_> {
 pub struct ErrorArgs<'a> {
     pub from: Option<flatbuffers::WIPOffset<&'a str>>,
     pub message: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub device_id: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub index: i32,
 }
 impl<'a> Default for ErrorArgs<'a> {
   #[inline]
@@ -83,6 +105,8 @@ impl<'a> Default for ErrorArgs<'a> {
     ErrorArgs {
       from: None,
       message: None,
+      device_id: None,
+      index: -1,
     }
   }
 }
@@ -101,6 +125,14 @@ impl<'a: 'b, 'b> ErrorBuilder<'a, 'b> {
     self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Error::VT_MESSAGE, message);
   }
   #[inline]
+  pub fn add_device_id(&mut self, device_id: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Error::VT_DEVICE_ID, device_id);
+  }
+  #[inline]
+  pub fn add_index(&mut self, index: i32) {
+    self.fbb_.push_slot::<i32>(Error::VT_INDEX, index, -1);
+  }
+  #[inline]
   pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ErrorBuilder<'a, 'b> {
     let start = _fbb.start_table();
     ErrorBuilder {
@@ -120,6 +152,8 @@ impl core::fmt::Debug for Error<'_> {
     let mut ds = f.debug_struct("Error");
       ds.field("from", &self.from());
       ds.field("message", &self.message());
+      ds.field("device_id", &self.device_id());
+      ds.field("index", &self.index());
       ds.finish()
   }
 }
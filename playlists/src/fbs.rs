@@ -1,15 +1,25 @@
 use nats::Connection;
 
 use crate::{
-    Error, ErrorArgs, FileEnded, FileEndedArgs, Message, MessageArgs, MessageContent,
-    MusicVolumeChanged, MusicVolumeChangedArgs, PauseChanged, PauseChangedArgs, Play, PlayArgs,
-    PlayContent, PlayStopped, PlayStoppedArgs, PlayYoutube, PlayYoutubeArgs, PlaylistEvent,
-    PlaylistEventArgs, PlaylistEventContent, PlaylistStateChanged, PlaylistStateChangedArgs,
-    SeekChanged, SeekChangedArgs, Song, SongArgs, SongInternal, SpeakerCommand, SpeakerCommandArgs,
-    SpeakerCommandContent, SpeakerEvent, SpeakerEventArgs, SpeakerEventContent, SpeakerListEvent,
-    SpeakerListEventArgs, Stop, StopArgs,
+    playlist::{Playlist, PlaylistError},
+    Error, ErrorArgs, Message, MessageArgs, MessageContent, Play, PlayArgs, PlayContent,
+    PlayYoutube, PlayYoutubeArgs, PlaylistEvent, PlaylistEventArgs, PlaylistEventContent,
+    PlaylistStateChanged, PlaylistStateChangedArgs, Preload, PreloadArgs, Song, SongArgs,
+    SpeakerCommand, SpeakerCommandArgs, SpeakerCommandContent, SpeakerListQuery,
+    SpeakerListQueryArgs, Stop, StopArgs,
 };
 
+/// Where the playlist's commands and events go, the NATS connection outside of tests.
+pub trait EventPublisher: Send + Sync {
+    fn publish(&self, topic: &str, message: Vec<u8>);
+}
+
+impl EventPublisher for Connection {
+    fn publish(&self, topic: &str, message: Vec<u8>) {
+        Connection::publish(self, topic, message).unwrap_nc(self, "playlist");
+    }
+}
+
 pub trait NcSendable {
     fn send(self, nc: &Connection, topic: &str, from: &str);
 }
@@ -47,15 +57,37 @@ impl<T, E: ToString> UnwrapNc<T> for Result<T, E> {
 }
 
 pub fn construct_error_message(error: &str, from: &str) -> Vec<u8> {
+    construct_error(error, from, None, None)
+}
+
+/// Error for a rejected playlist message, naming the device and index it was about.
+pub fn construct_playlist_error_message(error: &PlaylistError) -> Vec<u8> {
+    construct_error(
+        &error.message,
+        "playlist",
+        error.device_id.as_deref(),
+        error.index,
+    )
+}
+
+fn construct_error(
+    error: &str,
+    from: &str,
+    device_id: Option<&str>,
+    index: Option<i32>,
+) -> Vec<u8> {
     let mut fbb = flatbuffers::FlatBufferBuilder::new();
     let error_str = fbb.create_string(error);
     let from_str = fbb.create_string(from);
+    let device_id_str = device_id.map(|device_id| fbb.create_string(device_id));
 
     let error = Error::create(
         &mut fbb,
         &ErrorArgs {
             from: Some(from_str),
             message: Some(error_str),
+            device_id: device_id_str,
+            index: index.unwrap_or(-1),
         },
     );
 
@@ -79,6 +111,11 @@ pub fn send_error_message(nc: &Connection, error: &str, from: &str) {
     nc.publish("error", error_message).unwrap();
 }
 
+pub fn send_playlist_error_message(nc: &Connection, error: &PlaylistError) {
+    nc.publish("error", construct_playlist_error_message(error))
+        .unwrap();
+}
+
 fn get_current_timestamp() -> u64 {
     std::time::SystemTime::now()
         .duration_since(std::time::UNIX_EPOCH)
@@ -127,6 +164,46 @@ pub fn construct_play_youtube_song_command(url: String, device_id: String) -> Ve
     fbb.finished_data().to_vec()
 }
 
+pub fn construct_preload_command(url: String, device_id: String) -> Vec<u8> {
+    let mut fbb = flatbuffers::FlatBufferBuilder::new();
+    let url_str = fbb.create_string(&url);
+
+    let play_youtube = PlayYoutube::create(&mut fbb, &PlayYoutubeArgs { url: Some(url_str) });
+
+    let preload_command = Preload::create(
+        &mut fbb,
+        &PreloadArgs {
+            content_type: PlayContent::PlayYoutube,
+            content: Some(play_youtube.as_union_value()),
+        },
+    );
+
+    let device_id_str = fbb.create_string(&device_id);
+
+    let command = SpeakerCommand::create(
+        &mut fbb,
+        &SpeakerCommandArgs {
+            device_id: Some(device_id_str),
+            command_type: SpeakerCommandContent::Preload,
+            command: Some(preload_command.as_union_value()),
+        },
+    );
+
+    let timestamp = get_current_timestamp();
+
+    let root = Message::create(
+        &mut fbb,
+        &MessageArgs {
+            timestamp,
+            content_type: MessageContent::SpeakerCommand,
+            content: Some(command.as_union_value()),
+        },
+    );
+    fbb.finish(root, None);
+
+    fbb.finished_data().to_vec()
+}
+
 pub fn construct_stop_command(device_id: String) -> Vec<u8> {
     let mut fbb = flatbuffers::FlatBufferBuilder::new();
 
@@ -158,16 +235,34 @@ pub fn construct_stop_command(device_id: String) -> Vec<u8> {
     fbb.finished_data().to_vec()
 }
 
-pub fn construct_playlist_updated_event(
-    playlist: &Vec<SongInternal>,
-    device_id: String,
-) -> Vec<u8> {
+pub fn construct_speaker_list_query() -> Vec<u8> {
+    let mut fbb = flatbuffers::FlatBufferBuilder::new();
+
+    let query = SpeakerListQuery::create(&mut fbb, &SpeakerListQueryArgs {});
+
+    let timestamp = get_current_timestamp();
+
+    let root = Message::create(
+        &mut fbb,
+        &MessageArgs {
+            timestamp,
+            content_type: MessageContent::SpeakerListQuery,
+            content: Some(query.as_union_value()),
+        },
+    );
+    fbb.finish(root, None);
+
+    fbb.finished_data().to_vec()
+}
+
+pub fn construct_playlist_updated_event(playlist: &Playlist, device_id: String) -> Vec<u8> {
     let mut fbb = flatbuffers::FlatBufferBuilder::new();
     let mut songs = Vec::new();
-    for song in playlist {
+    for song in &playlist.songs {
         let url_str = fbb.create_string(&song.url);
         let title_str = fbb.create_string(&song.title);
         let thumbnail_b64_str = fbb.create_string(&song.thumbnail_b64);
+        let id_str = fbb.create_string(&song.id);
 
         let song = Song::create(
             &mut fbb,
@@ -175,6 +270,8 @@ pub fn construct_playlist_updated_event(
                 url: Some(url_str),
                 title: Some(title_str),
                 thumbnail_b64: Some(thumbnail_b64_str),
+                id: Some(id_str),
+                one_shot: song.one_shot,
             },
         );
         songs.push(song);
@@ -182,8 +279,15 @@ pub fn construct_playlist_updated_event(
 
     let vec = fbb.create_vector(&songs);
 
-    let playlist =
-        PlaylistStateChanged::create(&mut fbb, &PlaylistStateChangedArgs { songs: Some(vec) });
+    let playlist = PlaylistStateChanged::create(
+        &mut fbb,
+        &PlaylistStateChangedArgs {
+            songs: Some(vec),
+            repeat: playlist.repeat.to_fbs(),
+            shuffle: playlist.shuffle,
+            revision: playlist.revision,
+        },
+    );
 
     let device_id_str = fbb.create_string(&device_id);
 
@@ -0,0 +1,572 @@
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::{RepeatMode, SongInternal};
+
+// Songs kept for Previous
+const HISTORY_LEN: usize = 50;
+
+// `expected_revision` of commands that don't check the revision
+const NO_EXPECTED_REVISION: i64 = -1;
+
+/// `RepeatMode` as kept on disk.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Default)]
+pub enum Repeat {
+    #[default]
+    Off,
+    One,
+    All,
+}
+
+impl Repeat {
+    pub fn from_fbs(mode: RepeatMode) -> Result<Self, String> {
+        match mode {
+            RepeatMode::Off => Ok(Self::Off),
+            RepeatMode::One => Ok(Self::One),
+            RepeatMode::All => Ok(Self::All),
+            RepeatMode(mode) => Err(format!("Invalid repeat mode {}", mode)),
+        }
+    }
+
+    pub fn to_fbs(self) -> RepeatMode {
+        match self {
+            Self::Off => RepeatMode::Off,
+            Self::One => RepeatMode::One,
+            Self::All => RepeatMode::All,
+        }
+    }
+}
+
+/// Why a playlist message was rejected, sent back on "error" as an `Error`.
+#[derive(Debug)]
+pub struct PlaylistError {
+    pub message: String,
+    pub device_id: Option<String>,
+    pub index: Option<i32>,
+}
+
+impl PlaylistError {
+    pub fn invalid_index(index: i32, len: usize) -> Self {
+        Self {
+            message: format!(
+                "Index {} is out of range for a playlist of {} songs",
+                index, len
+            ),
+            device_id: None,
+            index: Some(index),
+        }
+    }
+
+    pub fn revision_conflict(revision: u64, expected: i64) -> Self {
+        format!(
+            "Playlist is at revision {}, the command expected {}",
+            revision, expected
+        )
+        .into()
+    }
+
+    pub fn for_device(mut self, device_id: &str) -> Self {
+        self.device_id.get_or_insert_with(|| device_id.to_string());
+        self
+    }
+}
+
+impl From<String> for PlaylistError {
+    fn from(message: String) -> Self {
+        Self {
+            message,
+            device_id: None,
+            index: None,
+        }
+    }
+}
+
+impl From<&str> for PlaylistError {
+    fn from(message: &str) -> Self {
+        message.to_string().into()
+    }
+}
+
+/// Checks an index from the wire, which can be negative or stale, against a length.
+fn check_index(index: i32, len: usize) -> Result<usize, PlaylistError> {
+    usize::try_from(index)
+        .ok()
+        .filter(|&index| index < len)
+        .ok_or_else(|| PlaylistError::invalid_index(index, len))
+}
+
+/// Why the song at position 0 is left behind.
+#[derive(Clone, Copy, PartialEq)]
+pub enum SongEnd {
+    Finished,
+    Failed,
+    Skipped,
+}
+
+/// A device's queue and play mode. The song at position 0 is the one playing.
+#[derive(Serialize, Deserialize, Default)]
+pub struct Playlist {
+    pub songs: Vec<SongInternal>,
+    #[serde(default)]
+    pub repeat: Repeat,
+    #[serde(default)]
+    pub shuffle: bool,
+    /// Songs played before the current one, most recent last
+    #[serde(default)]
+    pub history: Vec<SongInternal>,
+    /// Goes up with every change, so clients can tell whether they edit what they last saw
+    #[serde(default)]
+    pub revision: u64,
+    // Counter behind the ids handed to songs
+    #[serde(default)]
+    pub last_id: u64,
+}
+
+impl Playlist {
+    /// The song to play once the current one ends, which the speaker is asked to preload.
+    pub fn next_song(&self) -> Option<&SongInternal> {
+        let head = self.songs.first()?;
+        match self.repeat {
+            _ if head.one_shot => self.songs.get(1),
+            Repeat::Off => self.songs.get(1),
+            Repeat::One => Some(head),
+            Repeat::All => self.songs.get(1).or(Some(head)),
+        }
+    }
+
+    /// Moves past the song at position 0, as the play mode says. Repeat one only holds on to
+    /// songs that finished, and songs that failed to play are dropped whatever the mode so a
+    /// broken url can't loop forever. One-shot songs are dropped as well, without history.
+    pub fn advance(&mut self, end: SongEnd) {
+        if self.songs.is_empty() {
+            return;
+        }
+        match (self.repeat, end) {
+            _ if self.songs[0].one_shot => {
+                self.songs.remove(0);
+            }
+            (_, SongEnd::Failed) => {
+                self.songs.remove(0);
+            }
+            (Repeat::One, SongEnd::Finished) => return,
+            (Repeat::All, _) => {
+                self.remember(vec![self.songs[0].clone()]);
+                self.songs.rotate_left(1);
+            }
+            (Repeat::Off | Repeat::One, _) => {
+                let song = self.songs.remove(0);
+                self.remember(vec![song]);
+            }
+        }
+        self.shuffle_next();
+    }
+
+    /// Rejects a command made against another revision, unless it skips the check with -1.
+    pub fn check_revision(&self, expected: i64) -> Result<(), PlaylistError> {
+        if expected == NO_EXPECTED_REVISION {
+            return Ok(());
+        }
+        match u64::try_from(expected) {
+            Ok(revision) if revision == self.revision => Ok(()),
+            Ok(_) => Err(PlaylistError::revision_conflict(self.revision, expected)),
+            // Only -1 means no expectation, other negatives come from a confused client
+            Err(_) => Err(format!("Invalid expected revision {}", expected).into()),
+        }
+    }
+
+    /// The index of the song with `id` if one is given, otherwise `index` as it came.
+    pub fn target(&self, index: i32, id: Option<&str>) -> Result<i32, PlaylistError> {
+        match id.filter(|id| !id.is_empty()) {
+            Some(id) => self
+                .songs
+                .iter()
+                .position(|song| song.id == id)
+                .map(|position| position as i32)
+                .ok_or_else(|| format!("No song with id {} in the playlist", id).into()),
+            None => Ok(index),
+        }
+    }
+
+    /// Gives songs stored before they had ids one.
+    pub fn assign_missing_ids(&mut self) {
+        for index in 0..self.songs.len() {
+            if self.songs[index].id.is_empty() {
+                self.songs[index].id = self.new_id();
+            }
+        }
+    }
+
+    fn new_id(&mut self) -> String {
+        self.last_id += 1;
+        format!("song-{}", self.last_id)
+    }
+
+    pub fn add_song(&mut self, mut song: SongInternal) {
+        song.id = self.new_id();
+        self.songs.push(song);
+    }
+
+    /// Puts `song` at `index`, where the length of the playlist appends it.
+    pub fn insert_song(&mut self, index: i32, mut song: SongInternal) -> Result<(), PlaylistError> {
+        let index = check_index(index, self.songs.len() + 1)?;
+        song.id = self.new_id();
+        self.songs.insert(index, song);
+        Ok(())
+    }
+
+    /// Swaps the song at `index` for `song`, where the length of the playlist appends it.
+    pub fn replace_song(
+        &mut self,
+        index: i32,
+        mut song: SongInternal,
+    ) -> Result<(), PlaylistError> {
+        let index = check_index(index, self.songs.len() + 1)?;
+        song.id = self.new_id();
+        if index < self.songs.len() {
+            self.songs[index] = song;
+        } else {
+            self.songs.push(song);
+        }
+        Ok(())
+    }
+
+    pub fn remove_song(&mut self, index: i32) -> Result<(), PlaylistError> {
+        let index = check_index(index, self.songs.len())?;
+        self.songs.remove(index);
+        Ok(())
+    }
+
+    /// Skips the songs before `index` so it becomes the current song.
+    pub fn skip_to(&mut self, index: i32) -> Result<(), PlaylistError> {
+        let index = check_index(index, self.songs.len())?;
+        let skipped: Vec<SongInternal> = self
+            .songs
+            .drain(..index)
+            .filter(|song| !song.one_shot)
+            .collect();
+        self.remember(skipped.clone());
+        // Repeat all keeps the skipped songs for the next round
+        if self.repeat == Repeat::All {
+            self.songs.extend(skipped);
+        }
+        self.shuffle_next();
+        Ok(())
+    }
+
+    /// Makes the song played before the current one the current song again. A song still in
+    /// the queue, as repeat all keeps them, is moved to the front rather than queued twice.
+    pub fn previous(&mut self) -> Result<(), PlaylistError> {
+        let mut song = self.history.pop().ok_or("No previous song")?;
+        let position = self
+            .songs
+            .iter()
+            .position(|queued| !song.id.is_empty() && queued.id == song.id);
+        match position {
+            Some(position) => {
+                let song = self.songs.remove(position);
+                self.songs.insert(0, song);
+            }
+            None => {
+                // History from before songs had ids
+                if song.id.is_empty() {
+                    song.id = self.new_id();
+                }
+                self.songs.insert(0, song);
+            }
+        }
+        Ok(())
+    }
+
+    /// Moves the song at `from` so it ends up at `to`.
+    pub fn move_song(&mut self, from: i32, to: i32) -> Result<(), PlaylistError> {
+        let from = check_index(from, self.songs.len())?;
+        let to = check_index(to, self.songs.len())?;
+        let song = self.songs.remove(from);
+        self.songs.insert(to, song);
+        Ok(())
+    }
+
+    fn remember(&mut self, songs: Vec<SongInternal>) {
+        self.history.extend(songs);
+        let excess = self.history.len().saturating_sub(HISTORY_LEN);
+        self.history.drain(..excess);
+    }
+
+    /// With shuffle on, swaps a random song into position 1 so it gets preloaded and played
+    /// after the current one.
+    pub fn shuffle_next(&mut self) {
+        if !self.shuffle || self.repeat == Repeat::One {
+            return;
+        }
+        // The song repeat all just moved to the end waits for the next round
+        let end = match self.repeat {
+            Repeat::All if self.songs.len() > 2 => self.songs.len() - 1,
+            _ => self.songs.len(),
+        };
+        if end > 2 {
+            let next = rand::thread_rng().gen_range(1..end);
+            self.songs.swap(1, next);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn playlist(urls: &[&str]) -> Playlist {
+        let mut playlist = Playlist::default();
+        for url in urls {
+            playlist.add_song(SongInternal {
+                url: url.to_string(),
+                title: url.to_uppercase(),
+                thumbnail_b64: String::new(),
+                id: String::new(),
+                one_shot: false,
+            });
+        }
+        playlist
+    }
+
+    fn urls(playlist: &Playlist) -> Vec<&str> {
+        playlist
+            .songs
+            .iter()
+            .map(|song| song.url.as_str())
+            .collect()
+    }
+
+    fn ids(playlist: &Playlist) -> Vec<&str> {
+        playlist.songs.iter().map(|song| song.id.as_str()).collect()
+    }
+
+    #[test]
+    fn check_index_accepts_indexes_below_len() {
+        assert_eq!(check_index(0, 3).unwrap(), 0);
+        assert_eq!(check_index(2, 3).unwrap(), 2);
+    }
+
+    #[test]
+    fn check_index_rejects_negative_and_out_of_range_indexes() {
+        for index in [-1, 3, 4, i32::MIN, i32::MAX] {
+            let error = check_index(index, 3).unwrap_err();
+            assert_eq!(error.index, Some(index));
+            assert_eq!(
+                error.message,
+                format!("Index {} is out of range for a playlist of 3 songs", index)
+            );
+        }
+    }
+
+    #[test]
+    fn check_index_rejects_everything_on_an_empty_playlist() {
+        assert!(check_index(0, 0).is_err());
+    }
+
+    #[test]
+    fn insert_song_appends_at_len() {
+        let mut playlist = playlist(&["a", "b"]);
+
+        playlist.insert_song(2, playlist.songs[0].clone()).unwrap();
+
+        assert_eq!(urls(&playlist), ["a", "b", "a"]);
+        assert_eq!(ids(&playlist), ["song-1", "song-2", "song-3"]);
+        assert!(playlist.insert_song(4, playlist.songs[0].clone()).is_err());
+    }
+
+    #[test]
+    fn move_song_moves_either_way() {
+        let mut playlist = playlist(&["a", "b", "c", "d"]);
+
+        playlist.move_song(0, 2).unwrap();
+        assert_eq!(urls(&playlist), ["b", "c", "a", "d"]);
+
+        playlist.move_song(3, 0).unwrap();
+        assert_eq!(urls(&playlist), ["d", "b", "c", "a"]);
+    }
+
+    #[test]
+    fn move_song_rejects_len_as_destination() {
+        let mut playlist = playlist(&["a", "b"]);
+
+        assert!(playlist.move_song(0, 2).is_err());
+        assert!(playlist.move_song(-1, 0).is_err());
+        assert_eq!(urls(&playlist), ["a", "b"]);
+    }
+
+    #[test]
+    fn skip_to_drops_the_skipped_songs_into_history() {
+        let mut playlist = playlist(&["a", "b", "c"]);
+
+        playlist.skip_to(2).unwrap();
+
+        assert_eq!(urls(&playlist), ["c"]);
+        let history: Vec<&str> = playlist
+            .history
+            .iter()
+            .map(|song| song.url.as_str())
+            .collect();
+        assert_eq!(history, ["a", "b"]);
+        assert!(playlist.skip_to(1).is_err());
+    }
+
+    #[test]
+    fn skip_to_with_repeat_all_keeps_the_skipped_songs() {
+        let mut playlist = playlist(&["a", "b", "c"]);
+        playlist.repeat = Repeat::All;
+
+        playlist.skip_to(2).unwrap();
+
+        assert_eq!(urls(&playlist), ["c", "a", "b"]);
+    }
+
+    #[test]
+    fn advance_follows_the_repeat_mode() {
+        let mut playlist = playlist(&["a", "b", "c"]);
+
+        playlist.advance(SongEnd::Finished);
+        assert_eq!(urls(&playlist), ["b", "c"]);
+
+        playlist.repeat = Repeat::One;
+        playlist.advance(SongEnd::Finished);
+        assert_eq!(urls(&playlist), ["b", "c"]);
+        playlist.advance(SongEnd::Skipped);
+        assert_eq!(urls(&playlist), ["c"]);
+
+        let mut playlist = self::playlist(&["a", "b", "c"]);
+        playlist.repeat = Repeat::All;
+        playlist.advance(SongEnd::Finished);
+        assert_eq!(urls(&playlist), ["b", "c", "a"]);
+    }
+
+    #[test]
+    fn advance_drops_failed_songs_whatever_the_mode() {
+        for repeat in [Repeat::Off, Repeat::One, Repeat::All] {
+            let mut playlist = playlist(&["a", "b"]);
+            playlist.repeat = repeat;
+
+            playlist.advance(SongEnd::Failed);
+
+            assert_eq!(urls(&playlist), ["b"]);
+            assert!(playlist.history.is_empty());
+        }
+    }
+
+    #[test]
+    fn shuffle_with_repeat_all_never_picks_the_song_that_just_finished() {
+        let mut playlist = playlist(&["a", "b", "c"]);
+        playlist.repeat = Repeat::All;
+        playlist.shuffle = true;
+
+        for _ in 0..20 {
+            playlist.advance(SongEnd::Finished);
+            let finished = playlist.history.last().unwrap().id.clone();
+            assert_ne!(playlist.songs[1].id, finished);
+        }
+    }
+
+    #[test]
+    fn previous_puts_the_last_played_song_back() {
+        let mut playlist = playlist(&["a", "b"]);
+        playlist.advance(SongEnd::Finished);
+
+        playlist.previous().unwrap();
+
+        assert_eq!(urls(&playlist), ["a", "b"]);
+        assert_eq!(ids(&playlist), ["song-1", "song-2"]);
+        assert_eq!(playlist.previous().unwrap_err().message, "No previous song");
+    }
+
+    #[test]
+    fn previous_with_repeat_all_moves_the_song_instead_of_copying_it() {
+        let mut playlist = playlist(&["a", "b", "c"]);
+        playlist.repeat = Repeat::All;
+        playlist.advance(SongEnd::Finished);
+        playlist.move_song(2, 1).unwrap();
+
+        playlist.previous().unwrap();
+
+        assert_eq!(urls(&playlist), ["a", "b", "c"]);
+        assert_eq!(ids(&playlist), ["song-1", "song-2", "song-3"]);
+    }
+
+    #[test]
+    fn previous_tells_songs_with_the_same_url_apart() {
+        let mut playlist = playlist(&["a", "b", "a"]);
+        playlist.repeat = Repeat::All;
+        playlist.advance(SongEnd::Finished);
+
+        playlist.previous().unwrap();
+
+        assert_eq!(ids(&playlist), ["song-1", "song-2", "song-3"]);
+    }
+
+    #[test]
+    fn check_revision_only_accepts_the_current_revision() {
+        let mut playlist = playlist(&["a"]);
+        playlist.revision = 4;
+
+        assert!(playlist.check_revision(-1).is_ok());
+        assert!(playlist.check_revision(4).is_ok());
+        assert_eq!(
+            playlist.check_revision(3).unwrap_err().message,
+            "Playlist is at revision 4, the command expected 3"
+        );
+        for expected in [-2, i64::MIN] {
+            assert_eq!(
+                playlist.check_revision(expected).unwrap_err().message,
+                format!("Invalid expected revision {}", expected)
+            );
+        }
+    }
+
+    #[test]
+    fn target_finds_songs_by_id() {
+        let playlist = playlist(&["a", "b", "c"]);
+
+        assert_eq!(playlist.target(0, Some("song-3")).unwrap(), 2);
+        // An empty id falls back to the index
+        assert_eq!(playlist.target(1, Some("")).unwrap(), 1);
+        assert_eq!(playlist.target(7, None).unwrap(), 7);
+        assert_eq!(
+            playlist.target(0, Some("song-9")).unwrap_err().message,
+            "No song with id song-9 in the playlist"
+        );
+    }
+
+    #[test]
+    fn assign_missing_ids_keeps_existing_ones() {
+        let mut playlist = playlist(&["a", "b"]);
+        playlist.songs[1].id.clear();
+
+        playlist.assign_missing_ids();
+
+        assert_eq!(ids(&playlist), ["song-1", "song-3"]);
+    }
+
+    #[test]
+    fn one_shot_songs_are_dropped_whatever_the_repeat_mode() {
+        for repeat in [Repeat::Off, Repeat::One, Repeat::All] {
+            let mut playlist = playlist(&["alarm", "a", "b"]);
+            playlist.songs[0].one_shot = true;
+            playlist.repeat = repeat;
+            assert_eq!(playlist.next_song().unwrap().url, "a");
+
+            playlist.advance(SongEnd::Finished);
+
+            assert_eq!(urls(&playlist), ["a", "b"]);
+            assert!(playlist.history.is_empty());
+        }
+    }
+
+    #[test]
+    fn skipped_one_shot_songs_are_dropped() {
+        let mut playlist = playlist(&["alarm", "a", "b"]);
+        playlist.songs[0].one_shot = true;
+        playlist.repeat = Repeat::All;
+
+        playlist.skip_to(2).unwrap();
+
+        assert_eq!(urls(&playlist), ["b", "a"]);
+    }
+}
@@ -0,0 +1,310 @@
+use std::{collections::HashSet, sync::Arc};
+
+use crate::{
+    fbs::{
+        construct_play_youtube_song_command, construct_playlist_updated_event,
+        construct_preload_command, construct_stop_command, EventPublisher,
+    },
+    playlist::{Playlist, PlaylistError, Repeat, SongEnd},
+    playlist_store::PlaylistStore,
+    root_as_message, MessageContent, PlaylistCommand, PlaylistCommandContent, PlaylistQuery,
+    PlaylistQueryContent, SongInternal, SpeakerEventContent, SpeakerListEvent,
+};
+
+fn new_song_at_position_0(nc: Arc<dyn EventPublisher>, device_id: String, playlist: &Playlist) {
+    let song = &playlist.songs[0];
+    let url = &song.url;
+    nc.publish(
+        "speaker.command",
+        construct_play_youtube_song_command(url.to_string(), device_id.to_string()),
+    );
+    preload_next_song(nc, device_id, playlist);
+}
+
+/// Lets the speaker prefetch the song after the current one. Speakers ignore repeated preloads
+/// of the same url, so this is sent after every change to the playlist.
+fn preload_next_song(nc: Arc<dyn EventPublisher>, device_id: String, playlist: &Playlist) {
+    if let Some(song) = playlist.next_song() {
+        nc.publish(
+            "speaker.command",
+            construct_preload_command(song.url.to_string(), device_id),
+        );
+    }
+}
+
+fn stop_command(nc: Arc<dyn EventPublisher>, device_id: String) {
+    nc.publish("speaker.command", construct_stop_command(device_id));
+}
+
+fn playlist_updated_event(nc: Arc<dyn EventPublisher>, device_id: String, playlist: &Playlist) {
+    nc.publish(
+        "playlist.event",
+        construct_playlist_updated_event(playlist, device_id.to_string()),
+    );
+}
+
+/// Publishes the playlist and gets the speaker in line with it: a new head is played, or
+/// playback stopped if the queue ran empty, otherwise only the next song is preloaded.
+fn playlist_changed(
+    nc: Arc<dyn EventPublisher>,
+    device_id: String,
+    playlist: &Playlist,
+    head_changed: bool,
+) {
+    playlist_updated_event(nc.clone(), device_id.clone(), playlist);
+    if !head_changed {
+        preload_next_song(nc, device_id, playlist);
+    } else if playlist.songs.is_empty() {
+        stop_command(nc, device_id);
+    } else {
+        new_song_at_position_0(nc, device_id, playlist);
+    }
+}
+
+/// Brings speakers in line with the queues loaded at startup, once per device as it shows up:
+/// the head of a queue is played unless the speaker already is playing it, and a speaker whose
+/// queue ran empty is stopped.
+fn reconcile_playback(
+    nc: Arc<dyn EventPublisher>,
+    speaker_list: SpeakerListEvent,
+    playlists: &PlaylistStore,
+    unreconciled: &mut HashSet<String>,
+) {
+    let Some(speakers) = speaker_list.speakers() else {
+        return;
+    };
+    for speaker in speakers {
+        let Some(device_id) = speaker.device_id() else {
+            continue;
+        };
+        if !unreconciled.remove(device_id) {
+            continue;
+        }
+
+        let playlist = playlists.get(device_id);
+        match playlist.songs.first() {
+            Some(song) if speaker.url() == Some(song.url.as_str()) => {
+                preload_next_song(nc.clone(), device_id.to_string(), playlist);
+            }
+            Some(_) => {
+                println!("Resuming playlist on {}", device_id);
+                new_song_at_position_0(nc.clone(), device_id.to_string(), playlist);
+            }
+            None if speaker.playing() => {
+                println!("Stopping {}, its playlist is empty", device_id);
+                stop_command(nc.clone(), device_id.to_string());
+            }
+            None => {}
+        }
+    }
+}
+
+/// The queues of every device and the handling of messages that change them. Anything wrong
+/// with a message comes back as a `PlaylistError` rather than a panic.
+pub struct PlaylistHandler {
+    nc: Arc<dyn EventPublisher>,
+    playlists: PlaylistStore,
+    // Devices with a stored queue that no SpeakerListEvent has reported yet
+    unreconciled: HashSet<String>,
+}
+
+impl PlaylistHandler {
+    pub fn new(nc: Arc<dyn EventPublisher>, playlists: PlaylistStore) -> Self {
+        let unreconciled = playlists
+            .iter()
+            .map(|(device_id, _)| device_id.clone())
+            .collect();
+        Self {
+            nc,
+            playlists,
+            unreconciled,
+        }
+    }
+
+    pub fn handle_playlist_message(&mut self, data: &[u8]) -> Result<(), PlaylistError> {
+        let message = root_as_message(data).map_err(|e| e.to_string())?;
+        match message.content_type() {
+            MessageContent::PlaylistQuery => {
+                let query = message
+                    .content_as_playlist_query()
+                    .ok_or("PlaylistQuery is none")?;
+                self.handle_query(query)
+            }
+            MessageContent::PlaylistCommand => {
+                let command = message
+                    .content_as_playlist_command()
+                    .ok_or("PlaylistCommand is none")?;
+                let device_id = command.device_id().ok_or("Device id is none")?;
+                self.handle_command(device_id, command)
+                    .map_err(|e| e.for_device(device_id))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn handle_query(&self, query: PlaylistQuery) -> Result<(), PlaylistError> {
+        let device_id = query.device_id().ok_or("Device id is none")?;
+
+        const MAX: u8 = PlaylistQueryContent::ENUM_MAX + 1;
+        match query.query_type() {
+            PlaylistQueryContent::QueryPlaylistState => {
+                let playlist = self.playlists.get(device_id);
+                playlist_updated_event(self.nc.clone(), device_id.to_string(), playlist);
+                Ok(())
+            }
+            PlaylistQueryContent::NONE | PlaylistQueryContent(MAX..=u8::MAX) => {
+                Err(PlaylistError::from("Invalid query type").for_device(device_id))
+            }
+        }
+    }
+
+    fn handle_command(
+        &mut self,
+        device_id: &str,
+        command: PlaylistCommand,
+    ) -> Result<(), PlaylistError> {
+        let playlist = self.playlists.get_mut(device_id);
+        playlist.check_revision(command.expected_revision())?;
+
+        const MAX: u8 = PlaylistCommandContent::ENUM_MAX + 1;
+        // Whether the song at position 0 is a different one now
+        let head_changed = match command.command_type() {
+            PlaylistCommandContent::AddSong => {
+                let command = command.command_as_add_song().ok_or("AddSong is none")?;
+                playlist.add_song(SongInternal::from_fbs(command.song())?);
+                playlist.songs.len() == 1
+            }
+            PlaylistCommandContent::InsertSong => {
+                let command = command
+                    .command_as_insert_song()
+                    .ok_or("InsertSong is none")?;
+                let index = playlist.target(command.index(), command.before_id())?;
+                let song = SongInternal::from_fbs(command.song())?;
+                playlist.insert_song(index, song)?;
+                index == 0
+            }
+            PlaylistCommandContent::RemoveSong => {
+                let command = command
+                    .command_as_remove_song()
+                    .ok_or("RemoveSong is none")?;
+                let index = playlist.target(command.index(), command.id())?;
+                playlist.remove_song(index)?;
+                index == 0
+            }
+            PlaylistCommandContent::ReplaceSong => {
+                let command = command
+                    .command_as_replace_song()
+                    .ok_or("ReplaceSong is none")?;
+                let index = playlist.target(command.index(), command.id())?;
+                let song = SongInternal::from_fbs(command.song())?;
+                playlist.replace_song(index, song)?;
+                index == 0
+            }
+            PlaylistCommandContent::SetRepeatMode => {
+                let command = command
+                    .command_as_set_repeat_mode()
+                    .ok_or("SetRepeatMode is none")?;
+                playlist.repeat = Repeat::from_fbs(command.mode())?;
+                false
+            }
+            PlaylistCommandContent::SetShuffle => {
+                let command = command
+                    .command_as_set_shuffle()
+                    .ok_or("SetShuffle is none")?;
+                playlist.shuffle = command.shuffle();
+                playlist.shuffle_next();
+                false
+            }
+            PlaylistCommandContent::MoveSong => {
+                let command = command.command_as_move_song().ok_or("MoveSong is none")?;
+                let from = playlist.target(command.from(), command.id())?;
+                let to = command.to();
+                playlist.move_song(from, to)?;
+                from != to && (from == 0 || to == 0)
+            }
+            PlaylistCommandContent::ClearPlaylist => {
+                let was_playing = !playlist.songs.is_empty();
+                playlist.songs.clear();
+                was_playing
+            }
+            PlaylistCommandContent::SkipToIndex => {
+                let command = command
+                    .command_as_skip_to_index()
+                    .ok_or("SkipToIndex is none")?;
+                let index = playlist.target(command.index(), command.id())?;
+                playlist.skip_to(index)?;
+                index != 0
+            }
+            PlaylistCommandContent::Next => {
+                if playlist.songs.is_empty() {
+                    return Ok(());
+                }
+                playlist.advance(SongEnd::Skipped);
+                true
+            }
+            PlaylistCommandContent::Previous => {
+                playlist.previous()?;
+                true
+            }
+            PlaylistCommandContent::NONE | PlaylistCommandContent(MAX..=u8::MAX) => {
+                return Err("Invalid command type".into());
+            }
+        };
+        playlist.revision += 1;
+
+        playlist_changed(
+            self.nc.clone(),
+            device_id.to_string(),
+            playlist,
+            head_changed,
+        );
+        self.playlists.save()?;
+        Ok(())
+    }
+
+    pub fn handle_speaker_message(&mut self, data: &[u8]) -> Result<(), PlaylistError> {
+        let message = root_as_message(data).map_err(|e| e.to_string())?;
+        match message.content_type() {
+            // Speakers answer the startup query with a SpeakerListEvent
+            MessageContent::SpeakerListEvent => {
+                let speaker_list = message
+                    .content_as_speaker_list_event()
+                    .ok_or("SpeakerListEvent is none")?;
+                reconcile_playback(
+                    self.nc.clone(),
+                    speaker_list,
+                    &self.playlists,
+                    &mut self.unreconciled,
+                );
+                Ok(())
+            }
+            MessageContent::SpeakerEvent => {
+                let event = message
+                    .content_as_speaker_event()
+                    .ok_or("SpeakerEvent is none")?;
+                let device_id = event.device_id().ok_or("Device id is none")?;
+                // A track that failed to play is skipped just like one that finished
+                let end = match event.event_type() {
+                    SpeakerEventContent::FileEnded => SongEnd::Finished,
+                    SpeakerEventContent::PlaybackFailed => SongEnd::Failed,
+                    _ => return Ok(()),
+                };
+
+                let playlist = self.playlists.get_mut(device_id);
+                if playlist.songs.is_empty() {
+                    return Ok(());
+                }
+                playlist.advance(end);
+                playlist.revision += 1;
+                playlist_changed(self.nc.clone(), device_id.to_string(), playlist, true);
+                self.playlists
+                    .save()
+                    .map_err(|e| PlaylistError::from(e).for_device(device_id))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
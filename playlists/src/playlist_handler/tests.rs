@@ -0,0 +1,289 @@
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc, Mutex,
+};
+
+use flatbuffers::{FlatBufferBuilder, UnionWIPOffset, WIPOffset};
+
+use super::PlaylistHandler;
+use crate::{
+    fbs::{construct_playlist_error_message, EventPublisher},
+    playlist::PlaylistError,
+    playlist_store::PlaylistStore,
+    root_as_message, AddSong, AddSongArgs, InsertSong, InsertSongArgs, Message, MessageArgs,
+    MessageContent, MoveSong, MoveSongArgs, PlaylistCommand, PlaylistCommandArgs,
+    PlaylistCommandContent, RemoveSong, RemoveSongArgs, SkipToIndex, SkipToIndexArgs, Song,
+    SongArgs, SpeakerCommandContent,
+};
+
+const KITCHEN: &str = "kitchen";
+
+type Content = WIPOffset<UnionWIPOffset>;
+
+#[derive(Default)]
+struct RecordedEvents {
+    messages: Mutex<Vec<(String, Vec<u8>)>>,
+}
+
+impl EventPublisher for RecordedEvents {
+    fn publish(&self, topic: &str, message: Vec<u8>) {
+        self.messages
+            .lock()
+            .unwrap()
+            .push((topic.to_string(), message));
+    }
+}
+
+fn temp_path(name: &str) -> String {
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+    let n = COUNTER.fetch_add(1, Ordering::SeqCst);
+    std::env::temp_dir()
+        .join(format!(
+            "playlists-test-{}-{}-{}",
+            std::process::id(),
+            n,
+            name
+        ))
+        .to_str()
+        .unwrap()
+        .to_string()
+}
+
+struct Harness {
+    handler: PlaylistHandler,
+    events: Arc<RecordedEvents>,
+    path: String,
+}
+
+impl Harness {
+    /// A handler whose kitchen queue holds `urls`, with the commands that built it discarded.
+    fn with_songs(urls: &[&str]) -> Self {
+        let path = temp_path("playlists.json");
+        let events = Arc::new(RecordedEvents::default());
+        let handler = PlaylistHandler::new(
+            events.clone(),
+            PlaylistStore::load_from(path.clone()).unwrap(),
+        );
+        let mut harness = Self {
+            handler,
+            events,
+            path,
+        };
+        for url in urls {
+            harness
+                .command(PlaylistCommandContent::AddSong, |fbb| {
+                    let song = song(fbb, url);
+                    AddSong::create(fbb, &AddSongArgs { song: Some(song) }).as_union_value()
+                })
+                .unwrap();
+        }
+        harness.speaker_commands();
+        harness
+    }
+
+    fn command(
+        &mut self,
+        command_type: PlaylistCommandContent,
+        build: impl FnOnce(&mut FlatBufferBuilder<'static>) -> Content,
+    ) -> Result<(), PlaylistError> {
+        let mut fbb = FlatBufferBuilder::new();
+        let command = build(&mut fbb);
+        let device_id = fbb.create_string(KITCHEN);
+        let command = PlaylistCommand::create(
+            &mut fbb,
+            &PlaylistCommandArgs {
+                device_id: Some(device_id),
+                command_type,
+                command: Some(command),
+                ..Default::default()
+            },
+        );
+        let root = Message::create(
+            &mut fbb,
+            &MessageArgs {
+                timestamp: 0,
+                content_type: MessageContent::PlaylistCommand,
+                content: Some(command.as_union_value()),
+            },
+        );
+        fbb.finish(root, None);
+        self.handler.handle_playlist_message(fbb.finished_data())
+    }
+
+    /// Speaker commands sent since the last call, as (type, url of Play or Preload).
+    fn speaker_commands(&self) -> Vec<(SpeakerCommandContent, Option<String>)> {
+        std::mem::take(&mut *self.events.messages.lock().unwrap())
+            .iter()
+            .filter(|(topic, _)| topic == "speaker.command")
+            .filter_map(|(_, message)| {
+                let command = root_as_message(message)
+                    .ok()?
+                    .content_as_speaker_command()?;
+                let url = match command.command_type() {
+                    SpeakerCommandContent::Play => {
+                        command.command_as_play()?.content_as_play_youtube()?.url()
+                    }
+                    SpeakerCommandContent::Preload => command
+                        .command_as_preload()?
+                        .content_as_play_youtube()?
+                        .url(),
+                    _ => None,
+                };
+                Some((command.command_type(), url.map(str::to_string)))
+            })
+            .collect()
+    }
+
+    fn plays(&self) -> Vec<String> {
+        self.speaker_commands()
+            .into_iter()
+            .filter(|(command_type, _)| *command_type == SpeakerCommandContent::Play)
+            .filter_map(|(_, url)| url)
+            .collect()
+    }
+}
+
+impl Drop for Harness {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+fn song<'a>(fbb: &mut FlatBufferBuilder<'a>, url: &str) -> WIPOffset<Song<'a>> {
+    let url = fbb.create_string(url);
+    let title = fbb.create_string("title");
+    let thumbnail_b64 = fbb.create_string("");
+    Song::create(
+        fbb,
+        &SongArgs {
+            url: Some(url),
+            title: Some(title),
+            thumbnail_b64: Some(thumbnail_b64),
+            ..Default::default()
+        },
+    )
+}
+
+fn insert(harness: &mut Harness, index: i32, url: &str) -> Result<(), PlaylistError> {
+    harness.command(PlaylistCommandContent::InsertSong, |fbb| {
+        let song = song(fbb, url);
+        InsertSong::create(
+            fbb,
+            &InsertSongArgs {
+                index,
+                song: Some(song),
+                before_id: None,
+            },
+        )
+        .as_union_value()
+    })
+}
+
+fn remove(harness: &mut Harness, index: i32) -> Result<(), PlaylistError> {
+    harness.command(PlaylistCommandContent::RemoveSong, |fbb| {
+        RemoveSong::create(fbb, &RemoveSongArgs { index, id: None }).as_union_value()
+    })
+}
+
+fn move_song(harness: &mut Harness, from: i32, to: i32) -> Result<(), PlaylistError> {
+    harness.command(PlaylistCommandContent::MoveSong, |fbb| {
+        MoveSong::create(fbb, &MoveSongArgs { from, to, id: None }).as_union_value()
+    })
+}
+
+fn skip_to(harness: &mut Harness, index: i32) -> Result<(), PlaylistError> {
+    harness.command(PlaylistCommandContent::SkipToIndex, |fbb| {
+        SkipToIndex::create(fbb, &SkipToIndexArgs { index, id: None }).as_union_value()
+    })
+}
+
+#[test]
+fn bad_index_is_rejected_with_device_and_index() {
+    let mut harness = Harness::with_songs(&["a"]);
+
+    let error = remove(&mut harness, 5).unwrap_err();
+
+    assert_eq!(error.device_id.as_deref(), Some(KITCHEN));
+    assert_eq!(error.index, Some(5));
+    let message = construct_playlist_error_message(&error);
+    let error = root_as_message(&message)
+        .unwrap()
+        .content_as_error()
+        .unwrap();
+    assert_eq!(error.device_id(), Some(KITCHEN));
+    assert_eq!(error.index(), 5);
+    assert!(harness.speaker_commands().is_empty());
+}
+
+#[test]
+fn handling_continues_after_a_bad_message() {
+    let mut harness = Harness::with_songs(&["a"]);
+
+    assert!(insert(&mut harness, -1, "b").is_err());
+    assert!(harness.handler.handle_playlist_message(b"garbage").is_err());
+
+    insert(&mut harness, 0, "b").unwrap();
+    assert_eq!(harness.plays(), ["b"]);
+}
+
+#[test]
+fn insert_plays_only_a_new_head() {
+    let mut harness = Harness::with_songs(&["a"]);
+
+    insert(&mut harness, 1, "b").unwrap();
+    assert!(harness.plays().is_empty());
+
+    insert(&mut harness, 0, "c").unwrap();
+    assert_eq!(harness.plays(), ["c"]);
+}
+
+#[test]
+fn remove_plays_only_a_new_head() {
+    let mut harness = Harness::with_songs(&["a", "b", "c"]);
+
+    remove(&mut harness, 2).unwrap();
+    assert!(harness.plays().is_empty());
+
+    remove(&mut harness, 0).unwrap();
+    assert_eq!(harness.plays(), ["b"]);
+}
+
+#[test]
+fn removing_the_last_song_stops_the_speaker() {
+    let mut harness = Harness::with_songs(&["a"]);
+
+    remove(&mut harness, 0).unwrap();
+
+    assert_eq!(
+        harness.speaker_commands(),
+        [(SpeakerCommandContent::Stop, None)]
+    );
+}
+
+#[test]
+fn move_plays_only_a_new_head() {
+    let mut harness = Harness::with_songs(&["a", "b", "c"]);
+
+    move_song(&mut harness, 2, 1).unwrap();
+    assert!(harness.plays().is_empty());
+
+    move_song(&mut harness, 0, 0).unwrap();
+    assert!(harness.plays().is_empty());
+
+    move_song(&mut harness, 2, 0).unwrap();
+    assert_eq!(harness.plays(), ["b"]);
+
+    move_song(&mut harness, 0, 2).unwrap();
+    assert_eq!(harness.plays(), ["a"]);
+}
+
+#[test]
+fn skip_plays_only_a_new_head() {
+    let mut harness = Harness::with_songs(&["a", "b", "c"]);
+
+    skip_to(&mut harness, 0).unwrap();
+    assert!(harness.plays().is_empty());
+
+    skip_to(&mut harness, 2).unwrap();
+    assert_eq!(harness.plays(), ["c"]);
+}
@@ -0,0 +1,69 @@
+use std::collections::HashMap;
+
+use crate::playlist::{Playlist, Repeat};
+
+const DEFAULT_PLAYLISTS_PATH: &str = "/config/playlists.json";
+
+/// Writes `contents` next to `path` and renames it over it, so a crash mid-write can't lose
+/// the queues.
+fn write_atomically(path: &str, contents: &str) -> std::io::Result<()> {
+    let tmp_path = format!("{}.tmp", path);
+    std::fs::write(&tmp_path, contents).and_then(|_| std::fs::rename(&tmp_path, path))
+}
+
+/// Device id to `Playlist` map, persisted as JSON in `PLAYLISTS_PATH` so queues survive a restart.
+pub struct PlaylistStore {
+    path: String,
+    playlists: HashMap<String, Playlist>,
+}
+
+static EMPTY_PLAYLIST: Playlist = Playlist {
+    songs: Vec::new(),
+    repeat: Repeat::Off,
+    shuffle: false,
+    history: Vec::new(),
+    revision: 0,
+    last_id: 0,
+};
+
+impl PlaylistStore {
+    pub fn load() -> Result<Self, String> {
+        let path =
+            std::env::var("PLAYLISTS_PATH").unwrap_or_else(|_| DEFAULT_PLAYLISTS_PATH.to_string());
+        Self::load_from(path)
+    }
+
+    pub fn load_from(path: String) -> Result<Self, String> {
+        let mut playlists: HashMap<String, Playlist> = match std::fs::read_to_string(&path) {
+            Ok(json) => serde_json::from_str(&json)
+                .map_err(|e| format!("Error parsing playlists {}: {}", path, e))?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => HashMap::new(),
+            Err(e) => return Err(format!("Error reading playlists {}: {}", path, e)),
+        };
+
+        for playlist in playlists.values_mut() {
+            playlist.assign_missing_ids();
+        }
+
+        Ok(Self { path, playlists })
+    }
+
+    pub fn get(&self, device_id: &str) -> &Playlist {
+        self.playlists.get(device_id).unwrap_or(&EMPTY_PLAYLIST)
+    }
+
+    pub fn get_mut(&mut self, device_id: &str) -> &mut Playlist {
+        self.playlists.entry(device_id.to_string()).or_default()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &Playlist)> {
+        self.playlists.iter()
+    }
+
+    /// Writes every queue to disk, called after each change.
+    pub fn save(&self) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(&self.playlists).map_err(|e| e.to_string())?;
+        write_atomically(&self.path, &json)
+            .map_err(|e| format!("Error writing playlists {}: {}", self.path, e))
+    }
+}
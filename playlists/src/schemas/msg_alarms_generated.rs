@@ -0,0 +1,1478 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+
+// @generated
+
+use core::mem;
+use core::cmp::Ordering;
+
+extern crate flatbuffers;
+use self::flatbuffers::{EndianScalar, Follow};
+
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_ALARM_COMMAND_CONTENT: u8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_ALARM_COMMAND_CONTENT: u8 = 2;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_ALARM_COMMAND_CONTENT: [AlarmCommandContent; 3] = [
+  AlarmCommandContent::NONE,
+  AlarmCommandContent::CreateAlarm,
+  AlarmCommandContent::DeleteAlarm,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct AlarmCommandContent(pub u8);
+#[allow(non_upper_case_globals)]
+impl AlarmCommandContent {
+  pub const NONE: Self = Self(0);
+  pub const CreateAlarm: Self = Self(1);
+  pub const DeleteAlarm: Self = Self(2);
+
+  pub const ENUM_MIN: u8 = 0;
+  pub const ENUM_MAX: u8 = 2;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::NONE,
+    Self::CreateAlarm,
+    Self::DeleteAlarm,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::NONE => Some("NONE"),
+      Self::CreateAlarm => Some("CreateAlarm"),
+      Self::DeleteAlarm => Some("DeleteAlarm"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for AlarmCommandContent {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for AlarmCommandContent {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = flatbuffers::read_scalar_at::<u8>(buf, loc);
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for AlarmCommandContent {
+    type Output = AlarmCommandContent;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<u8>(dst, self.0);
+    }
+}
+
+impl flatbuffers::EndianScalar for AlarmCommandContent {
+  type Scalar = u8;
+  #[inline]
+  fn to_little_endian(self) -> u8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: u8) -> Self {
+    let b = u8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for AlarmCommandContent {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    u8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for AlarmCommandContent {}
+pub struct AlarmCommandContentUnionTableOffset {}
+
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_ALARM_QUERY_CONTENT: u8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_ALARM_QUERY_CONTENT: u8 = 1;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_ALARM_QUERY_CONTENT: [AlarmQueryContent; 2] = [
+  AlarmQueryContent::NONE,
+  AlarmQueryContent::QueryAlarms,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct AlarmQueryContent(pub u8);
+#[allow(non_upper_case_globals)]
+impl AlarmQueryContent {
+  pub const NONE: Self = Self(0);
+  pub const QueryAlarms: Self = Self(1);
+
+  pub const ENUM_MIN: u8 = 0;
+  pub const ENUM_MAX: u8 = 1;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::NONE,
+    Self::QueryAlarms,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::NONE => Some("NONE"),
+      Self::QueryAlarms => Some("QueryAlarms"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for AlarmQueryContent {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for AlarmQueryContent {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = flatbuffers::read_scalar_at::<u8>(buf, loc);
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for AlarmQueryContent {
+    type Output = AlarmQueryContent;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<u8>(dst, self.0);
+    }
+}
+
+impl flatbuffers::EndianScalar for AlarmQueryContent {
+  type Scalar = u8;
+  #[inline]
+  fn to_little_endian(self) -> u8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: u8) -> Self {
+    let b = u8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for AlarmQueryContent {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    u8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for AlarmQueryContent {}
+pub struct AlarmQueryContentUnionTableOffset {}
+
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_ALARM_EVENT_CONTENT: u8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_ALARM_EVENT_CONTENT: u8 = 2;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_ALARM_EVENT_CONTENT: [AlarmEventContent; 3] = [
+  AlarmEventContent::NONE,
+  AlarmEventContent::AlarmsChanged,
+  AlarmEventContent::AlarmFired,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct AlarmEventContent(pub u8);
+#[allow(non_upper_case_globals)]
+impl AlarmEventContent {
+  pub const NONE: Self = Self(0);
+  pub const AlarmsChanged: Self = Self(1);
+  pub const AlarmFired: Self = Self(2);
+
+  pub const ENUM_MIN: u8 = 0;
+  pub const ENUM_MAX: u8 = 2;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::NONE,
+    Self::AlarmsChanged,
+    Self::AlarmFired,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::NONE => Some("NONE"),
+      Self::AlarmsChanged => Some("AlarmsChanged"),
+      Self::AlarmFired => Some("AlarmFired"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for AlarmEventContent {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for AlarmEventContent {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = flatbuffers::read_scalar_at::<u8>(buf, loc);
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for AlarmEventContent {
+    type Output = AlarmEventContent;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<u8>(dst, self.0);
+    }
+}
+
+impl flatbuffers::EndianScalar for AlarmEventContent {
+  type Scalar = u8;
+  #[inline]
+  fn to_little_endian(self) -> u8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: u8) -> Self {
+    let b = u8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for AlarmEventContent {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    u8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for AlarmEventContent {}
+pub struct AlarmEventContentUnionTableOffset {}
+
+pub enum AlarmOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Alarm<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Alarm<'a> {
+  type Inner = Alarm<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> Alarm<'a> {
+  pub const VT_ID: flatbuffers::VOffsetT = 4;
+  pub const VT_DEVICE_ID: flatbuffers::VOffsetT = 6;
+  pub const VT_DAYS: flatbuffers::VOffsetT = 8;
+  pub const VT_HOUR: flatbuffers::VOffsetT = 10;
+  pub const VT_MINUTE: flatbuffers::VOffsetT = 12;
+  pub const VT_URL: flatbuffers::VOffsetT = 14;
+  pub const VT_VOLUME: flatbuffers::VOffsetT = 16;
+  pub const VT_RAMP_VOLUME: flatbuffers::VOffsetT = 18;
+  pub const VT_RAMP_DURATION: flatbuffers::VOffsetT = 20;
+  pub const VT_ENABLED: flatbuffers::VOffsetT = 22;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Alarm { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args AlarmArgs<'args>
+  ) -> flatbuffers::WIPOffset<Alarm<'bldr>> {
+    let mut builder = AlarmBuilder::new(_fbb);
+    builder.add_ramp_duration(args.ramp_duration);
+    builder.add_ramp_volume(args.ramp_volume);
+    builder.add_volume(args.volume);
+    if let Some(x) = args.url { builder.add_url(x); }
+    if let Some(x) = args.days { builder.add_days(x); }
+    if let Some(x) = args.device_id { builder.add_device_id(x); }
+    if let Some(x) = args.id { builder.add_id(x); }
+    builder.add_enabled(args.enabled);
+    builder.add_minute(args.minute);
+    builder.add_hour(args.hour);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn id(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Alarm::VT_ID, None)}
+  }
+  #[inline]
+  pub fn device_id(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Alarm::VT_DEVICE_ID, None)}
+  }
+  #[inline]
+  pub fn days(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(Alarm::VT_DAYS, None)}
+  }
+  #[inline]
+  pub fn hour(&self) -> u8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u8>(Alarm::VT_HOUR, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn minute(&self) -> u8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u8>(Alarm::VT_MINUTE, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn url(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Alarm::VT_URL, None)}
+  }
+  #[inline]
+  pub fn volume(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(Alarm::VT_VOLUME, Some(30.0)).unwrap()}
+  }
+  #[inline]
+  pub fn ramp_volume(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(Alarm::VT_RAMP_VOLUME, Some(0.0)).unwrap()}
+  }
+  #[inline]
+  pub fn ramp_duration(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(Alarm::VT_RAMP_DURATION, Some(0.0)).unwrap()}
+  }
+  #[inline]
+  pub fn enabled(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(Alarm::VT_ENABLED, Some(true)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for Alarm<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("id", Self::VT_ID, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("device_id", Self::VT_DEVICE_ID, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("days", Self::VT_DAYS, false)?
+     .visit_field::<u8>("hour", Self::VT_HOUR, false)?
+     .visit_field::<u8>("minute", Self::VT_MINUTE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("url", Self::VT_URL, false)?
+     .visit_field::<f32>("volume", Self::VT_VOLUME, false)?
+     .visit_field::<f32>("ramp_volume", Self::VT_RAMP_VOLUME, false)?
+     .visit_field::<f32>("ramp_duration", Self::VT_RAMP_DURATION, false)?
+     .visit_field::<bool>("enabled", Self::VT_ENABLED, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct AlarmArgs<'a> {
+    pub id: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub device_id: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub days: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+    pub hour: u8,
+    pub minute: u8,
+    pub url: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub volume: f32,
+    pub ramp_volume: f32,
+    pub ramp_duration: f32,
+    pub enabled: bool,
+}
+impl<'a> Default for AlarmArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    AlarmArgs {
+      id: None,
+      device_id: None,
+      days: None,
+      hour: 0,
+      minute: 0,
+      url: None,
+      volume: 30.0,
+      ramp_volume: 0.0,
+      ramp_duration: 0.0,
+      enabled: true,
+    }
+  }
+}
+
+pub struct AlarmBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> AlarmBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_id(&mut self, id: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Alarm::VT_ID, id);
+  }
+  #[inline]
+  pub fn add_device_id(&mut self, device_id: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Alarm::VT_DEVICE_ID, device_id);
+  }
+  #[inline]
+  pub fn add_days(&mut self, days: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Alarm::VT_DAYS, days);
+  }
+  #[inline]
+  pub fn add_hour(&mut self, hour: u8) {
+    self.fbb_.push_slot::<u8>(Alarm::VT_HOUR, hour, 0);
+  }
+  #[inline]
+  pub fn add_minute(&mut self, minute: u8) {
+    self.fbb_.push_slot::<u8>(Alarm::VT_MINUTE, minute, 0);
+  }
+  #[inline]
+  pub fn add_url(&mut self, url: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Alarm::VT_URL, url);
+  }
+  #[inline]
+  pub fn add_volume(&mut self, volume: f32) {
+    self.fbb_.push_slot::<f32>(Alarm::VT_VOLUME, volume, 30.0);
+  }
+  #[inline]
+  pub fn add_ramp_volume(&mut self, ramp_volume: f32) {
+    self.fbb_.push_slot::<f32>(Alarm::VT_RAMP_VOLUME, ramp_volume, 0.0);
+  }
+  #[inline]
+  pub fn add_ramp_duration(&mut self, ramp_duration: f32) {
+    self.fbb_.push_slot::<f32>(Alarm::VT_RAMP_DURATION, ramp_duration, 0.0);
+  }
+  #[inline]
+  pub fn add_enabled(&mut self, enabled: bool) {
+    self.fbb_.push_slot::<bool>(Alarm::VT_ENABLED, enabled, true);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> AlarmBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    AlarmBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Alarm<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Alarm<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Alarm");
+      ds.field("id", &self.id());
+      ds.field("device_id", &self.device_id());
+      ds.field("days", &self.days());
+      ds.field("hour", &self.hour());
+      ds.field("minute", &self.minute());
+      ds.field("url", &self.url());
+      ds.field("volume", &self.volume());
+      ds.field("ramp_volume", &self.ramp_volume());
+      ds.field("ramp_duration", &self.ramp_duration());
+      ds.field("enabled", &self.enabled());
+      ds.finish()
+  }
+}
+pub enum CreateAlarmOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct CreateAlarm<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for CreateAlarm<'a> {
+  type Inner = CreateAlarm<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> CreateAlarm<'a> {
+  pub const VT_ALARM: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    CreateAlarm { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args CreateAlarmArgs<'args>
+  ) -> flatbuffers::WIPOffset<CreateAlarm<'bldr>> {
+    let mut builder = CreateAlarmBuilder::new(_fbb);
+    if let Some(x) = args.alarm { builder.add_alarm(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn alarm(&self) -> Option<Alarm<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<Alarm>>(CreateAlarm::VT_ALARM, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for CreateAlarm<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<Alarm>>("alarm", Self::VT_ALARM, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct CreateAlarmArgs<'a> {
+    pub alarm: Option<flatbuffers::WIPOffset<Alarm<'a>>>,
+}
+impl<'a> Default for CreateAlarmArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    CreateAlarmArgs {
+      alarm: None,
+    }
+  }
+}
+
+pub struct CreateAlarmBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> CreateAlarmBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_alarm(&mut self, alarm: flatbuffers::WIPOffset<Alarm<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<Alarm>>(CreateAlarm::VT_ALARM, alarm);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> CreateAlarmBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    CreateAlarmBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<CreateAlarm<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for CreateAlarm<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("CreateAlarm");
+      ds.field("alarm", &self.alarm());
+      ds.finish()
+  }
+}
+pub enum DeleteAlarmOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct DeleteAlarm<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for DeleteAlarm<'a> {
+  type Inner = DeleteAlarm<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> DeleteAlarm<'a> {
+  pub const VT_ID: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    DeleteAlarm { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args DeleteAlarmArgs<'args>
+  ) -> flatbuffers::WIPOffset<DeleteAlarm<'bldr>> {
+    let mut builder = DeleteAlarmBuilder::new(_fbb);
+    if let Some(x) = args.id { builder.add_id(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn id(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(DeleteAlarm::VT_ID, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for DeleteAlarm<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("id", Self::VT_ID, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct DeleteAlarmArgs<'a> {
+    pub id: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for DeleteAlarmArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    DeleteAlarmArgs {
+      id: None,
+    }
+  }
+}
+
+pub struct DeleteAlarmBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> DeleteAlarmBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_id(&mut self, id: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(DeleteAlarm::VT_ID, id);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> DeleteAlarmBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    DeleteAlarmBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<DeleteAlarm<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for DeleteAlarm<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("DeleteAlarm");
+      ds.field("id", &self.id());
+      ds.finish()
+  }
+}
+pub enum QueryAlarmsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct QueryAlarms<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for QueryAlarms<'a> {
+  type Inner = QueryAlarms<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> QueryAlarms<'a> {
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    QueryAlarms { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    _args: &'args QueryAlarmsArgs
+  ) -> flatbuffers::WIPOffset<QueryAlarms<'bldr>> {
+    let mut builder = QueryAlarmsBuilder::new(_fbb);
+    builder.finish()
+  }
+
+}
+
+impl flatbuffers::Verifiable for QueryAlarms<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct QueryAlarmsArgs {
+}
+impl<'a> Default for QueryAlarmsArgs {
+  #[inline]
+  fn default() -> Self {
+    QueryAlarmsArgs {
+    }
+  }
+}
+
+pub struct QueryAlarmsBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> QueryAlarmsBuilder<'a, 'b> {
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> QueryAlarmsBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    QueryAlarmsBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<QueryAlarms<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for QueryAlarms<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("QueryAlarms");
+      ds.finish()
+  }
+}
+pub enum AlarmsChangedOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct AlarmsChanged<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for AlarmsChanged<'a> {
+  type Inner = AlarmsChanged<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> AlarmsChanged<'a> {
+  pub const VT_ALARMS: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    AlarmsChanged { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args AlarmsChangedArgs<'args>
+  ) -> flatbuffers::WIPOffset<AlarmsChanged<'bldr>> {
+    let mut builder = AlarmsChangedBuilder::new(_fbb);
+    if let Some(x) = args.alarms { builder.add_alarms(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn alarms(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Alarm<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Alarm>>>>(AlarmsChanged::VT_ALARMS, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for AlarmsChanged<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Alarm>>>>("alarms", Self::VT_ALARMS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct AlarmsChangedArgs<'a> {
+    pub alarms: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Alarm<'a>>>>>,
+}
+impl<'a> Default for AlarmsChangedArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    AlarmsChangedArgs {
+      alarms: None,
+    }
+  }
+}
+
+pub struct AlarmsChangedBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> AlarmsChangedBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_alarms(&mut self, alarms: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Alarm<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(AlarmsChanged::VT_ALARMS, alarms);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> AlarmsChangedBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    AlarmsChangedBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<AlarmsChanged<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for AlarmsChanged<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("AlarmsChanged");
+      ds.field("alarms", &self.alarms());
+      ds.finish()
+  }
+}
+pub enum AlarmFiredOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct AlarmFired<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for AlarmFired<'a> {
+  type Inner = AlarmFired<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> AlarmFired<'a> {
+  pub const VT_ALARM: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    AlarmFired { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args AlarmFiredArgs<'args>
+  ) -> flatbuffers::WIPOffset<AlarmFired<'bldr>> {
+    let mut builder = AlarmFiredBuilder::new(_fbb);
+    if let Some(x) = args.alarm { builder.add_alarm(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn alarm(&self) -> Option<Alarm<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<Alarm>>(AlarmFired::VT_ALARM, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for AlarmFired<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<Alarm>>("alarm", Self::VT_ALARM, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct AlarmFiredArgs<'a> {
+    pub alarm: Option<flatbuffers::WIPOffset<Alarm<'a>>>,
+}
+impl<'a> Default for AlarmFiredArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    AlarmFiredArgs {
+      alarm: None,
+    }
+  }
+}
+
+pub struct AlarmFiredBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> AlarmFiredBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_alarm(&mut self, alarm: flatbuffers::WIPOffset<Alarm<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<Alarm>>(AlarmFired::VT_ALARM, alarm);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> AlarmFiredBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    AlarmFiredBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<AlarmFired<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for AlarmFired<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("AlarmFired");
+      ds.field("alarm", &self.alarm());
+      ds.finish()
+  }
+}
+pub enum AlarmCommandOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct AlarmCommand<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for AlarmCommand<'a> {
+  type Inner = AlarmCommand<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> AlarmCommand<'a> {
+  pub const VT_COMMAND_TYPE: flatbuffers::VOffsetT = 4;
+  pub const VT_COMMAND: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    AlarmCommand { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args AlarmCommandArgs
+  ) -> flatbuffers::WIPOffset<AlarmCommand<'bldr>> {
+    let mut builder = AlarmCommandBuilder::new(_fbb);
+    if let Some(x) = args.command { builder.add_command(x); }
+    builder.add_command_type(args.command_type);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn command_type(&self) -> AlarmCommandContent {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<AlarmCommandContent>(AlarmCommand::VT_COMMAND_TYPE, Some(AlarmCommandContent::NONE)).unwrap()}
+  }
+  #[inline]
+  pub fn command(&self) -> Option<flatbuffers::Table<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(AlarmCommand::VT_COMMAND, None)}
+  }
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn command_as_create_alarm(&self) -> Option<CreateAlarm<'a>> {
+    if self.command_type() == AlarmCommandContent::CreateAlarm {
+      self.command().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { CreateAlarm::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn command_as_delete_alarm(&self) -> Option<DeleteAlarm<'a>> {
+    if self.command_type() == AlarmCommandContent::DeleteAlarm {
+      self.command().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { DeleteAlarm::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+}
+
+impl flatbuffers::Verifiable for AlarmCommand<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_union::<AlarmCommandContent, _>("command_type", Self::VT_COMMAND_TYPE, "command", Self::VT_COMMAND, false, |key, v, pos| {
+        match key {
+          AlarmCommandContent::CreateAlarm => v.verify_union_variant::<flatbuffers::ForwardsUOffset<CreateAlarm>>("AlarmCommandContent::CreateAlarm", pos),
+          AlarmCommandContent::DeleteAlarm => v.verify_union_variant::<flatbuffers::ForwardsUOffset<DeleteAlarm>>("AlarmCommandContent::DeleteAlarm", pos),
+          _ => Ok(()),
+        }
+     })?
+     .finish();
+    Ok(())
+  }
+}
+pub struct AlarmCommandArgs {
+    pub command_type: AlarmCommandContent,
+    pub command: Option<flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>>,
+}
+impl<'a> Default for AlarmCommandArgs {
+  #[inline]
+  fn default() -> Self {
+    AlarmCommandArgs {
+      command_type: AlarmCommandContent::NONE,
+      command: None,
+    }
+  }
+}
+
+pub struct AlarmCommandBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> AlarmCommandBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_command_type(&mut self, command_type: AlarmCommandContent) {
+    self.fbb_.push_slot::<AlarmCommandContent>(AlarmCommand::VT_COMMAND_TYPE, command_type, AlarmCommandContent::NONE);
+  }
+  #[inline]
+  pub fn add_command(&mut self, command: flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(AlarmCommand::VT_COMMAND, command);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> AlarmCommandBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    AlarmCommandBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<AlarmCommand<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for AlarmCommand<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("AlarmCommand");
+      ds.field("command_type", &self.command_type());
+      match self.command_type() {
+        AlarmCommandContent::CreateAlarm => {
+          if let Some(x) = self.command_as_create_alarm() {
+            ds.field("command", &x)
+          } else {
+            ds.field("command", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        AlarmCommandContent::DeleteAlarm => {
+          if let Some(x) = self.command_as_delete_alarm() {
+            ds.field("command", &x)
+          } else {
+            ds.field("command", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        _ => {
+          let x: Option<()> = None;
+          ds.field("command", &x)
+        },
+      };
+      ds.finish()
+  }
+}
+pub enum AlarmQueryOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct AlarmQuery<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for AlarmQuery<'a> {
+  type Inner = AlarmQuery<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> AlarmQuery<'a> {
+  pub const VT_QUERY_TYPE: flatbuffers::VOffsetT = 4;
+  pub const VT_QUERY: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    AlarmQuery { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args AlarmQueryArgs
+  ) -> flatbuffers::WIPOffset<AlarmQuery<'bldr>> {
+    let mut builder = AlarmQueryBuilder::new(_fbb);
+    if let Some(x) = args.query { builder.add_query(x); }
+    builder.add_query_type(args.query_type);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn query_type(&self) -> AlarmQueryContent {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<AlarmQueryContent>(AlarmQuery::VT_QUERY_TYPE, Some(AlarmQueryContent::NONE)).unwrap()}
+  }
+  #[inline]
+  pub fn query(&self) -> Option<flatbuffers::Table<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(AlarmQuery::VT_QUERY, None)}
+  }
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn query_as_query_alarms(&self) -> Option<QueryAlarms<'a>> {
+    if self.query_type() == AlarmQueryContent::QueryAlarms {
+      self.query().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { QueryAlarms::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+}
+
+impl flatbuffers::Verifiable for AlarmQuery<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_union::<AlarmQueryContent, _>("query_type", Self::VT_QUERY_TYPE, "query", Self::VT_QUERY, false, |key, v, pos| {
+        match key {
+          AlarmQueryContent::QueryAlarms => v.verify_union_variant::<flatbuffers::ForwardsUOffset<QueryAlarms>>("AlarmQueryContent::QueryAlarms", pos),
+          _ => Ok(()),
+        }
+     })?
+     .finish();
+    Ok(())
+  }
+}
+pub struct AlarmQueryArgs {
+    pub query_type: AlarmQueryContent,
+    pub query: Option<flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>>,
+}
+impl<'a> Default for AlarmQueryArgs {
+  #[inline]
+  fn default() -> Self {
+    AlarmQueryArgs {
+      query_type: AlarmQueryContent::NONE,
+      query: None,
+    }
+  }
+}
+
+pub struct AlarmQueryBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> AlarmQueryBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_query_type(&mut self, query_type: AlarmQueryContent) {
+    self.fbb_.push_slot::<AlarmQueryContent>(AlarmQuery::VT_QUERY_TYPE, query_type, AlarmQueryContent::NONE);
+  }
+  #[inline]
+  pub fn add_query(&mut self, query: flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(AlarmQuery::VT_QUERY, query);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> AlarmQueryBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    AlarmQueryBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<AlarmQuery<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for AlarmQuery<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("AlarmQuery");
+      ds.field("query_type", &self.query_type());
+      match self.query_type() {
+        AlarmQueryContent::QueryAlarms => {
+          if let Some(x) = self.query_as_query_alarms() {
+            ds.field("query", &x)
+          } else {
+            ds.field("query", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        _ => {
+          let x: Option<()> = None;
+          ds.field("query", &x)
+        },
+      };
+      ds.finish()
+  }
+}
+pub enum AlarmEventOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct AlarmEvent<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for AlarmEvent<'a> {
+  type Inner = AlarmEvent<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> AlarmEvent<'a> {
+  pub const VT_EVENT_TYPE: flatbuffers::VOffsetT = 4;
+  pub const VT_EVENT: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    AlarmEvent { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args AlarmEventArgs
+  ) -> flatbuffers::WIPOffset<AlarmEvent<'bldr>> {
+    let mut builder = AlarmEventBuilder::new(_fbb);
+    if let Some(x) = args.event { builder.add_event(x); }
+    builder.add_event_type(args.event_type);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn event_type(&self) -> AlarmEventContent {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<AlarmEventContent>(AlarmEvent::VT_EVENT_TYPE, Some(AlarmEventContent::NONE)).unwrap()}
+  }
+  #[inline]
+  pub fn event(&self) -> Option<flatbuffers::Table<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(AlarmEvent::VT_EVENT, None)}
+  }
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn event_as_alarms_changed(&self) -> Option<AlarmsChanged<'a>> {
+    if self.event_type() == AlarmEventContent::AlarmsChanged {
+      self.event().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { AlarmsChanged::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn event_as_alarm_fired(&self) -> Option<AlarmFired<'a>> {
+    if self.event_type() == AlarmEventContent::AlarmFired {
+      self.event().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { AlarmFired::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+}
+
+impl flatbuffers::Verifiable for AlarmEvent<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_union::<AlarmEventContent, _>("event_type", Self::VT_EVENT_TYPE, "event", Self::VT_EVENT, false, |key, v, pos| {
+        match key {
+          AlarmEventContent::AlarmsChanged => v.verify_union_variant::<flatbuffers::ForwardsUOffset<AlarmsChanged>>("AlarmEventContent::AlarmsChanged", pos),
+          AlarmEventContent::AlarmFired => v.verify_union_variant::<flatbuffers::ForwardsUOffset<AlarmFired>>("AlarmEventContent::AlarmFired", pos),
+          _ => Ok(()),
+        }
+     })?
+     .finish();
+    Ok(())
+  }
+}
+pub struct AlarmEventArgs {
+    pub event_type: AlarmEventContent,
+    pub event: Option<flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>>,
+}
+impl<'a> Default for AlarmEventArgs {
+  #[inline]
+  fn default() -> Self {
+    AlarmEventArgs {
+      event_type: AlarmEventContent::NONE,
+      event: None,
+    }
+  }
+}
+
+pub struct AlarmEventBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> AlarmEventBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_event_type(&mut self, event_type: AlarmEventContent) {
+    self.fbb_.push_slot::<AlarmEventContent>(AlarmEvent::VT_EVENT_TYPE, event_type, AlarmEventContent::NONE);
+  }
+  #[inline]
+  pub fn add_event(&mut self, event: flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(AlarmEvent::VT_EVENT, event);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> AlarmEventBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    AlarmEventBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<AlarmEvent<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for AlarmEvent<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("AlarmEvent");
+      ds.field("event_type", &self.event_type());
+      match self.event_type() {
+        AlarmEventContent::AlarmsChanged => {
+          if let Some(x) = self.event_as_alarms_changed() {
+            ds.field("event", &x)
+          } else {
+            ds.field("event", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        AlarmEventContent::AlarmFired => {
+          if let Some(x) = self.event_as_alarm_fired() {
+            ds.field("event", &x)
+          } else {
+            ds.field("event", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        _ => {
+          let x: Option<()> = None;
+          ds.field("event", &x)
+        },
+      };
+      ds.finish()
+  }
+}
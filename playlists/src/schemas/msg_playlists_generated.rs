@@ -9,18 +9,115 @@ use core::cmp::Ordering;
 extern crate flatbuffers;
 use self::flatbuffers::{EndianScalar, Follow};
 
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_REPEAT_MODE: i8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_REPEAT_MODE: i8 = 2;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_REPEAT_MODE: [RepeatMode; 3] = [
+  RepeatMode::Off,
+  RepeatMode::One,
+  RepeatMode::All,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct RepeatMode(pub i8);
+#[allow(non_upper_case_globals)]
+impl RepeatMode {
+  pub const Off: Self = Self(0);
+  pub const One: Self = Self(1);
+  pub const All: Self = Self(2);
+
+  pub const ENUM_MIN: i8 = 0;
+  pub const ENUM_MAX: i8 = 2;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::Off,
+    Self::One,
+    Self::All,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::Off => Some("Off"),
+      Self::One => Some("One"),
+      Self::All => Some("All"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for RepeatMode {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for RepeatMode {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = flatbuffers::read_scalar_at::<i8>(buf, loc);
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for RepeatMode {
+    type Output = RepeatMode;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<i8>(dst, self.0);
+    }
+}
+
+impl flatbuffers::EndianScalar for RepeatMode {
+  type Scalar = i8;
+  #[inline]
+  fn to_little_endian(self) -> i8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: i8) -> Self {
+    let b = i8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for RepeatMode {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    i8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for RepeatMode {}
+
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
 pub const ENUM_MIN_PLAYLIST_COMMAND_CONTENT: u8 = 0;
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
-pub const ENUM_MAX_PLAYLIST_COMMAND_CONTENT: u8 = 4;
+pub const ENUM_MAX_PLAYLIST_COMMAND_CONTENT: u8 = 11;
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
 #[allow(non_camel_case_types)]
-pub const ENUM_VALUES_PLAYLIST_COMMAND_CONTENT: [PlaylistCommandContent; 5] = [
+pub const ENUM_VALUES_PLAYLIST_COMMAND_CONTENT: [PlaylistCommandContent; 12] = [
   PlaylistCommandContent::NONE,
   PlaylistCommandContent::AddSong,
   PlaylistCommandContent::ReplaceSong,
   PlaylistCommandContent::RemoveSong,
   PlaylistCommandContent::InsertSong,
+  PlaylistCommandContent::SetRepeatMode,
+  PlaylistCommandContent::SetShuffle,
+  PlaylistCommandContent::MoveSong,
+  PlaylistCommandContent::ClearPlaylist,
+  PlaylistCommandContent::SkipToIndex,
+  PlaylistCommandContent::Next,
+  PlaylistCommandContent::Previous,
 ];
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
@@ -33,15 +130,29 @@ impl PlaylistCommandContent {
   pub const ReplaceSong: Self = Self(2);
   pub const RemoveSong: Self = Self(3);
   pub const InsertSong: Self = Self(4);
+  pub const SetRepeatMode: Self = Self(5);
+  pub const SetShuffle: Self = Self(6);
+  pub const MoveSong: Self = Self(7);
+  pub const ClearPlaylist: Self = Self(8);
+  pub const SkipToIndex: Self = Self(9);
+  pub const Next: Self = Self(10);
+  pub const Previous: Self = Self(11);
 
   pub const ENUM_MIN: u8 = 0;
-  pub const ENUM_MAX: u8 = 4;
+  pub const ENUM_MAX: u8 = 11;
   pub const ENUM_VALUES: &'static [Self] = &[
     Self::NONE,
     Self::AddSong,
     Self::ReplaceSong,
     Self::RemoveSong,
     Self::InsertSong,
+    Self::SetRepeatMode,
+    Self::SetShuffle,
+    Self::MoveSong,
+    Self::ClearPlaylist,
+    Self::SkipToIndex,
+    Self::Next,
+    Self::Previous,
   ];
   /// Returns the variant's name or "" if unknown.
   pub fn variant_name(self) -> Option<&'static str> {
@@ -51,6 +162,13 @@ impl PlaylistCommandContent {
       Self::ReplaceSong => Some("ReplaceSong"),
       Self::RemoveSong => Some("RemoveSong"),
       Self::InsertSong => Some("InsertSong"),
+      Self::SetRepeatMode => Some("SetRepeatMode"),
+      Self::SetShuffle => Some("SetShuffle"),
+      Self::MoveSong => Some("MoveSong"),
+      Self::ClearPlaylist => Some("ClearPlaylist"),
+      Self::SkipToIndex => Some("SkipToIndex"),
+      Self::Next => Some("Next"),
+      Self::Previous => Some("Previous"),
       _ => None,
     }
   }
@@ -301,6 +419,8 @@ impl<'a> Song<'a> {
   pub const VT_URL: flatbuffers::VOffsetT = 4;
   pub const VT_THUMBNAIL_B64: flatbuffers::VOffsetT = 6;
   pub const VT_TITLE: flatbuffers::VOffsetT = 8;
+  pub const VT_ID: flatbuffers::VOffsetT = 10;
+  pub const VT_ONE_SHOT: flatbuffers::VOffsetT = 12;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -312,9 +432,11 @@ impl<'a> Song<'a> {
     args: &'args SongArgs<'args>
   ) -> flatbuffers::WIPOffset<Song<'bldr>> {
     let mut builder = SongBuilder::new(_fbb);
+    if let Some(x) = args.id { builder.add_id(x); }
     if let Some(x) = args.title { builder.add_title(x); }
     if let Some(x) = args.thumbnail_b64 { builder.add_thumbnail_b64(x); }
     if let Some(x) = args.url { builder.add_url(x); }
+    builder.add_one_shot(args.one_shot);
     builder.finish()
   }
 
@@ -340,6 +462,20 @@ impl<'a> Song<'a> {
     // which contains a valid value in this slot
     unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Song::VT_TITLE, None)}
   }
+  #[inline]
+  pub fn id(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Song::VT_ID, None)}
+  }
+  #[inline]
+  pub fn one_shot(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(Song::VT_ONE_SHOT, Some(false)).unwrap()}
+  }
 }
 
 impl flatbuffers::Verifiable for Song<'_> {
@@ -352,6 +488,8 @@ impl flatbuffers::Verifiable for Song<'_> {
      .visit_field::<flatbuffers::ForwardsUOffset<&str>>("url", Self::VT_URL, false)?
      .visit_field::<flatbuffers::ForwardsUOffset<&str>>("thumbnail_b64", Self::VT_THUMBNAIL_B64, false)?
      .visit_field::<flatbuffers::ForwardsUOffset<&str>>("title", Self::VT_TITLE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("id", Self::VT_ID, false)?
+     .visit_field::<bool>("one_shot", Self::VT_ONE_SHOT, false)?
      .finish();
     Ok(())
   }
@@ -360,6 +498,8 @@ pub struct SongArgs<'a> {
     pub url: Option<flatbuffers::WIPOffset<&'a str>>,
     pub thumbnail_b64: Option<flatbuffers::WIPOffset<&'a str>>,
     pub title: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub id: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub one_shot: bool,
 }
 impl<'a> Default for SongArgs<'a> {
   #[inline]
@@ -368,6 +508,8 @@ impl<'a> Default for SongArgs<'a> {
       url: None,
       thumbnail_b64: None,
       title: None,
+      id: None,
+      one_shot: false,
     }
   }
 }
@@ -390,6 +532,14 @@ impl<'a: 'b, 'b> SongBuilder<'a, 'b> {
     self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Song::VT_TITLE, title);
   }
   #[inline]
+  pub fn add_id(&mut self, id: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Song::VT_ID, id);
+  }
+  #[inline]
+  pub fn add_one_shot(&mut self, one_shot: bool) {
+    self.fbb_.push_slot::<bool>(Song::VT_ONE_SHOT, one_shot, false);
+  }
+  #[inline]
   pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> SongBuilder<'a, 'b> {
     let start = _fbb.start_table();
     SongBuilder {
@@ -410,6 +560,8 @@ impl core::fmt::Debug for Song<'_> {
       ds.field("url", &self.url());
       ds.field("thumbnail_b64", &self.thumbnail_b64());
       ds.field("title", &self.title());
+      ds.field("id", &self.id());
+      ds.field("one_shot", &self.one_shot());
       ds.finish()
   }
 }
@@ -528,6 +680,7 @@ impl<'a> flatbuffers::Follow<'a> for ReplaceSong<'a> {
 impl<'a> ReplaceSong<'a> {
   pub const VT_INDEX: flatbuffers::VOffsetT = 4;
   pub const VT_SONG: flatbuffers::VOffsetT = 6;
+  pub const VT_ID: flatbuffers::VOffsetT = 8;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -539,6 +692,7 @@ impl<'a> ReplaceSong<'a> {
     args: &'args ReplaceSongArgs<'args>
   ) -> flatbuffers::WIPOffset<ReplaceSong<'bldr>> {
     let mut builder = ReplaceSongBuilder::new(_fbb);
+    if let Some(x) = args.id { builder.add_id(x); }
     if let Some(x) = args.song { builder.add_song(x); }
     builder.add_index(args.index);
     builder.finish()
@@ -559,6 +713,13 @@ impl<'a> ReplaceSong<'a> {
     // which contains a valid value in this slot
     unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<Song>>(ReplaceSong::VT_SONG, None)}
   }
+  #[inline]
+  pub fn id(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(ReplaceSong::VT_ID, None)}
+  }
 }
 
 impl flatbuffers::Verifiable for ReplaceSong<'_> {
@@ -570,6 +731,7 @@ impl flatbuffers::Verifiable for ReplaceSong<'_> {
     v.visit_table(pos)?
      .visit_field::<i32>("index", Self::VT_INDEX, false)?
      .visit_field::<flatbuffers::ForwardsUOffset<Song>>("song", Self::VT_SONG, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("id", Self::VT_ID, false)?
      .finish();
     Ok(())
   }
@@ -577,6 +739,7 @@ impl flatbuffers::Verifiable for ReplaceSong<'_> {
 pub struct ReplaceSongArgs<'a> {
     pub index: i32,
     pub song: Option<flatbuffers::WIPOffset<Song<'a>>>,
+    pub id: Option<flatbuffers::WIPOffset<&'a str>>,
 }
 impl<'a> Default for ReplaceSongArgs<'a> {
   #[inline]
@@ -584,6 +747,7 @@ impl<'a> Default for ReplaceSongArgs<'a> {
     ReplaceSongArgs {
       index: 0,
       song: None,
+      id: None,
     }
   }
 }
@@ -602,6 +766,10 @@ impl<'a: 'b, 'b> ReplaceSongBuilder<'a, 'b> {
     self.fbb_.push_slot_always::<flatbuffers::WIPOffset<Song>>(ReplaceSong::VT_SONG, song);
   }
   #[inline]
+  pub fn add_id(&mut self, id: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ReplaceSong::VT_ID, id);
+  }
+  #[inline]
   pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ReplaceSongBuilder<'a, 'b> {
     let start = _fbb.start_table();
     ReplaceSongBuilder {
@@ -621,6 +789,7 @@ impl core::fmt::Debug for ReplaceSong<'_> {
     let mut ds = f.debug_struct("ReplaceSong");
       ds.field("index", &self.index());
       ds.field("song", &self.song());
+      ds.field("id", &self.id());
       ds.finish()
   }
 }
@@ -641,6 +810,7 @@ impl<'a> flatbuffers::Follow<'a> for RemoveSong<'a> {
 
 impl<'a> RemoveSong<'a> {
   pub const VT_INDEX: flatbuffers::VOffsetT = 4;
+  pub const VT_ID: flatbuffers::VOffsetT = 6;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -649,9 +819,10 @@ impl<'a> RemoveSong<'a> {
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-    args: &'args RemoveSongArgs
+    args: &'args RemoveSongArgs<'args>
   ) -> flatbuffers::WIPOffset<RemoveSong<'bldr>> {
     let mut builder = RemoveSongBuilder::new(_fbb);
+    if let Some(x) = args.id { builder.add_id(x); }
     builder.add_index(args.index);
     builder.finish()
   }
@@ -664,6 +835,13 @@ impl<'a> RemoveSong<'a> {
     // which contains a valid value in this slot
     unsafe { self._tab.get::<i32>(RemoveSong::VT_INDEX, Some(0)).unwrap()}
   }
+  #[inline]
+  pub fn id(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(RemoveSong::VT_ID, None)}
+  }
 }
 
 impl flatbuffers::Verifiable for RemoveSong<'_> {
@@ -674,18 +852,21 @@ impl flatbuffers::Verifiable for RemoveSong<'_> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
      .visit_field::<i32>("index", Self::VT_INDEX, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("id", Self::VT_ID, false)?
      .finish();
     Ok(())
   }
 }
-pub struct RemoveSongArgs {
+pub struct RemoveSongArgs<'a> {
     pub index: i32,
+    pub id: Option<flatbuffers::WIPOffset<&'a str>>,
 }
-impl<'a> Default for RemoveSongArgs {
+impl<'a> Default for RemoveSongArgs<'a> {
   #[inline]
   fn default() -> Self {
     RemoveSongArgs {
       index: 0,
+      id: None,
     }
   }
 }
@@ -700,6 +881,10 @@ impl<'a: 'b, 'b> RemoveSongBuilder<'a, 'b> {
     self.fbb_.push_slot::<i32>(RemoveSong::VT_INDEX, index, 0);
   }
   #[inline]
+  pub fn add_id(&mut self, id: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(RemoveSong::VT_ID, id);
+  }
+  #[inline]
   pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> RemoveSongBuilder<'a, 'b> {
     let start = _fbb.start_table();
     RemoveSongBuilder {
@@ -718,6 +903,7 @@ impl core::fmt::Debug for RemoveSong<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
     let mut ds = f.debug_struct("RemoveSong");
       ds.field("index", &self.index());
+      ds.field("id", &self.id());
       ds.finish()
   }
 }
@@ -739,6 +925,7 @@ impl<'a> flatbuffers::Follow<'a> for InsertSong<'a> {
 impl<'a> InsertSong<'a> {
   pub const VT_INDEX: flatbuffers::VOffsetT = 4;
   pub const VT_SONG: flatbuffers::VOffsetT = 6;
+  pub const VT_BEFORE_ID: flatbuffers::VOffsetT = 8;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -750,6 +937,7 @@ impl<'a> InsertSong<'a> {
     args: &'args InsertSongArgs<'args>
   ) -> flatbuffers::WIPOffset<InsertSong<'bldr>> {
     let mut builder = InsertSongBuilder::new(_fbb);
+    if let Some(x) = args.before_id { builder.add_before_id(x); }
     if let Some(x) = args.song { builder.add_song(x); }
     builder.add_index(args.index);
     builder.finish()
@@ -770,6 +958,13 @@ impl<'a> InsertSong<'a> {
     // which contains a valid value in this slot
     unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<Song>>(InsertSong::VT_SONG, None)}
   }
+  #[inline]
+  pub fn before_id(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(InsertSong::VT_BEFORE_ID, None)}
+  }
 }
 
 impl flatbuffers::Verifiable for InsertSong<'_> {
@@ -781,6 +976,7 @@ impl flatbuffers::Verifiable for InsertSong<'_> {
     v.visit_table(pos)?
      .visit_field::<i32>("index", Self::VT_INDEX, false)?
      .visit_field::<flatbuffers::ForwardsUOffset<Song>>("song", Self::VT_SONG, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("before_id", Self::VT_BEFORE_ID, false)?
      .finish();
     Ok(())
   }
@@ -788,6 +984,7 @@ impl flatbuffers::Verifiable for InsertSong<'_> {
 pub struct InsertSongArgs<'a> {
     pub index: i32,
     pub song: Option<flatbuffers::WIPOffset<Song<'a>>>,
+    pub before_id: Option<flatbuffers::WIPOffset<&'a str>>,
 }
 impl<'a> Default for InsertSongArgs<'a> {
   #[inline]
@@ -795,6 +992,7 @@ impl<'a> Default for InsertSongArgs<'a> {
     InsertSongArgs {
       index: 0,
       song: None,
+      before_id: None,
     }
   }
 }
@@ -813,6 +1011,10 @@ impl<'a: 'b, 'b> InsertSongBuilder<'a, 'b> {
     self.fbb_.push_slot_always::<flatbuffers::WIPOffset<Song>>(InsertSong::VT_SONG, song);
   }
   #[inline]
+  pub fn add_before_id(&mut self, before_id: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(InsertSong::VT_BEFORE_ID, before_id);
+  }
+  #[inline]
   pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> InsertSongBuilder<'a, 'b> {
     let start = _fbb.start_table();
     InsertSongBuilder {
@@ -832,288 +1034,1018 @@ impl core::fmt::Debug for InsertSong<'_> {
     let mut ds = f.debug_struct("InsertSong");
       ds.field("index", &self.index());
       ds.field("song", &self.song());
+      ds.field("before_id", &self.before_id());
       ds.finish()
   }
 }
-pub enum QueryPlaylistStateOffset {}
+pub enum MoveSongOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct QueryPlaylistState<'a> {
+pub struct MoveSong<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for QueryPlaylistState<'a> {
-  type Inner = QueryPlaylistState<'a>;
+impl<'a> flatbuffers::Follow<'a> for MoveSong<'a> {
+  type Inner = MoveSong<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> QueryPlaylistState<'a> {
+impl<'a> MoveSong<'a> {
+  pub const VT_FROM: flatbuffers::VOffsetT = 4;
+  pub const VT_TO: flatbuffers::VOffsetT = 6;
+  pub const VT_ID: flatbuffers::VOffsetT = 8;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    QueryPlaylistState { _tab: table }
+    MoveSong { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-    _args: &'args QueryPlaylistStateArgs
-  ) -> flatbuffers::WIPOffset<QueryPlaylistState<'bldr>> {
-    let mut builder = QueryPlaylistStateBuilder::new(_fbb);
+    args: &'args MoveSongArgs<'args>
+  ) -> flatbuffers::WIPOffset<MoveSong<'bldr>> {
+    let mut builder = MoveSongBuilder::new(_fbb);
+    if let Some(x) = args.id { builder.add_id(x); }
+    builder.add_to(args.to);
+    builder.add_from(args.from);
     builder.finish()
   }
 
+
+  #[inline]
+  pub fn from(&self) -> i32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i32>(MoveSong::VT_FROM, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn to(&self) -> i32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i32>(MoveSong::VT_TO, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn id(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(MoveSong::VT_ID, None)}
+  }
 }
 
-impl flatbuffers::Verifiable for QueryPlaylistState<'_> {
+impl flatbuffers::Verifiable for MoveSong<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
+     .visit_field::<i32>("from", Self::VT_FROM, false)?
+     .visit_field::<i32>("to", Self::VT_TO, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("id", Self::VT_ID, false)?
      .finish();
     Ok(())
   }
 }
-pub struct QueryPlaylistStateArgs {
+pub struct MoveSongArgs<'a> {
+    pub from: i32,
+    pub to: i32,
+    pub id: Option<flatbuffers::WIPOffset<&'a str>>,
 }
-impl<'a> Default for QueryPlaylistStateArgs {
+impl<'a> Default for MoveSongArgs<'a> {
   #[inline]
   fn default() -> Self {
-    QueryPlaylistStateArgs {
+    MoveSongArgs {
+      from: 0,
+      to: 0,
+      id: None,
     }
   }
 }
 
-pub struct QueryPlaylistStateBuilder<'a: 'b, 'b> {
+pub struct MoveSongBuilder<'a: 'b, 'b> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> QueryPlaylistStateBuilder<'a, 'b> {
+impl<'a: 'b, 'b> MoveSongBuilder<'a, 'b> {
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> QueryPlaylistStateBuilder<'a, 'b> {
+  pub fn add_from(&mut self, from: i32) {
+    self.fbb_.push_slot::<i32>(MoveSong::VT_FROM, from, 0);
+  }
+  #[inline]
+  pub fn add_to(&mut self, to: i32) {
+    self.fbb_.push_slot::<i32>(MoveSong::VT_TO, to, 0);
+  }
+  #[inline]
+  pub fn add_id(&mut self, id: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(MoveSong::VT_ID, id);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> MoveSongBuilder<'a, 'b> {
     let start = _fbb.start_table();
-    QueryPlaylistStateBuilder {
+    MoveSongBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<QueryPlaylistState<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<MoveSong<'a>> {
     let o = self.fbb_.end_table(self.start_);
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for QueryPlaylistState<'_> {
+impl core::fmt::Debug for MoveSong<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("QueryPlaylistState");
+    let mut ds = f.debug_struct("MoveSong");
+      ds.field("from", &self.from());
+      ds.field("to", &self.to());
+      ds.field("id", &self.id());
       ds.finish()
   }
 }
-pub enum PlaylistStateChangedOffset {}
+pub enum ClearPlaylistOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct PlaylistStateChanged<'a> {
+pub struct ClearPlaylist<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for PlaylistStateChanged<'a> {
-  type Inner = PlaylistStateChanged<'a>;
+impl<'a> flatbuffers::Follow<'a> for ClearPlaylist<'a> {
+  type Inner = ClearPlaylist<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> PlaylistStateChanged<'a> {
-  pub const VT_SONGS: flatbuffers::VOffsetT = 4;
+impl<'a> ClearPlaylist<'a> {
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    PlaylistStateChanged { _tab: table }
+    ClearPlaylist { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-    args: &'args PlaylistStateChangedArgs<'args>
-  ) -> flatbuffers::WIPOffset<PlaylistStateChanged<'bldr>> {
-    let mut builder = PlaylistStateChangedBuilder::new(_fbb);
-    if let Some(x) = args.songs { builder.add_songs(x); }
+    _args: &'args ClearPlaylistArgs
+  ) -> flatbuffers::WIPOffset<ClearPlaylist<'bldr>> {
+    let mut builder = ClearPlaylistBuilder::new(_fbb);
     builder.finish()
   }
 
-
-  #[inline]
-  pub fn songs(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Song<'a>>>> {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Song>>>>(PlaylistStateChanged::VT_SONGS, None)}
-  }
 }
 
-impl flatbuffers::Verifiable for PlaylistStateChanged<'_> {
+impl flatbuffers::Verifiable for ClearPlaylist<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
-     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Song>>>>("songs", Self::VT_SONGS, false)?
      .finish();
     Ok(())
   }
 }
-pub struct PlaylistStateChangedArgs<'a> {
-    pub songs: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Song<'a>>>>>,
+pub struct ClearPlaylistArgs {
 }
-impl<'a> Default for PlaylistStateChangedArgs<'a> {
+impl<'a> Default for ClearPlaylistArgs {
   #[inline]
   fn default() -> Self {
-    PlaylistStateChangedArgs {
-      songs: None,
+    ClearPlaylistArgs {
     }
   }
 }
 
-pub struct PlaylistStateChangedBuilder<'a: 'b, 'b> {
+pub struct ClearPlaylistBuilder<'a: 'b, 'b> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> PlaylistStateChangedBuilder<'a, 'b> {
-  #[inline]
-  pub fn add_songs(&mut self, songs: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Song<'b >>>>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(PlaylistStateChanged::VT_SONGS, songs);
-  }
+impl<'a: 'b, 'b> ClearPlaylistBuilder<'a, 'b> {
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> PlaylistStateChangedBuilder<'a, 'b> {
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ClearPlaylistBuilder<'a, 'b> {
     let start = _fbb.start_table();
-    PlaylistStateChangedBuilder {
+    ClearPlaylistBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<PlaylistStateChanged<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<ClearPlaylist<'a>> {
     let o = self.fbb_.end_table(self.start_);
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for PlaylistStateChanged<'_> {
+impl core::fmt::Debug for ClearPlaylist<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("PlaylistStateChanged");
-      ds.field("songs", &self.songs());
+    let mut ds = f.debug_struct("ClearPlaylist");
       ds.finish()
   }
 }
-pub enum PlaylistCommandOffset {}
+pub enum SkipToIndexOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct PlaylistCommand<'a> {
+pub struct SkipToIndex<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for PlaylistCommand<'a> {
-  type Inner = PlaylistCommand<'a>;
+impl<'a> flatbuffers::Follow<'a> for SkipToIndex<'a> {
+  type Inner = SkipToIndex<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> PlaylistCommand<'a> {
-  pub const VT_DEVICE_ID: flatbuffers::VOffsetT = 4;
-  pub const VT_COMMAND_TYPE: flatbuffers::VOffsetT = 6;
-  pub const VT_COMMAND: flatbuffers::VOffsetT = 8;
+impl<'a> SkipToIndex<'a> {
+  pub const VT_INDEX: flatbuffers::VOffsetT = 4;
+  pub const VT_ID: flatbuffers::VOffsetT = 6;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    PlaylistCommand { _tab: table }
+    SkipToIndex { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-    args: &'args PlaylistCommandArgs<'args>
-  ) -> flatbuffers::WIPOffset<PlaylistCommand<'bldr>> {
-    let mut builder = PlaylistCommandBuilder::new(_fbb);
-    if let Some(x) = args.command { builder.add_command(x); }
-    if let Some(x) = args.device_id { builder.add_device_id(x); }
-    builder.add_command_type(args.command_type);
+    args: &'args SkipToIndexArgs<'args>
+  ) -> flatbuffers::WIPOffset<SkipToIndex<'bldr>> {
+    let mut builder = SkipToIndexBuilder::new(_fbb);
+    if let Some(x) = args.id { builder.add_id(x); }
+    builder.add_index(args.index);
     builder.finish()
   }
 
 
   #[inline]
-  pub fn device_id(&self) -> Option<&'a str> {
+  pub fn index(&self) -> i32 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(PlaylistCommand::VT_DEVICE_ID, None)}
+    unsafe { self._tab.get::<i32>(SkipToIndex::VT_INDEX, Some(0)).unwrap()}
   }
   #[inline]
-  pub fn command_type(&self) -> PlaylistCommandContent {
+  pub fn id(&self) -> Option<&'a str> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<PlaylistCommandContent>(PlaylistCommand::VT_COMMAND_TYPE, Some(PlaylistCommandContent::NONE)).unwrap()}
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(SkipToIndex::VT_ID, None)}
   }
+}
+
+impl flatbuffers::Verifiable for SkipToIndex<'_> {
   #[inline]
-  pub fn command(&self) -> Option<flatbuffers::Table<'a>> {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(PlaylistCommand::VT_COMMAND, None)}
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<i32>("index", Self::VT_INDEX, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("id", Self::VT_ID, false)?
+     .finish();
+    Ok(())
   }
+}
+pub struct SkipToIndexArgs<'a> {
+    pub index: i32,
+    pub id: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for SkipToIndexArgs<'a> {
   #[inline]
-  #[allow(non_snake_case)]
-  pub fn command_as_add_song(&self) -> Option<AddSong<'a>> {
-    if self.command_type() == PlaylistCommandContent::AddSong {
-      self.command().map(|t| {
-       // Safety:
-       // Created from a valid Table for this object
-       // Which contains a valid union in this slot
-       unsafe { AddSong::init_from_table(t) }
-     })
-    } else {
-      None
+  fn default() -> Self {
+    SkipToIndexArgs {
+      index: 0,
+      id: None,
     }
   }
+}
 
+pub struct SkipToIndexBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> SkipToIndexBuilder<'a, 'b> {
   #[inline]
-  #[allow(non_snake_case)]
-  pub fn command_as_replace_song(&self) -> Option<ReplaceSong<'a>> {
-    if self.command_type() == PlaylistCommandContent::ReplaceSong {
-      self.command().map(|t| {
-       // Safety:
-       // Created from a valid Table for this object
-       // Which contains a valid union in this slot
-       unsafe { ReplaceSong::init_from_table(t) }
-     })
-    } else {
-      None
-    }
+  pub fn add_index(&mut self, index: i32) {
+    self.fbb_.push_slot::<i32>(SkipToIndex::VT_INDEX, index, 0);
   }
-
   #[inline]
-  #[allow(non_snake_case)]
-  pub fn command_as_remove_song(&self) -> Option<RemoveSong<'a>> {
-    if self.command_type() == PlaylistCommandContent::RemoveSong {
-      self.command().map(|t| {
-       // Safety:
-       // Created from a valid Table for this object
-       // Which contains a valid union in this slot
-       unsafe { RemoveSong::init_from_table(t) }
-     })
-    } else {
-      None
-    }
+  pub fn add_id(&mut self, id: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SkipToIndex::VT_ID, id);
   }
-
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> SkipToIndexBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    SkipToIndexBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<SkipToIndex<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for SkipToIndex<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("SkipToIndex");
+      ds.field("index", &self.index());
+      ds.field("id", &self.id());
+      ds.finish()
+  }
+}
+pub enum NextOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Next<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Next<'a> {
+  type Inner = Next<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> Next<'a> {
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Next { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    _args: &'args NextArgs
+  ) -> flatbuffers::WIPOffset<Next<'bldr>> {
+    let mut builder = NextBuilder::new(_fbb);
+    builder.finish()
+  }
+
+}
+
+impl flatbuffers::Verifiable for Next<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct NextArgs {
+}
+impl<'a> Default for NextArgs {
+  #[inline]
+  fn default() -> Self {
+    NextArgs {
+    }
+  }
+}
+
+pub struct NextBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> NextBuilder<'a, 'b> {
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> NextBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    NextBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Next<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Next<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Next");
+      ds.finish()
+  }
+}
+pub enum PreviousOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Previous<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Previous<'a> {
+  type Inner = Previous<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> Previous<'a> {
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Previous { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    _args: &'args PreviousArgs
+  ) -> flatbuffers::WIPOffset<Previous<'bldr>> {
+    let mut builder = PreviousBuilder::new(_fbb);
+    builder.finish()
+  }
+
+}
+
+impl flatbuffers::Verifiable for Previous<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct PreviousArgs {
+}
+impl<'a> Default for PreviousArgs {
+  #[inline]
+  fn default() -> Self {
+    PreviousArgs {
+    }
+  }
+}
+
+pub struct PreviousBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> PreviousBuilder<'a, 'b> {
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> PreviousBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    PreviousBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Previous<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Previous<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Previous");
+      ds.finish()
+  }
+}
+pub enum SetRepeatModeOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct SetRepeatMode<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SetRepeatMode<'a> {
+  type Inner = SetRepeatMode<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> SetRepeatMode<'a> {
+  pub const VT_MODE: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    SetRepeatMode { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args SetRepeatModeArgs
+  ) -> flatbuffers::WIPOffset<SetRepeatMode<'bldr>> {
+    let mut builder = SetRepeatModeBuilder::new(_fbb);
+    builder.add_mode(args.mode);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn mode(&self) -> RepeatMode {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<RepeatMode>(SetRepeatMode::VT_MODE, Some(RepeatMode::Off)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for SetRepeatMode<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<RepeatMode>("mode", Self::VT_MODE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct SetRepeatModeArgs {
+    pub mode: RepeatMode,
+}
+impl<'a> Default for SetRepeatModeArgs {
+  #[inline]
+  fn default() -> Self {
+    SetRepeatModeArgs {
+      mode: RepeatMode::Off,
+    }
+  }
+}
+
+pub struct SetRepeatModeBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> SetRepeatModeBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_mode(&mut self, mode: RepeatMode) {
+    self.fbb_.push_slot::<RepeatMode>(SetRepeatMode::VT_MODE, mode, RepeatMode::Off);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> SetRepeatModeBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    SetRepeatModeBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<SetRepeatMode<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for SetRepeatMode<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("SetRepeatMode");
+      ds.field("mode", &self.mode());
+      ds.finish()
+  }
+}
+pub enum SetShuffleOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct SetShuffle<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SetShuffle<'a> {
+  type Inner = SetShuffle<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> SetShuffle<'a> {
+  pub const VT_SHUFFLE: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    SetShuffle { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args SetShuffleArgs
+  ) -> flatbuffers::WIPOffset<SetShuffle<'bldr>> {
+    let mut builder = SetShuffleBuilder::new(_fbb);
+    builder.add_shuffle(args.shuffle);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn shuffle(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(SetShuffle::VT_SHUFFLE, Some(false)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for SetShuffle<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<bool>("shuffle", Self::VT_SHUFFLE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct SetShuffleArgs {
+    pub shuffle: bool,
+}
+impl<'a> Default for SetShuffleArgs {
+  #[inline]
+  fn default() -> Self {
+    SetShuffleArgs {
+      shuffle: false,
+    }
+  }
+}
+
+pub struct SetShuffleBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> SetShuffleBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_shuffle(&mut self, shuffle: bool) {
+    self.fbb_.push_slot::<bool>(SetShuffle::VT_SHUFFLE, shuffle, false);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> SetShuffleBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    SetShuffleBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<SetShuffle<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for SetShuffle<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("SetShuffle");
+      ds.field("shuffle", &self.shuffle());
+      ds.finish()
+  }
+}
+pub enum QueryPlaylistStateOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct QueryPlaylistState<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for QueryPlaylistState<'a> {
+  type Inner = QueryPlaylistState<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> QueryPlaylistState<'a> {
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    QueryPlaylistState { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    _args: &'args QueryPlaylistStateArgs
+  ) -> flatbuffers::WIPOffset<QueryPlaylistState<'bldr>> {
+    let mut builder = QueryPlaylistStateBuilder::new(_fbb);
+    builder.finish()
+  }
+
+}
+
+impl flatbuffers::Verifiable for QueryPlaylistState<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct QueryPlaylistStateArgs {
+}
+impl<'a> Default for QueryPlaylistStateArgs {
+  #[inline]
+  fn default() -> Self {
+    QueryPlaylistStateArgs {
+    }
+  }
+}
+
+pub struct QueryPlaylistStateBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> QueryPlaylistStateBuilder<'a, 'b> {
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> QueryPlaylistStateBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    QueryPlaylistStateBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<QueryPlaylistState<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for QueryPlaylistState<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("QueryPlaylistState");
+      ds.finish()
+  }
+}
+pub enum PlaylistStateChangedOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct PlaylistStateChanged<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for PlaylistStateChanged<'a> {
+  type Inner = PlaylistStateChanged<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> PlaylistStateChanged<'a> {
+  pub const VT_SONGS: flatbuffers::VOffsetT = 4;
+  pub const VT_REPEAT: flatbuffers::VOffsetT = 6;
+  pub const VT_SHUFFLE: flatbuffers::VOffsetT = 8;
+  pub const VT_REVISION: flatbuffers::VOffsetT = 10;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    PlaylistStateChanged { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args PlaylistStateChangedArgs<'args>
+  ) -> flatbuffers::WIPOffset<PlaylistStateChanged<'bldr>> {
+    let mut builder = PlaylistStateChangedBuilder::new(_fbb);
+    builder.add_revision(args.revision);
+    if let Some(x) = args.songs { builder.add_songs(x); }
+    builder.add_shuffle(args.shuffle);
+    builder.add_repeat(args.repeat);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn songs(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Song<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Song>>>>(PlaylistStateChanged::VT_SONGS, None)}
+  }
+  #[inline]
+  pub fn repeat(&self) -> RepeatMode {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<RepeatMode>(PlaylistStateChanged::VT_REPEAT, Some(RepeatMode::Off)).unwrap()}
+  }
+  #[inline]
+  pub fn shuffle(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(PlaylistStateChanged::VT_SHUFFLE, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn revision(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(PlaylistStateChanged::VT_REVISION, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for PlaylistStateChanged<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Song>>>>("songs", Self::VT_SONGS, false)?
+     .visit_field::<RepeatMode>("repeat", Self::VT_REPEAT, false)?
+     .visit_field::<bool>("shuffle", Self::VT_SHUFFLE, false)?
+     .visit_field::<u64>("revision", Self::VT_REVISION, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct PlaylistStateChangedArgs<'a> {
+    pub songs: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Song<'a>>>>>,
+    pub repeat: RepeatMode,
+    pub shuffle: bool,
+    pub revision: u64,
+}
+impl<'a> Default for PlaylistStateChangedArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    PlaylistStateChangedArgs {
+      songs: None,
+      repeat: RepeatMode::Off,
+      shuffle: false,
+      revision: 0,
+    }
+  }
+}
+
+pub struct PlaylistStateChangedBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> PlaylistStateChangedBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_songs(&mut self, songs: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Song<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(PlaylistStateChanged::VT_SONGS, songs);
+  }
+  #[inline]
+  pub fn add_repeat(&mut self, repeat: RepeatMode) {
+    self.fbb_.push_slot::<RepeatMode>(PlaylistStateChanged::VT_REPEAT, repeat, RepeatMode::Off);
+  }
+  #[inline]
+  pub fn add_shuffle(&mut self, shuffle: bool) {
+    self.fbb_.push_slot::<bool>(PlaylistStateChanged::VT_SHUFFLE, shuffle, false);
+  }
+  #[inline]
+  pub fn add_revision(&mut self, revision: u64) {
+    self.fbb_.push_slot::<u64>(PlaylistStateChanged::VT_REVISION, revision, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> PlaylistStateChangedBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    PlaylistStateChangedBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<PlaylistStateChanged<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for PlaylistStateChanged<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("PlaylistStateChanged");
+      ds.field("songs", &self.songs());
+      ds.field("repeat", &self.repeat());
+      ds.field("shuffle", &self.shuffle());
+      ds.field("revision", &self.revision());
+      ds.finish()
+  }
+}
+pub enum PlaylistCommandOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct PlaylistCommand<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for PlaylistCommand<'a> {
+  type Inner = PlaylistCommand<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> PlaylistCommand<'a> {
+  pub const VT_DEVICE_ID: flatbuffers::VOffsetT = 4;
+  pub const VT_COMMAND_TYPE: flatbuffers::VOffsetT = 6;
+  pub const VT_COMMAND: flatbuffers::VOffsetT = 8;
+  pub const VT_EXPECTED_REVISION: flatbuffers::VOffsetT = 10;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    PlaylistCommand { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args PlaylistCommandArgs<'args>
+  ) -> flatbuffers::WIPOffset<PlaylistCommand<'bldr>> {
+    let mut builder = PlaylistCommandBuilder::new(_fbb);
+    builder.add_expected_revision(args.expected_revision);
+    if let Some(x) = args.command { builder.add_command(x); }
+    if let Some(x) = args.device_id { builder.add_device_id(x); }
+    builder.add_command_type(args.command_type);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn device_id(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(PlaylistCommand::VT_DEVICE_ID, None)}
+  }
+  #[inline]
+  pub fn command_type(&self) -> PlaylistCommandContent {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<PlaylistCommandContent>(PlaylistCommand::VT_COMMAND_TYPE, Some(PlaylistCommandContent::NONE)).unwrap()}
+  }
+  #[inline]
+  pub fn command(&self) -> Option<flatbuffers::Table<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(PlaylistCommand::VT_COMMAND, None)}
+  }
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn command_as_add_song(&self) -> Option<AddSong<'a>> {
+    if self.command_type() == PlaylistCommandContent::AddSong {
+      self.command().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { AddSong::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn command_as_replace_song(&self) -> Option<ReplaceSong<'a>> {
+    if self.command_type() == PlaylistCommandContent::ReplaceSong {
+      self.command().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { ReplaceSong::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn command_as_remove_song(&self) -> Option<RemoveSong<'a>> {
+    if self.command_type() == PlaylistCommandContent::RemoveSong {
+      self.command().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { RemoveSong::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
   #[inline]
   #[allow(non_snake_case)]
   pub fn command_as_insert_song(&self) -> Option<InsertSong<'a>> {
@@ -1129,6 +2061,118 @@ impl<'a> PlaylistCommand<'a> {
     }
   }
 
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn command_as_set_repeat_mode(&self) -> Option<SetRepeatMode<'a>> {
+    if self.command_type() == PlaylistCommandContent::SetRepeatMode {
+      self.command().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { SetRepeatMode::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn command_as_set_shuffle(&self) -> Option<SetShuffle<'a>> {
+    if self.command_type() == PlaylistCommandContent::SetShuffle {
+      self.command().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { SetShuffle::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn command_as_move_song(&self) -> Option<MoveSong<'a>> {
+    if self.command_type() == PlaylistCommandContent::MoveSong {
+      self.command().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { MoveSong::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn command_as_clear_playlist(&self) -> Option<ClearPlaylist<'a>> {
+    if self.command_type() == PlaylistCommandContent::ClearPlaylist {
+      self.command().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { ClearPlaylist::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn command_as_skip_to_index(&self) -> Option<SkipToIndex<'a>> {
+    if self.command_type() == PlaylistCommandContent::SkipToIndex {
+      self.command().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { SkipToIndex::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn command_as_next(&self) -> Option<Next<'a>> {
+    if self.command_type() == PlaylistCommandContent::Next {
+      self.command().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { Next::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn command_as_previous(&self) -> Option<Previous<'a>> {
+    if self.command_type() == PlaylistCommandContent::Previous {
+      self.command().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { Previous::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  pub fn expected_revision(&self) -> i64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i64>(PlaylistCommand::VT_EXPECTED_REVISION, Some(-1)).unwrap()}
+  }
 }
 
 impl flatbuffers::Verifiable for PlaylistCommand<'_> {
@@ -1145,9 +2189,17 @@ impl flatbuffers::Verifiable for PlaylistCommand<'_> {
           PlaylistCommandContent::ReplaceSong => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ReplaceSong>>("PlaylistCommandContent::ReplaceSong", pos),
           PlaylistCommandContent::RemoveSong => v.verify_union_variant::<flatbuffers::ForwardsUOffset<RemoveSong>>("PlaylistCommandContent::RemoveSong", pos),
           PlaylistCommandContent::InsertSong => v.verify_union_variant::<flatbuffers::ForwardsUOffset<InsertSong>>("PlaylistCommandContent::InsertSong", pos),
+          PlaylistCommandContent::SetRepeatMode => v.verify_union_variant::<flatbuffers::ForwardsUOffset<SetRepeatMode>>("PlaylistCommandContent::SetRepeatMode", pos),
+          PlaylistCommandContent::SetShuffle => v.verify_union_variant::<flatbuffers::ForwardsUOffset<SetShuffle>>("PlaylistCommandContent::SetShuffle", pos),
+          PlaylistCommandContent::MoveSong => v.verify_union_variant::<flatbuffers::ForwardsUOffset<MoveSong>>("PlaylistCommandContent::MoveSong", pos),
+          PlaylistCommandContent::ClearPlaylist => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ClearPlaylist>>("PlaylistCommandContent::ClearPlaylist", pos),
+          PlaylistCommandContent::SkipToIndex => v.verify_union_variant::<flatbuffers::ForwardsUOffset<SkipToIndex>>("PlaylistCommandContent::SkipToIndex", pos),
+          PlaylistCommandContent::Next => v.verify_union_variant::<flatbuffers::ForwardsUOffset<Next>>("PlaylistCommandContent::Next", pos),
+          PlaylistCommandContent::Previous => v.verify_union_variant::<flatbuffers::ForwardsUOffset<Previous>>("PlaylistCommandContent::Previous", pos),
           _ => Ok(()),
         }
      })?
+     .visit_field::<i64>("expected_revision", Self::VT_EXPECTED_REVISION, false)?
      .finish();
     Ok(())
   }
@@ -1156,6 +2208,7 @@ pub struct PlaylistCommandArgs<'a> {
     pub device_id: Option<flatbuffers::WIPOffset<&'a str>>,
     pub command_type: PlaylistCommandContent,
     pub command: Option<flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>>,
+    pub expected_revision: i64,
 }
 impl<'a> Default for PlaylistCommandArgs<'a> {
   #[inline]
@@ -1164,6 +2217,7 @@ impl<'a> Default for PlaylistCommandArgs<'a> {
       device_id: None,
       command_type: PlaylistCommandContent::NONE,
       command: None,
+      expected_revision: -1,
     }
   }
 }
@@ -1186,6 +2240,10 @@ impl<'a: 'b, 'b> PlaylistCommandBuilder<'a, 'b> {
     self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(PlaylistCommand::VT_COMMAND, command);
   }
   #[inline]
+  pub fn add_expected_revision(&mut self, expected_revision: i64) {
+    self.fbb_.push_slot::<i64>(PlaylistCommand::VT_EXPECTED_REVISION, expected_revision, -1);
+  }
+  #[inline]
   pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> PlaylistCommandBuilder<'a, 'b> {
     let start = _fbb.start_table();
     PlaylistCommandBuilder {
@@ -1234,11 +2292,61 @@ impl core::fmt::Debug for PlaylistCommand<'_> {
             ds.field("command", &"InvalidFlatbuffer: Union discriminant does not match value.")
           }
         },
+        PlaylistCommandContent::SetRepeatMode => {
+          if let Some(x) = self.command_as_set_repeat_mode() {
+            ds.field("command", &x)
+          } else {
+            ds.field("command", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        PlaylistCommandContent::SetShuffle => {
+          if let Some(x) = self.command_as_set_shuffle() {
+            ds.field("command", &x)
+          } else {
+            ds.field("command", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        PlaylistCommandContent::MoveSong => {
+          if let Some(x) = self.command_as_move_song() {
+            ds.field("command", &x)
+          } else {
+            ds.field("command", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        PlaylistCommandContent::ClearPlaylist => {
+          if let Some(x) = self.command_as_clear_playlist() {
+            ds.field("command", &x)
+          } else {
+            ds.field("command", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        PlaylistCommandContent::SkipToIndex => {
+          if let Some(x) = self.command_as_skip_to_index() {
+            ds.field("command", &x)
+          } else {
+            ds.field("command", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        PlaylistCommandContent::Next => {
+          if let Some(x) = self.command_as_next() {
+            ds.field("command", &x)
+          } else {
+            ds.field("command", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        PlaylistCommandContent::Previous => {
+          if let Some(x) = self.command_as_previous() {
+            ds.field("command", &x)
+          } else {
+            ds.field("command", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
         _ => {
           let x: Option<()> = None;
           ds.field("command", &x)
         },
       };
+      ds.field("expected_revision", &self.expected_revision());
       ds.finish()
   }
 }
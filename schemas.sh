@@ -46,6 +46,11 @@ if [ -n "$CONTAINER_ID" ]; then
   mkdir -p ./playlists/src/schemas
   cp -r /tmp/out/rs/* ./playlists/src/schemas
 
+  #Copy /tmp/out/rs to ./alarms/src/schemas
+  rm -rf ./alarms/src/schemas
+  mkdir -p ./alarms/src/schemas
+  cp -r /tmp/out/rs/* ./alarms/src/schemas
+
   # Stop and remove the container
   docker stop "$CONTAINER_ID"
   docker rm "$CONTAINER_ID"
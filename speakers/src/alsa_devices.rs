@@ -0,0 +1,46 @@
+use alsa::{device_name::HintIter, Direction};
+
+use crate::{
+    backend::DeviceEnumerator,
+    device_filter::DeviceFilter,
+    hardware_mixer::{get_hardware_volume, set_hardware_volume},
+};
+
+/// The machine's ALSA pcm devices and their mixers.
+pub struct AlsaDevices;
+
+pub fn list_pcm_devices(
+    direction: Direction,
+    device_filter: &DeviceFilter,
+) -> Result<Vec<String>, String> {
+    let hints = HintIter::new_str(None, "pcm").map_err(|e| e.to_string())?;
+    let mut devices = Vec::new();
+    for hint in hints {
+        let name = hint.name.unwrap_or_default();
+        let desc = hint.desc.unwrap_or_default();
+        if device_filter.matches(&name, &desc)
+            && (hint.direction.is_none()
+                || hint
+                    .direction
+                    .map(|dir| dir == direction)
+                    .unwrap_or_default())
+        {
+            devices.push(name);
+        }
+    }
+    Ok(devices)
+}
+
+impl DeviceEnumerator for AlsaDevices {
+    fn playback_devices(&self, device_filter: &DeviceFilter) -> Result<Vec<String>, String> {
+        list_pcm_devices(Direction::Playback, device_filter)
+    }
+
+    fn hardware_volume(&self, device_id: &str) -> Result<f32, String> {
+        get_hardware_volume(device_id)
+    }
+
+    fn set_hardware_volume(&self, device_id: &str, volume: f32) -> Result<(), String> {
+        set_hardware_volume(device_id, volume)
+    }
+}
@@ -0,0 +1,90 @@
+use std::{sync::Arc, time::Duration};
+
+use serde_json::Value;
+
+use crate::{device_filter::DeviceFilter, mpv_process::MpvChannel, PlayContent};
+
+/// A speaker's part in a group, which decides the ids its events are published under.
+#[derive(Clone, Debug, PartialEq)]
+pub enum GroupRole {
+    /// Also reports under the group id, and reports its track ending only there
+    Leader(String),
+    /// Follows the leader, so its own track ending is not reported at all
+    Member,
+}
+
+/// Starts players on playback devices. `MpvBackend` runs an mpv process for each.
+pub trait PlayerBackend: Send {
+    fn spawn(&self, device_id: &str, channel: MpvChannel) -> Result<Box<dyn Player>, String>;
+}
+
+/// Lists playback devices and drives their mixers. `AlsaDevices` asks ALSA.
+pub trait DeviceEnumerator: Send {
+    /// Playback devices passing `device_filter`, in the system's order
+    fn playback_devices(&self, device_filter: &DeviceFilter) -> Result<Vec<String>, String>;
+    /// Percent of the mixer's range
+    fn hardware_volume(&self, device_id: &str) -> Result<f32, String>;
+    fn set_hardware_volume(&self, device_id: &str, volume: f32) -> Result<(), String>;
+}
+
+/// One player on one device, the music or announcement channel of a speaker. Property names
+/// and values are mpv's; changes are reported as speaker events by the player itself.
+pub trait Player: Send {
+    /// Plays `url` unless the player already moved on to it from a preload. `kind` is the
+    /// `PlayContent` member it was requested as, reported back in `PlayStarted`.
+    fn play(&mut self, url: &str, kind: PlayContent) -> Result<(), String>;
+    fn play_wav(&mut self, data: &[u8]) -> Result<(), String>;
+    /// Queues `url` behind the current file so it starts without a gap.
+    fn preload(&mut self, url: &str, kind: PlayContent) -> Result<(), String>;
+    /// Loads `url` paused so several speakers can be started at the same moment.
+    fn loadfile_paused(&mut self, url: &str, kind: PlayContent) -> Result<(), String>;
+    /// Continues `url` from `position`, e.g. after the service restarted.
+    fn resume(
+        &mut self,
+        url: &str,
+        kind: PlayContent,
+        position: Option<f64>,
+        paused: bool,
+    ) -> Result<(), String>;
+    fn stop(&mut self) -> Result<(), String>;
+
+    fn set_property(&mut self, property: &str, value: Value) -> Result<(), String>;
+    /// Asks for the property to be published as its speaker event.
+    fn query_property(&mut self, property: &str) -> Result<(), String>;
+    fn cycle_property(&mut self, property: &str) -> Result<(), String>;
+    fn seek_relative(&mut self, offset: f32) -> Result<(), String>;
+    /// Overlaps the end of each track with the preloaded next one for `duration` seconds, the
+    /// end playing out on `tail`. No tail or a `duration` of 0 turns crossfading off.
+    fn set_crossfade(
+        &mut self,
+        tail: Option<Arc<dyn PlayerRemote>>,
+        duration: f32,
+    ) -> Result<(), String>;
+    /// A preset from `AUDIO_FILTER_PRESETS`, or "none"
+    fn set_audio_filter(&mut self, preset: &str) -> Result<(), String>;
+    /// Gain of the sleep timer's fade, `rebuild` for when nothing may be playing
+    fn set_sleep_gain(&mut self, gain: f32, rebuild: bool) -> Result<(), String>;
+
+    /// The group this speaker is part of, `None` once it plays on its own again
+    fn set_group_role(&mut self, role: Option<GroupRole>);
+    /// Sets the music this announcement channel ducks by `amount` while playing.
+    fn set_ducking(&mut self, music: Option<Arc<dyn PlayerRemote>>, amount: f32);
+    fn remote(&self) -> Arc<dyn PlayerRemote>;
+
+    fn is_playing_preloaded(&self, url: &str) -> bool;
+    fn current_path(&self) -> Option<String>;
+    /// What is playing, as which `PlayContent` member and where, if it can be resumed later.
+    fn resumable_position(&self) -> Option<(String, PlayContent, Option<f64>)>;
+    fn is_paused(&self) -> bool;
+    fn is_running(&self) -> bool;
+    fn kill(&mut self) -> Result<(), String>;
+}
+
+/// Handle for driving a player from another thread, e.g. a group's resync thread.
+pub trait PlayerRemote: Send + Sync {
+    fn get_property(&self, property: &str, timeout: Duration) -> Result<Value, String>;
+    fn set_property(&self, property: &str, value: Value) -> Result<(), String>;
+    fn command(&self, command: Value) -> Result<(), String>;
+    /// Whether the file loaded by `loadfile_paused` is ready to be unpaused.
+    fn is_ready(&self) -> bool;
+}
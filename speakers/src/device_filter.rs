@@ -0,0 +1,80 @@
+use glob::Pattern;
+use serde::Deserialize;
+
+const DEFAULT_DEVICE_FILTER_PATH: &str = "/config/device_filter.json";
+
+/// Glob lists as written in the config file. A device is listed if its name and description
+/// each match an include pattern and no exclude pattern.
+#[derive(Deserialize)]
+#[serde(default)]
+struct DeviceFilterConfig {
+    include_names: Vec<String>,
+    exclude_names: Vec<String>,
+    include_descriptions: Vec<String>,
+    exclude_descriptions: Vec<String>,
+}
+
+impl Default for DeviceFilterConfig {
+    fn default() -> Self {
+        Self {
+            include_names: vec!["plughw:*".to_string()],
+            exclude_names: vec!["*NVidia*".to_string()],
+            include_descriptions: vec!["*".to_string()],
+            exclude_descriptions: vec!["* Alt *".to_string()],
+        }
+    }
+}
+
+/// Decides which ALSA pcm devices show up as speakers.
+pub struct DeviceFilter {
+    include_names: Vec<Pattern>,
+    exclude_names: Vec<Pattern>,
+    include_descriptions: Vec<Pattern>,
+    exclude_descriptions: Vec<Pattern>,
+}
+
+impl DeviceFilter {
+    /// Loads the filter from `DEVICE_FILTER_PATH`, falling back to plughw devices without
+    /// NVidia and " Alt " outputs if the file doesn't exist.
+    pub fn load() -> Result<Self, String> {
+        let path = std::env::var("DEVICE_FILTER_PATH")
+            .unwrap_or_else(|_| DEFAULT_DEVICE_FILTER_PATH.to_string());
+        Self::load_from(&path)
+    }
+
+    pub fn load_from(path: &str) -> Result<Self, String> {
+        let config: DeviceFilterConfig = match std::fs::read_to_string(path) {
+            Ok(json) => serde_json::from_str(&json)
+                .map_err(|e| format!("Error parsing device filter {}: {}", path, e))?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                println!("No device filter at {}, using the default", path);
+                DeviceFilterConfig::default()
+            }
+            Err(e) => return Err(format!("Error reading device filter {}: {}", path, e)),
+        };
+
+        Ok(Self {
+            include_names: compile(&config.include_names)?,
+            exclude_names: compile(&config.exclude_names)?,
+            include_descriptions: compile(&config.include_descriptions)?,
+            exclude_descriptions: compile(&config.exclude_descriptions)?,
+        })
+    }
+
+    pub fn matches(&self, name: &str, description: &str) -> bool {
+        let matches_any =
+            |patterns: &[Pattern], text: &str| patterns.iter().any(|pattern| pattern.matches(text));
+
+        matches_any(&self.include_names, name)
+            && !matches_any(&self.exclude_names, name)
+            && matches_any(&self.include_descriptions, description)
+            && !matches_any(&self.exclude_descriptions, description)
+    }
+}
+
+fn compile(globs: &[String]) -> Result<Vec<Pattern>, String> {
+    globs
+        .iter()
+        .map(|glob| Pattern::new(glob).map_err(|e| format!("Invalid glob {}: {}", glob, e)))
+        .collect()
+}
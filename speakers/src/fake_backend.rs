@@ -0,0 +1,591 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use serde_json::Value;
+
+use crate::{
+    backend::{DeviceEnumerator, GroupRole, Player, PlayerBackend, PlayerRemote},
+    device_filter::DeviceFilter,
+    fbs::{
+        construct_duration_changed_event_message, construct_file_ended_event_message,
+        construct_mute_changed_event_message, construct_pause_changed_event_message,
+        construct_play_started_event_message, construct_seek_changed_event_message,
+        construct_speed_changed_event_message, construct_volume_changed_event_message,
+        EventPublisher,
+    },
+    mpv_process::{duck_command, MpvChannel},
+    PlayContent,
+};
+
+/// Messages published by the handler and the fake players, in order.
+#[derive(Default)]
+pub struct RecordedEvents {
+    messages: Mutex<Vec<(String, Vec<u8>)>>,
+}
+
+impl RecordedEvents {
+    pub fn take(&self) -> Vec<(String, Vec<u8>)> {
+        std::mem::take(&mut *self.messages.lock().unwrap())
+    }
+}
+
+impl EventPublisher for RecordedEvents {
+    fn publish(&self, topic: &str, message: Vec<u8>) {
+        self.messages
+            .lock()
+            .unwrap()
+            .push((topic.to_string(), message));
+    }
+}
+
+/// What a fake player is doing, shared with its remote.
+struct FakePlayback {
+    path: Option<String>,
+    kind: PlayContent,
+    preloaded: Option<String>,
+    paused: bool,
+    running: bool,
+    time_pos: f64,
+    properties: HashMap<String, Value>,
+    group_role: Option<GroupRole>,
+    /// Music an announcement channel ducks and by how much
+    ducking: Option<(Arc<dyn PlayerRemote>, f32)>,
+    /// Gains of the labelled volume filters in mpv's `af` list
+    filters: HashMap<String, String>,
+    /// Gains in the filter chain of the current file, built from `filters` when it starts
+    filter_chain: HashMap<String, String>,
+}
+
+impl FakePlayback {
+    /// Ids events are published under, like `MpvShared::event_device_ids` and, for a track
+    /// ending, `MpvShared::track_event_device_ids`.
+    fn event_ids(&self, device_id: &str, track: bool) -> Vec<String> {
+        match (&self.group_role, track) {
+            (Some(GroupRole::Leader(group_id)), false) => {
+                vec![device_id.to_string(), group_id.clone()]
+            }
+            (Some(GroupRole::Leader(group_id)), true) => vec![group_id.clone()],
+            (Some(GroupRole::Member), true) => Vec::new(),
+            _ => vec![device_id.to_string()],
+        }
+    }
+
+    /// Lowers or restores the ducked music, like mpv's recv thread on start-file and end-file.
+    fn duck(&self, active: bool) {
+        if let Some((music, amount)) = &self.ducking {
+            let gain = if active { 1.0 - amount } else { 1.0 };
+            music.command(duck_command(gain)).unwrap();
+        }
+    }
+}
+
+#[derive(Default)]
+struct FakeState {
+    devices: Vec<String>,
+    hardware_volumes: HashMap<String, f32>,
+    /// "device/channel: call" for everything done to a player
+    calls: Vec<String>,
+    /// Calls, in the same form, that return an error
+    failing: Vec<String>,
+    players: Vec<(String, MpvChannel, Arc<Mutex<FakePlayback>>)>,
+}
+
+/// In-memory stand-in for mpv and ALSA. Clones share the same devices and call log.
+#[derive(Clone)]
+pub struct FakeBackend {
+    state: Arc<Mutex<FakeState>>,
+    events: Arc<RecordedEvents>,
+}
+
+fn channel_name(channel: MpvChannel) -> &'static str {
+    match channel {
+        MpvChannel::Music => "music",
+        MpvChannel::Announcement => "announcement",
+        MpvChannel::Crossfade => "crossfade",
+    }
+}
+
+impl FakeBackend {
+    pub fn new(devices: &[&str], events: Arc<RecordedEvents>) -> Self {
+        let state = FakeState {
+            devices: devices.iter().map(|device| device.to_string()).collect(),
+            ..Default::default()
+        };
+        Self {
+            state: Arc::new(Mutex::new(state)),
+            events,
+        }
+    }
+
+    pub fn set_devices(&self, devices: &[&str]) {
+        self.state.lock().unwrap().devices =
+            devices.iter().map(|device| device.to_string()).collect();
+    }
+
+    /// Takes the calls made so far.
+    pub fn take_calls(&self) -> Vec<String> {
+        std::mem::take(&mut self.state.lock().unwrap().calls)
+    }
+
+    /// Ends the announcement playing on the device, restoring the music it ducked.
+    pub fn end_announcement(&self, device_id: &str) {
+        // Ducking records calls, so the state can't stay locked
+        let announcements: Vec<_> = self
+            .state
+            .lock()
+            .unwrap()
+            .players
+            .iter()
+            .filter(|(id, channel, _)| id == device_id && *channel == MpvChannel::Announcement)
+            .map(|(_, _, playback)| playback.clone())
+            .collect();
+        for playback in announcements {
+            let mut playback = playback.lock().unwrap();
+            if playback.path.take().is_some() {
+                playback.duck(false);
+            }
+        }
+    }
+
+    /// Plays the device's music to its end, reporting it like mpv's recv thread does.
+    pub fn end_file(&self, device_id: &str) {
+        let state = self.state.lock().unwrap();
+        for (id, channel, playback) in &state.players {
+            let mut playback = playback.lock().unwrap();
+            if id != device_id || *channel != MpvChannel::Music || playback.path.is_none() {
+                continue;
+            }
+            playback.path = None;
+            for id in playback.event_ids(device_id, true) {
+                self.events
+                    .publish("speaker.event", construct_file_ended_event_message(&id));
+            }
+        }
+    }
+
+    /// Gain the `label` filter applies to what the device's music plays right now.
+    pub fn filter_gain(&self, device_id: &str, label: &str) -> String {
+        let state = self.state.lock().unwrap();
+        let (_, _, playback) = state
+            .players
+            .iter()
+            .rev()
+            .find(|(id, channel, _)| id == device_id && *channel == MpvChannel::Music)
+            .expect("no music player");
+        let playback = playback.lock().unwrap();
+        playback
+            .filter_chain
+            .get(label)
+            .cloned()
+            .unwrap_or_else(|| "1".to_string())
+    }
+
+    /// Makes `call`, e.g. "plughw:CARD=Kitchen,DEV=0/music: stop", fail from now on.
+    pub fn fail(&self, call: &str) {
+        self.state.lock().unwrap().failing.push(call.to_string());
+    }
+
+    /// Makes the device's players exit as if mpv died.
+    pub fn crash(&self, device_id: &str) {
+        for (id, _, playback) in &self.state.lock().unwrap().players {
+            if id == device_id {
+                playback.lock().unwrap().running = false;
+            }
+        }
+    }
+}
+
+impl PlayerBackend for FakeBackend {
+    fn spawn(&self, device_id: &str, channel: MpvChannel) -> Result<Box<dyn Player>, String> {
+        let playback = Arc::new(Mutex::new(FakePlayback {
+            path: None,
+            kind: PlayContent::NONE,
+            preloaded: None,
+            paused: false,
+            running: true,
+            time_pos: 0.0,
+            properties: HashMap::new(),
+            group_role: None,
+            ducking: None,
+            filters: HashMap::new(),
+            filter_chain: HashMap::new(),
+        }));
+
+        let mut state = self.state.lock().unwrap();
+        state
+            .players
+            .push((device_id.to_string(), channel, playback.clone()));
+        let prefix = format!("{}/{}", device_id, channel_name(channel));
+        state.calls.push(format!("{}: spawn", prefix));
+
+        Ok(Box::new(FakePlayer {
+            device_id: device_id.to_string(),
+            channel,
+            prefix,
+            playback,
+            state: self.state.clone(),
+            events: self.events.clone(),
+        }))
+    }
+}
+
+impl DeviceEnumerator for FakeBackend {
+    fn playback_devices(&self, device_filter: &DeviceFilter) -> Result<Vec<String>, String> {
+        let state = self.state.lock().unwrap();
+        Ok(state
+            .devices
+            .iter()
+            .filter(|device| device_filter.matches(device, device))
+            .cloned()
+            .collect())
+    }
+
+    fn hardware_volume(&self, device_id: &str) -> Result<f32, String> {
+        let state = self.state.lock().unwrap();
+        if !state.devices.iter().any(|device| device == device_id) {
+            return Err(format!("No mixer on {}", device_id));
+        }
+        Ok(*state.hardware_volumes.get(device_id).unwrap_or(&100.0))
+    }
+
+    fn set_hardware_volume(&self, device_id: &str, volume: f32) -> Result<(), String> {
+        self.hardware_volume(device_id)?;
+        self.state
+            .lock()
+            .unwrap()
+            .hardware_volumes
+            .insert(device_id.to_string(), volume.clamp(0.0, 100.0));
+        Ok(())
+    }
+}
+
+pub struct FakePlayer {
+    device_id: String,
+    channel: MpvChannel,
+    prefix: String,
+    playback: Arc<Mutex<FakePlayback>>,
+    state: Arc<Mutex<FakeState>>,
+    events: Arc<RecordedEvents>,
+}
+
+impl FakePlayer {
+    fn record(&self, call: String) {
+        self.state
+            .lock()
+            .unwrap()
+            .calls
+            .push(format!("{}: {}", self.prefix, call));
+    }
+
+    /// Records `call` and returns an error if it was made to fail.
+    fn record_fallible(&self, call: String) -> Result<(), String> {
+        let call = format!("{}: {}", self.prefix, call);
+        let mut state = self.state.lock().unwrap();
+        let failing = state.failing.contains(&call);
+        state.calls.push(call.clone());
+        if failing {
+            return Err(format!("{} failed", call));
+        }
+        Ok(())
+    }
+
+    /// Publishes under the device and the group it leads, like mpv's events are.
+    fn publish(&self, construct: impl Fn(&str) -> Vec<u8>) {
+        let ids = self
+            .playback
+            .lock()
+            .unwrap()
+            .event_ids(&self.device_id, false);
+        for id in ids {
+            self.events.publish("speaker.event", construct(&id));
+        }
+    }
+
+    /// Publishes the property as its speaker event.
+    fn publish_property(&self, property: &str) -> Result<(), String> {
+        let value = get_playback_property(&self.playback.lock().unwrap(), property);
+        let number = value.as_f64().unwrap_or(0.0) as f32;
+        let flag = value.as_bool().unwrap_or(false);
+        match property {
+            "volume" => self.publish(|id| construct_volume_changed_event_message(number, id)),
+            "pause" => self.publish(|id| construct_pause_changed_event_message(flag, id)),
+            "time-pos" => self.publish(|id| construct_seek_changed_event_message(number, id)),
+            "duration" => self.publish(|id| construct_duration_changed_event_message(number, id)),
+            "mute" => self.publish(|id| construct_mute_changed_event_message(flag, id)),
+            "speed" => self.publish(|id| construct_speed_changed_event_message(number, id)),
+            _ => return Err(format!("Unknown property {}", property)),
+        }
+        Ok(())
+    }
+
+    /// Reports a change of the property like the music player's observers do.
+    fn observe(&self, property: &str) {
+        if self.channel == MpvChannel::Music {
+            // Only the properties with an event are of interest
+            let _ = self.publish_property(property);
+        }
+    }
+
+    fn start(&self, url: &str, kind: PlayContent, paused: bool) {
+        let mut playback = self.playback.lock().unwrap();
+        if self.channel == MpvChannel::Announcement && playback.path.is_none() {
+            playback.duck(true);
+        }
+        playback.path = Some(url.to_string());
+        playback.kind = kind;
+        playback.paused = paused;
+        playback.time_pos = 0.0;
+        playback.filter_chain = playback.filters.clone();
+    }
+}
+
+impl Player for FakePlayer {
+    fn play(&mut self, url: &str, kind: PlayContent) -> Result<(), String> {
+        self.record(format!("play {}", url));
+        self.start(url, kind, false);
+        self.publish(|id| construct_play_started_event_message(kind, Some(url), id));
+        Ok(())
+    }
+
+    fn play_wav(&mut self, data: &[u8]) -> Result<(), String> {
+        self.record(format!("play_wav {} bytes", data.len()));
+        self.start("wav", PlayContent::PlayWav, false);
+        Ok(())
+    }
+
+    fn preload(&mut self, url: &str, kind: PlayContent) -> Result<(), String> {
+        self.record(format!("preload {} {:?}", url, kind));
+        self.playback.lock().unwrap().preloaded = Some(url.to_string());
+        Ok(())
+    }
+
+    fn loadfile_paused(&mut self, url: &str, kind: PlayContent) -> Result<(), String> {
+        self.record(format!("loadfile_paused {}", url));
+        self.start(url, kind, true);
+        Ok(())
+    }
+
+    fn resume(
+        &mut self,
+        url: &str,
+        kind: PlayContent,
+        position: Option<f64>,
+        paused: bool,
+    ) -> Result<(), String> {
+        self.record(format!(
+            "resume {} {:?} {:?} {}",
+            url, kind, position, paused
+        ));
+        self.start(url, kind, paused);
+        self.playback.lock().unwrap().time_pos = position.unwrap_or(0.0);
+        Ok(())
+    }
+
+    fn stop(&mut self) -> Result<(), String> {
+        self.record_fallible("stop".to_string())?;
+        self.playback.lock().unwrap().path = None;
+        Ok(())
+    }
+
+    fn set_property(&mut self, property: &str, value: Value) -> Result<(), String> {
+        self.record(format!("set_property {} {}", property, value));
+        let changed = {
+            let mut playback = self.playback.lock().unwrap();
+            let changed = get_playback_property(&playback, property) != value;
+            set_playback_property(&mut playback, property, value);
+            changed
+        };
+        if changed {
+            self.observe(property);
+        }
+        Ok(())
+    }
+
+    fn query_property(&mut self, property: &str) -> Result<(), String> {
+        self.record(format!("query_property {}", property));
+        self.publish_property(property)
+    }
+
+    fn cycle_property(&mut self, property: &str) -> Result<(), String> {
+        self.record(format!("cycle_property {}", property));
+        {
+            let mut playback = self.playback.lock().unwrap();
+            let value = get_playback_property(&playback, property)
+                .as_bool()
+                .ok_or(format!("{} is not a flag", property))?;
+            set_playback_property(&mut playback, property, Value::Bool(!value));
+        }
+        self.observe(property);
+        Ok(())
+    }
+
+    fn seek_relative(&mut self, offset: f32) -> Result<(), String> {
+        self.record(format!("seek_relative {}", offset));
+        let mut playback = self.playback.lock().unwrap();
+        playback.time_pos = (playback.time_pos + offset as f64).max(0.0);
+        Ok(())
+    }
+
+    fn set_crossfade(
+        &mut self,
+        tail: Option<Arc<dyn PlayerRemote>>,
+        duration: f32,
+    ) -> Result<(), String> {
+        self.record(format!("set_crossfade {} {}", tail.is_some(), duration));
+        Ok(())
+    }
+
+    fn set_audio_filter(&mut self, preset: &str) -> Result<(), String> {
+        self.record(format!("set_audio_filter {}", preset));
+        Ok(())
+    }
+
+    fn set_sleep_gain(&mut self, gain: f32, rebuild: bool) -> Result<(), String> {
+        self.record(format!("set_sleep_gain {:.2} {}", gain, rebuild));
+        Ok(())
+    }
+
+    fn set_group_role(&mut self, role: Option<GroupRole>) {
+        self.record(format!("set_group_role {:?}", role));
+        self.playback.lock().unwrap().group_role = role;
+    }
+
+    fn set_ducking(&mut self, music: Option<Arc<dyn PlayerRemote>>, amount: f32) {
+        self.record(format!("set_ducking {} {}", music.is_some(), amount));
+        self.playback.lock().unwrap().ducking = music.map(|music| (music, amount));
+    }
+
+    fn remote(&self) -> Arc<dyn PlayerRemote> {
+        Arc::new(FakeRemote {
+            prefix: self.prefix.clone(),
+            playback: self.playback.clone(),
+            state: self.state.clone(),
+        })
+    }
+
+    fn is_playing_preloaded(&self, url: &str) -> bool {
+        let playback = self.playback.lock().unwrap();
+        playback.preloaded.as_deref() == Some(url) && playback.path.as_deref() == Some(url)
+    }
+
+    fn current_path(&self) -> Option<String> {
+        self.playback.lock().unwrap().path.clone()
+    }
+
+    fn resumable_position(&self) -> Option<(String, PlayContent, Option<f64>)> {
+        let playback = self.playback.lock().unwrap();
+        Some((
+            playback.path.clone()?,
+            playback.kind,
+            Some(playback.time_pos),
+        ))
+    }
+
+    fn is_paused(&self) -> bool {
+        self.playback.lock().unwrap().paused
+    }
+
+    fn is_running(&self) -> bool {
+        self.playback.lock().unwrap().running
+    }
+
+    fn kill(&mut self) -> Result<(), String> {
+        self.record("kill".to_string());
+        self.playback.lock().unwrap().running = false;
+        Ok(())
+    }
+}
+
+fn get_playback_property(playback: &FakePlayback, property: &str) -> Value {
+    match property {
+        "pause" => Value::Bool(playback.paused),
+        "time-pos" => Value::from(playback.time_pos),
+        _ => playback
+            .properties
+            .get(property)
+            .cloned()
+            .unwrap_or(Value::Null),
+    }
+}
+
+fn set_playback_property(playback: &mut FakePlayback, property: &str, value: Value) {
+    match property {
+        "pause" => playback.paused = value.as_bool().unwrap_or(false),
+        "time-pos" => playback.time_pos = value.as_f64().unwrap_or(0.0),
+        _ => {
+            playback.properties.insert(property.to_string(), value);
+        }
+    }
+}
+
+pub struct FakeRemote {
+    prefix: String,
+    playback: Arc<Mutex<FakePlayback>>,
+    state: Arc<Mutex<FakeState>>,
+}
+
+impl FakeRemote {
+    fn record(&self, call: String) {
+        self.state
+            .lock()
+            .unwrap()
+            .calls
+            .push(format!("{} remote: {}", self.prefix, call));
+    }
+}
+
+impl PlayerRemote for FakeRemote {
+    fn get_property(&self, property: &str, _timeout: Duration) -> Result<Value, String> {
+        Ok(get_playback_property(
+            &self.playback.lock().unwrap(),
+            property,
+        ))
+    }
+
+    fn set_property(&self, property: &str, value: Value) -> Result<(), String> {
+        self.record(format!("set_property {} {}", property, value));
+        set_playback_property(&mut self.playback.lock().unwrap(), property, value);
+        Ok(())
+    }
+
+    fn command(&self, command: Value) -> Result<(), String> {
+        self.record(format!("command {}", command));
+        let mut playback = self.playback.lock().unwrap();
+        let playing = playback.path.is_some();
+        match command.as_array().map(Vec::as_slice) {
+            // "@label:lavfi=[volume=gain]" replaces the filter, and the chain if one is playing
+            Some([Value::String(af), Value::String(add), Value::String(filter)])
+                if af == "af" && add == "add" =>
+            {
+                if let Some((label, gain)) = filter
+                    .strip_prefix('@')
+                    .and_then(|filter| filter.strip_suffix(']'))
+                    .and_then(|filter| filter.split_once(":lavfi=[volume="))
+                {
+                    playback.filters.insert(label.to_string(), gain.to_string());
+                    if playing {
+                        playback.filter_chain = playback.filters.clone();
+                    }
+                }
+            }
+            // Only reaches a chain that is playing, and is lost with it
+            Some([Value::String(af_command), Value::String(label), _, Value::String(gain)])
+                if af_command == "af-command" =>
+            {
+                if !playing {
+                    return Err("af-command needs a playing filter chain".to_string());
+                }
+                playback.filter_chain.insert(label.clone(), gain.clone());
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn is_ready(&self) -> bool {
+        self.playback.lock().unwrap().path.is_some()
+    }
+}
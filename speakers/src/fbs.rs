@@ -1,26 +1,53 @@
+use std::sync::Arc;
+
 use nats::Connection;
 
 use crate::{
-    Error, ErrorArgs, FileEnded, FileEndedArgs, Message, MessageArgs, MessageContent,
-    MusicVolumeChanged, MusicVolumeChangedArgs, PauseChanged, PauseChangedArgs, PlayStopped,
-    PlayStoppedArgs, SeekChanged, SeekChangedArgs, SpeakerEvent, SpeakerEventArgs,
-    SpeakerEventContent, SpeakerListEvent, SpeakerListEventArgs,
+    mpv_handler::SpeakerStatus, mpv_process::MediaInfo, AnnouncementEnded, AnnouncementEndedArgs,
+    AnnouncementStarted, AnnouncementStartedArgs, AudioFilterChanged, AudioFilterChangedArgs,
+    Error, ErrorArgs, FileEnded, FileEndedArgs, HardwareVolumeChanged, HardwareVolumeChangedArgs,
+    Message, MessageArgs, MessageContent, MusicVolumeChanged, MusicVolumeChangedArgs, MuteChanged,
+    MuteChangedArgs, NowPlaying, NowPlayingArgs, PauseChanged, PauseChangedArgs, PlayContent,
+    PlayDlna, PlayDlnaArgs, PlayLocalFile, PlayLocalFileArgs, PlayStarted, PlayStartedArgs,
+    PlayStopped, PlayStoppedArgs, PlayStream, PlayStreamArgs, PlayWav, PlayWavArgs, PlayYoutube,
+    PlayYoutubeArgs, PlaybackFailed, PlaybackFailedArgs, SeekChanged, SeekChangedArgs,
+    SleepTimerFired, SleepTimerFiredArgs, SleepTimerSet, SleepTimerSetArgs, SpeakerEvent,
+    SpeakerEventArgs, SpeakerEventContent, SpeakerGroupChanged, SpeakerGroupChangedArgs,
+    SpeakerInfo, SpeakerInfoArgs, SpeakerListEvent, SpeakerListEventArgs, SpeedChanged,
+    SpeedChangedArgs,
 };
 
+/// Where speaker events and errors go, the NATS connection outside of tests.
+pub trait EventPublisher: Send + Sync {
+    fn publish(&self, topic: &str, message: Vec<u8>);
+}
+
+impl EventPublisher for Connection {
+    fn publish(&self, topic: &str, message: Vec<u8>) {
+        Connection::publish(self, topic, message).unwrap();
+    }
+}
+
+impl<T: EventPublisher + ?Sized> EventPublisher for Arc<T> {
+    fn publish(&self, topic: &str, message: Vec<u8>) {
+        (**self).publish(topic, message)
+    }
+}
+
 pub trait NcSendable {
-    fn send(self, nc: &Connection, topic: &str);
+    fn send(self, nc: &dyn EventPublisher, topic: &str);
 }
 
 impl NcSendable for Result<Option<Vec<u8>>, String> {
-    fn send(self, nc: &Connection, topic: &str) {
+    fn send(self, nc: &dyn EventPublisher, topic: &str) {
         match self {
             Ok(Some(event)) => {
-                nc.publish(topic, event).unwrap();
+                nc.publish(topic, event);
             }
             Ok(None) => {}
             Err(err) => {
                 let error_message = construct_error_message(err);
-                nc.publish("error", error_message).unwrap();
+                nc.publish("error", error_message);
             }
         }
     }
@@ -36,7 +63,7 @@ fn get_current_timestamp() -> u64 {
 pub fn construct_volume_changed_event_message(volume: f32, device_id: &str) -> Vec<u8> {
     let mut fbb = flatbuffers::FlatBufferBuilder::new();
     let volume_changed_event =
-        MusicVolumeChanged::create(&mut fbb, &MusicVolumeChangedArgs { volume: volume });
+        MusicVolumeChanged::create(&mut fbb, &MusicVolumeChangedArgs { volume });
 
     let device_id_str = fbb.create_string(device_id);
 
@@ -65,21 +92,90 @@ pub fn construct_volume_changed_event_message(volume: f32, device_id: &str) -> V
     fbb.finished_data().to_vec()
 }
 
-pub fn construct_speaker_list_event_message(speakers: Vec<String>) -> Vec<u8> {
+pub fn construct_hardware_volume_changed_event_message(volume: f32, device_id: &str) -> Vec<u8> {
+    let mut fbb = flatbuffers::FlatBufferBuilder::new();
+    let volume_changed_event =
+        HardwareVolumeChanged::create(&mut fbb, &HardwareVolumeChangedArgs { volume });
+
+    let device_id_str = fbb.create_string(device_id);
+
+    let speaker_event = SpeakerEvent::create(
+        &mut fbb,
+        &SpeakerEventArgs {
+            event_type: SpeakerEventContent::HardwareVolumeChanged,
+            event: Some(volume_changed_event.as_union_value()),
+            device_id: Some(device_id_str),
+        },
+    );
+
+    //get current time in seconds
+    let timestamp = get_current_timestamp();
+
+    let root = Message::create(
+        &mut fbb,
+        &MessageArgs {
+            timestamp,
+            content_type: MessageContent::SpeakerEvent,
+            content: Some(speaker_event.as_union_value()),
+        },
+    );
+    fbb.finish(root, None);
+
+    fbb.finished_data().to_vec()
+}
+
+pub fn construct_speaker_list_event_message(speakers: &[SpeakerStatus]) -> Vec<u8> {
     let mut fbb = flatbuffers::FlatBufferBuilder::new();
 
+    let mut device_id_list = Vec::new();
     let mut speaker_list = Vec::new();
     for speaker in speakers {
-        let speaker_str = fbb.create_string(&speaker);
-        speaker_list.push(speaker_str);
+        let device_id_str = fbb.create_string(&speaker.device_id);
+        device_id_list.push(device_id_str);
+
+        let name_str = speaker
+            .info
+            .name
+            .as_deref()
+            .map(|name| fbb.create_string(name));
+        let room_str = speaker
+            .info
+            .room
+            .as_deref()
+            .map(|room| fbb.create_string(room));
+        let icon_str = speaker
+            .info
+            .icon
+            .as_deref()
+            .map(|icon| fbb.create_string(icon));
+        let url_str = speaker.url.as_deref().map(|url| fbb.create_string(url));
+
+        let speaker_info = SpeakerInfo::create(
+            &mut fbb,
+            &SpeakerInfoArgs {
+                device_id: Some(device_id_str),
+                name: name_str,
+                room: room_str,
+                icon: icon_str,
+                default_volume: speaker.info.default_volume.unwrap_or(-1.0),
+                hidden: speaker.info.hidden,
+                music_volume: speaker.music_volume,
+                playing: speaker.url.is_some(),
+                paused: speaker.paused,
+                url: url_str,
+            },
+        );
+        speaker_list.push(speaker_info);
     }
 
+    let device_id_vec = fbb.create_vector(&device_id_list);
     let speaker_vec = fbb.create_vector(&speaker_list);
 
     let speaker_list_event = SpeakerListEvent::create(
         &mut fbb,
         &SpeakerListEventArgs {
-            device_ids: Some(speaker_vec),
+            device_ids: Some(device_id_vec),
+            speakers: Some(speaker_vec),
         },
     );
 
@@ -109,6 +205,7 @@ pub fn construct_error_message(error: String) -> Vec<u8> {
         &ErrorArgs {
             from: Some(from_str),
             message: Some(error_str),
+            ..Default::default()
         },
     );
 
@@ -157,6 +254,127 @@ pub fn construct_seek_changed_event_message(seek: f32, device_id: &str) -> Vec<u
     fbb.finished_data().to_vec()
 }
 
+/// `kind` is the `PlayContent` member the file was requested as, `url` what mpv loaded.
+pub fn construct_play_started_event_message(
+    kind: PlayContent,
+    url: Option<&str>,
+    device_id: &str,
+) -> Vec<u8> {
+    let mut fbb = flatbuffers::FlatBufferBuilder::new();
+
+    let device_id_str = fbb.create_string(device_id);
+
+    // Spooled WAVs have no URL worth reporting, their data isn't sent back either
+    let url = url.filter(|_| kind != PlayContent::PlayWav);
+    let url_str = url.map(|url| fbb.create_string(url));
+    let (content_type, content) = match (kind, url_str) {
+        (PlayContent::PlayLocalFile, Some(path)) => (
+            kind,
+            PlayLocalFile::create(&mut fbb, &PlayLocalFileArgs { path: Some(path) })
+                .as_union_value(),
+        ),
+        (PlayContent::PlayStream, Some(url)) => (
+            kind,
+            PlayStream::create(&mut fbb, &PlayStreamArgs { url: Some(url) }).as_union_value(),
+        ),
+        (PlayContent::PlayDlna, Some(url)) => (
+            kind,
+            PlayDlna::create(&mut fbb, &PlayDlnaArgs { url: Some(url) }).as_union_value(),
+        ),
+        (_, Some(url)) => (
+            PlayContent::PlayYoutube,
+            PlayYoutube::create(&mut fbb, &PlayYoutubeArgs { url: Some(url) }).as_union_value(),
+        ),
+        (_, None) => (
+            PlayContent::PlayWav,
+            PlayWav::create(&mut fbb, &PlayWavArgs { data: None }).as_union_value(),
+        ),
+    };
+
+    let play_started = PlayStarted::create(
+        &mut fbb,
+        &PlayStartedArgs {
+            content_type,
+            content: Some(content),
+        },
+    );
+
+    let speaker_event = SpeakerEvent::create(
+        &mut fbb,
+        &SpeakerEventArgs {
+            event_type: SpeakerEventContent::PlayStarted,
+            device_id: Some(device_id_str),
+            event: Some(play_started.as_union_value()),
+        },
+    );
+
+    let timestamp = get_current_timestamp();
+
+    let root = Message::create(
+        &mut fbb,
+        &MessageArgs {
+            timestamp,
+            content_type: MessageContent::SpeakerEvent,
+            content: Some(speaker_event.as_union_value()),
+        },
+    );
+    fbb.finish(root, None);
+
+    fbb.finished_data().to_vec()
+}
+
+pub fn construct_now_playing_event_message(media_info: &MediaInfo, device_id: &str) -> Vec<u8> {
+    let mut fbb = flatbuffers::FlatBufferBuilder::new();
+
+    let device_id_str = fbb.create_string(device_id);
+    let title_str = media_info
+        .title
+        .as_deref()
+        .map(|title| fbb.create_string(title));
+    let stream_title_str = media_info
+        .stream_title
+        .as_deref()
+        .map(|stream_title| fbb.create_string(stream_title));
+    let chapter_title_str = media_info
+        .chapter_title
+        .as_deref()
+        .map(|chapter_title| fbb.create_string(chapter_title));
+
+    let now_playing = NowPlaying::create(
+        &mut fbb,
+        &NowPlayingArgs {
+            title: title_str,
+            stream_title: stream_title_str,
+            chapter: media_info.chapter,
+            chapters: media_info.chapters,
+            chapter_title: chapter_title_str,
+        },
+    );
+
+    let speaker_event = SpeakerEvent::create(
+        &mut fbb,
+        &SpeakerEventArgs {
+            event_type: SpeakerEventContent::NowPlaying,
+            device_id: Some(device_id_str),
+            event: Some(now_playing.as_union_value()),
+        },
+    );
+
+    let timestamp = get_current_timestamp();
+
+    let root = Message::create(
+        &mut fbb,
+        &MessageArgs {
+            timestamp,
+            content_type: MessageContent::SpeakerEvent,
+            content: Some(speaker_event.as_union_value()),
+        },
+    );
+    fbb.finish(root, None);
+
+    fbb.finished_data().to_vec()
+}
+
 pub fn construct_play_stopped_event_message(device_id: &str) -> Vec<u8> {
     let mut fbb = flatbuffers::FlatBufferBuilder::new();
 
@@ -219,6 +437,105 @@ pub fn construct_pause_changed_event_message(paused: bool, device_id: &str) -> V
     fbb.finished_data().to_vec()
 }
 
+pub fn construct_mute_changed_event_message(muted: bool, device_id: &str) -> Vec<u8> {
+    let mut fbb = flatbuffers::FlatBufferBuilder::new();
+
+    let mute_changed_event = MuteChanged::create(&mut fbb, &MuteChangedArgs { muted });
+
+    let device_id_str = fbb.create_string(device_id);
+
+    let speaker_event = SpeakerEvent::create(
+        &mut fbb,
+        &SpeakerEventArgs {
+            event_type: SpeakerEventContent::MuteChanged,
+            event: Some(mute_changed_event.as_union_value()),
+            device_id: Some(device_id_str),
+        },
+    );
+
+    let timestamp = get_current_timestamp();
+
+    let root = Message::create(
+        &mut fbb,
+        &MessageArgs {
+            timestamp,
+            content_type: MessageContent::SpeakerEvent,
+            content: Some(speaker_event.as_union_value()),
+        },
+    );
+    fbb.finish(root, None);
+
+    fbb.finished_data().to_vec()
+}
+
+pub fn construct_speed_changed_event_message(speed: f32, device_id: &str) -> Vec<u8> {
+    let mut fbb = flatbuffers::FlatBufferBuilder::new();
+
+    let speed_changed_event = SpeedChanged::create(&mut fbb, &SpeedChangedArgs { speed });
+
+    let device_id_str = fbb.create_string(device_id);
+
+    let speaker_event = SpeakerEvent::create(
+        &mut fbb,
+        &SpeakerEventArgs {
+            event_type: SpeakerEventContent::SpeedChanged,
+            event: Some(speed_changed_event.as_union_value()),
+            device_id: Some(device_id_str),
+        },
+    );
+
+    let timestamp = get_current_timestamp();
+
+    let root = Message::create(
+        &mut fbb,
+        &MessageArgs {
+            timestamp,
+            content_type: MessageContent::SpeakerEvent,
+            content: Some(speaker_event.as_union_value()),
+        },
+    );
+    fbb.finish(root, None);
+
+    fbb.finished_data().to_vec()
+}
+
+pub fn construct_audio_filter_changed_event_message(preset: &str, device_id: &str) -> Vec<u8> {
+    let mut fbb = flatbuffers::FlatBufferBuilder::new();
+
+    let preset_str = fbb.create_string(preset);
+    let audio_filter_changed_event = AudioFilterChanged::create(
+        &mut fbb,
+        &AudioFilterChangedArgs {
+            preset: Some(preset_str),
+        },
+    );
+
+    let device_id_str = fbb.create_string(device_id);
+
+    let speaker_event = SpeakerEvent::create(
+        &mut fbb,
+        &SpeakerEventArgs {
+            event_type: SpeakerEventContent::AudioFilterChanged,
+            event: Some(audio_filter_changed_event.as_union_value()),
+            device_id: Some(device_id_str),
+        },
+    );
+
+    let timestamp = get_current_timestamp();
+
+    let root = Message::create(
+        &mut fbb,
+        &MessageArgs {
+            timestamp,
+            content_type: MessageContent::SpeakerEvent,
+            content: Some(speaker_event.as_union_value()),
+        },
+    );
+    fbb.finish(root, None);
+
+    fbb.finished_data().to_vec()
+}
+
 pub fn construct_duration_changed_event_message(duration: f32, device_id: &str) -> Vec<u8> {
     let mut fbb = flatbuffers::FlatBufferBuilder::new();
 
@@ -280,3 +597,220 @@ pub fn construct_file_ended_event_message(device_id: &str) -> Vec<u8> {
 
     fbb.finished_data().to_vec()
 }
+
+pub fn construct_announcement_started_event_message(device_id: &str) -> Vec<u8> {
+    let mut fbb = flatbuffers::FlatBufferBuilder::new();
+
+    let device_id_str = fbb.create_string(device_id);
+
+    let announcement_started = AnnouncementStarted::create(&mut fbb, &AnnouncementStartedArgs {});
+
+    let speaker_event = SpeakerEvent::create(
+        &mut fbb,
+        &SpeakerEventArgs {
+            event_type: SpeakerEventContent::AnnouncementStarted,
+            device_id: Some(device_id_str),
+            event: Some(announcement_started.as_union_value()),
+        },
+    );
+
+    let timestamp = get_current_timestamp();
+
+    let root = Message::create(
+        &mut fbb,
+        &MessageArgs {
+            timestamp,
+            content_type: MessageContent::SpeakerEvent,
+            content: Some(speaker_event.as_union_value()),
+        },
+    );
+    fbb.finish(root, None);
+
+    fbb.finished_data().to_vec()
+}
+
+pub fn construct_announcement_ended_event_message(device_id: &str) -> Vec<u8> {
+    let mut fbb = flatbuffers::FlatBufferBuilder::new();
+
+    let device_id_str = fbb.create_string(device_id);
+
+    let announcement_ended = AnnouncementEnded::create(&mut fbb, &AnnouncementEndedArgs {});
+
+    let speaker_event = SpeakerEvent::create(
+        &mut fbb,
+        &SpeakerEventArgs {
+            event_type: SpeakerEventContent::AnnouncementEnded,
+            device_id: Some(device_id_str),
+            event: Some(announcement_ended.as_union_value()),
+        },
+    );
+
+    let timestamp = get_current_timestamp();
+
+    let root = Message::create(
+        &mut fbb,
+        &MessageArgs {
+            timestamp,
+            content_type: MessageContent::SpeakerEvent,
+            content: Some(speaker_event.as_union_value()),
+        },
+    );
+    fbb.finish(root, None);
+
+    fbb.finished_data().to_vec()
+}
+
+pub fn construct_sleep_timer_set_event_message(
+    remaining: f32,
+    fade: f32,
+    device_id: &str,
+) -> Vec<u8> {
+    let mut fbb = flatbuffers::FlatBufferBuilder::new();
+
+    let sleep_timer_set_event =
+        SleepTimerSet::create(&mut fbb, &SleepTimerSetArgs { remaining, fade });
+
+    let device_id_str = fbb.create_string(device_id);
+
+    let speaker_event = SpeakerEvent::create(
+        &mut fbb,
+        &SpeakerEventArgs {
+            event_type: SpeakerEventContent::SleepTimerSet,
+            event: Some(sleep_timer_set_event.as_union_value()),
+            device_id: Some(device_id_str),
+        },
+    );
+
+    let timestamp = get_current_timestamp();
+
+    let root = Message::create(
+        &mut fbb,
+        &MessageArgs {
+            timestamp,
+            content_type: MessageContent::SpeakerEvent,
+            content: Some(speaker_event.as_union_value()),
+        },
+    );
+    fbb.finish(root, None);
+
+    fbb.finished_data().to_vec()
+}
+
+pub fn construct_sleep_timer_fired_event_message(device_id: &str) -> Vec<u8> {
+    let mut fbb = flatbuffers::FlatBufferBuilder::new();
+
+    let device_id_str = fbb.create_string(device_id);
+
+    let sleep_timer_fired = SleepTimerFired::create(&mut fbb, &SleepTimerFiredArgs {});
+
+    let speaker_event = SpeakerEvent::create(
+        &mut fbb,
+        &SpeakerEventArgs {
+            event_type: SpeakerEventContent::SleepTimerFired,
+            device_id: Some(device_id_str),
+            event: Some(sleep_timer_fired.as_union_value()),
+        },
+    );
+
+    let timestamp = get_current_timestamp();
+
+    let root = Message::create(
+        &mut fbb,
+        &MessageArgs {
+            timestamp,
+            content_type: MessageContent::SpeakerEvent,
+            content: Some(speaker_event.as_union_value()),
+        },
+    );
+    fbb.finish(root, None);
+
+    fbb.finished_data().to_vec()
+}
+
+pub fn construct_playback_failed_event_message(
+    reason: &str,
+    error: &str,
+    device_id: &str,
+) -> Vec<u8> {
+    let mut fbb = flatbuffers::FlatBufferBuilder::new();
+
+    let device_id_str = fbb.create_string(device_id);
+    let reason_str = fbb.create_string(reason);
+    let error_str = fbb.create_string(error);
+
+    let playback_failed = PlaybackFailed::create(
+        &mut fbb,
+        &PlaybackFailedArgs {
+            reason: Some(reason_str),
+            error: Some(error_str),
+        },
+    );
+
+    let speaker_event = SpeakerEvent::create(
+        &mut fbb,
+        &SpeakerEventArgs {
+            event_type: SpeakerEventContent::PlaybackFailed,
+            device_id: Some(device_id_str),
+            event: Some(playback_failed.as_union_value()),
+        },
+    );
+
+    let timestamp = get_current_timestamp();
+
+    let root = Message::create(
+        &mut fbb,
+        &MessageArgs {
+            timestamp,
+            content_type: MessageContent::SpeakerEvent,
+            content: Some(speaker_event.as_union_value()),
+        },
+    );
+    fbb.finish(root, None);
+
+    fbb.finished_data().to_vec()
+}
+
+pub fn construct_speaker_group_changed_event_message(
+    device_ids: &[String],
+    group_id: &str,
+) -> Vec<u8> {
+    let mut fbb = flatbuffers::FlatBufferBuilder::new();
+
+    let device_id_str = fbb.create_string(group_id);
+
+    let mut member_list = Vec::new();
+    for device_id in device_ids {
+        member_list.push(fbb.create_string(device_id));
+    }
+    let member_vec = fbb.create_vector(&member_list);
+
+    let speaker_group_changed = SpeakerGroupChanged::create(
+        &mut fbb,
+        &SpeakerGroupChangedArgs {
+            device_ids: Some(member_vec),
+        },
+    );
+
+    let speaker_event = SpeakerEvent::create(
+        &mut fbb,
+        &SpeakerEventArgs {
+            event_type: SpeakerEventContent::SpeakerGroupChanged,
+            device_id: Some(device_id_str),
+            event: Some(speaker_group_changed.as_union_value()),
+        },
+    );
+
+    let timestamp = get_current_timestamp();
+
+    let root = Message::create(
+        &mut fbb,
+        &MessageArgs {
+            timestamp,
+            content_type: MessageContent::SpeakerEvent,
+            content: Some(speaker_event.as_union_value()),
+        },
+    );
+    fbb.finish(root, None);
+
+    fbb.finished_data().to_vec()
+}
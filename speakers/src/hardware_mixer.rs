@@ -0,0 +1,52 @@
+use alsa::mixer::{Mixer, Selem, SelemChannelId, SelemId};
+
+// The first of these the card has controls its output
+const MIXER_ELEMENTS: &[&str] = &["Master", "PCM", "Speaker", "Headphone"];
+
+/// ALSA control device of the card behind a pcm name like `plughw:CARD=PCH,DEV=0`. Devices
+/// without a card (default, pulse, ...) use the default mixer.
+fn mixer_device(device_id: &str) -> String {
+    match device_id.split_once("CARD=") {
+        Some((_, card)) => format!("hw:{}", card.split(',').next().unwrap_or_default()),
+        None => "default".to_string(),
+    }
+}
+
+fn with_selem<T>(
+    device_id: &str,
+    f: impl FnOnce(&Selem) -> Result<T, alsa::Error>,
+) -> Result<T, String> {
+    let mixer_device = mixer_device(device_id);
+    let mixer = Mixer::new(&mixer_device, false)
+        .map_err(|e| format!("Error opening mixer {}: {}", mixer_device, e))?;
+
+    let selem = MIXER_ELEMENTS
+        .iter()
+        .filter_map(|name| mixer.find_selem(&SelemId::new(name, 0)))
+        .find(|selem| selem.has_playback_volume())
+        .ok_or(format!("No playback volume control on {}", mixer_device))?;
+
+    f(&selem).map_err(|e| format!("Error using mixer {}: {}", mixer_device, e))
+}
+
+/// Returns the card's hardware volume as a percent of the mixer's range.
+pub fn get_hardware_volume(device_id: &str) -> Result<f32, String> {
+    with_selem(device_id, |selem| {
+        let (min, max) = selem.get_playback_volume_range();
+        let volume = selem.get_playback_volume(SelemChannelId::mono())?;
+        if max <= min {
+            return Ok(100.0);
+        }
+        Ok((volume - min) as f32 * 100.0 / (max - min) as f32)
+    })
+}
+
+/// Sets every channel of the card's mixer to `volume` percent of its range.
+pub fn set_hardware_volume(device_id: &str, volume: f32) -> Result<(), String> {
+    with_selem(device_id, |selem| {
+        let (min, max) = selem.get_playback_volume_range();
+        let fraction = volume.clamp(0.0, 100.0) / 100.0;
+        let value = min + ((max - min) as f32 * fraction).round() as i64;
+        selem.set_playback_volume_all(value)
+    })
+}
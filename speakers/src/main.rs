@@ -1,37 +1,55 @@
 extern crate flatbuffers;
 
-#[allow(dead_code, unused_imports)]
+#[allow(dead_code, unused_imports, warnings, clippy::all)]
 #[path = "./schemas/msg_playlists_generated.rs"]
 mod msg_playlists_generated;
 
-#[allow(dead_code, unused_imports)]
+#[allow(dead_code, unused_imports, warnings, clippy::all)]
 #[path = "./schemas/msg_speakers_generated.rs"]
 mod msg_speakers_generated;
 
-#[allow(dead_code, unused_imports)]
+#[allow(dead_code, unused_imports, warnings, clippy::all)]
 #[path = "./schemas/msg_echo_generated.rs"]
 mod msg_echo_generated;
 
-#[allow(dead_code, unused_imports)]
+#[allow(dead_code, unused_imports, warnings, clippy::all)]
 #[path = "./schemas/msg_print_generated.rs"]
 mod msg_print_generated;
 
-#[allow(dead_code, unused_imports)]
+#[allow(dead_code, unused_imports, warnings, clippy::all)]
 #[path = "./schemas/msg_error_generated.rs"]
 mod msg_error_generated;
 
-#[allow(dead_code, unused_imports)]
+#[allow(dead_code, unused_imports, warnings, clippy::all)]
+#[path = "./schemas/msg_alarms_generated.rs"]
+mod msg_alarms_generated;
+
+#[allow(dead_code, unused_imports, warnings, clippy::all)]
 #[path = "./schemas/root_generated.rs"]
 mod root_generated;
 
+pub mod alsa_devices;
+pub mod backend;
+pub mod device_filter;
+#[cfg(test)]
+pub mod fake_backend;
 pub mod fbs;
+pub mod hardware_mixer;
+pub mod media_source;
 pub mod mpv_handler;
 pub mod mpv_process;
+pub mod sleep_timer;
+pub mod speaker_group;
+pub mod speaker_registry;
+pub mod speaker_store;
 
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
 use fbs::NcSendable;
-use mpv_handler::MpvHandler;
+use mpv_handler::{
+    make_device_watcher_thread, make_sleep_timer_thread, make_state_saver_thread, MpvHandler,
+};
+pub use msg_alarms_generated::*;
 pub use msg_echo_generated::*;
 pub use msg_error_generated::*;
 pub use msg_playlists_generated::*;
@@ -41,12 +59,16 @@ pub use root_generated::*;
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let nc = Arc::new(nats::connect("nats://nats-server:4222")?);
-    let mut mpv_handler = MpvHandler::new(nc.clone())?;
+    let mpv_handler = Arc::new(Mutex::new(MpvHandler::new(nc.clone())?));
+    make_device_watcher_thread(Arc::clone(&mpv_handler));
+    make_state_saver_thread(Arc::clone(&mpv_handler));
+    make_sleep_timer_thread(Arc::clone(&mpv_handler));
     //listen for messages on all subjects
     let sub = nc.subscribe("speaker.*")?;
 
     for msg in sub.messages() {
         let message = root_as_message(msg.data.as_slice())?;
+        let mut mpv_handler = mpv_handler.lock().unwrap();
 
         match message.content_type() {
             MessageContent::SpeakerCommand => {
@@ -0,0 +1,121 @@
+use std::path::{Path, PathBuf};
+
+use crate::{
+    Play, PlayAnnouncement, PlayContent, PlayDlna, PlayLocalFile, PlayStream, PlayWav, PlayYoutube,
+    Preload,
+};
+
+const DEFAULT_MUSIC_DIR: &str = "/music";
+
+/// What a `PlayContent` union asks mpv to play, validated.
+pub enum PlaySource<'a> {
+    /// URL or absolute path for loadfile, and the union member it came from
+    Url(PlayContent, String),
+    Wav(&'a [u8]),
+}
+
+impl<'a> PlaySource<'a> {
+    pub fn from_play(play: Play<'a>) -> Result<Self, String> {
+        Self::new(
+            play.content_as_play_youtube(),
+            play.content_as_play_wav(),
+            play.content_as_play_local_file(),
+            play.content_as_play_stream(),
+            play.content_as_play_dlna(),
+        )
+    }
+
+    pub fn from_announcement(announcement: PlayAnnouncement<'a>) -> Result<Self, String> {
+        Self::new(
+            announcement.content_as_play_youtube(),
+            announcement.content_as_play_wav(),
+            announcement.content_as_play_local_file(),
+            announcement.content_as_play_stream(),
+            announcement.content_as_play_dlna(),
+        )
+    }
+
+    pub fn from_preload(preload: Preload<'a>) -> Result<Self, String> {
+        Self::new(
+            preload.content_as_play_youtube(),
+            preload.content_as_play_wav(),
+            preload.content_as_play_local_file(),
+            preload.content_as_play_stream(),
+            preload.content_as_play_dlna(),
+        )
+    }
+
+    /// Takes the union's `content_as_*` accessors, of which only the set member is `Some`.
+    fn new(
+        play_youtube: Option<PlayYoutube<'a>>,
+        play_wav: Option<PlayWav<'a>>,
+        play_local_file: Option<PlayLocalFile<'a>>,
+        play_stream: Option<PlayStream<'a>>,
+        play_dlna: Option<PlayDlna<'a>>,
+    ) -> Result<Self, String> {
+        if let Some(play_youtube) = play_youtube {
+            let url = play_youtube.url().ok_or("URL is None")?;
+            Ok(Self::Url(
+                PlayContent::PlayYoutube,
+                validate_url(url)?.to_string(),
+            ))
+        } else if let Some(play_wav) = play_wav {
+            let data = play_wav.data().ok_or("Data is None")?;
+            Ok(Self::Wav(data.bytes()))
+        } else if let Some(play_local_file) = play_local_file {
+            let path = play_local_file.path().ok_or("Path is None")?;
+            Ok(Self::Url(
+                PlayContent::PlayLocalFile,
+                resolve_local_file(path)?,
+            ))
+        } else if let Some(play_stream) = play_stream {
+            let url = play_stream.url().ok_or("URL is None")?;
+            Ok(Self::Url(
+                PlayContent::PlayStream,
+                validate_url(url)?.to_string(),
+            ))
+        } else if let Some(play_dlna) = play_dlna {
+            let url = play_dlna.url().ok_or("URL is None")?;
+            Ok(Self::Url(
+                PlayContent::PlayDlna,
+                validate_url(url)?.to_string(),
+            ))
+        } else {
+            Err("Unsupported play content".to_string())
+        }
+    }
+}
+
+/// Checks that `url` is a plain HTTP(S) URL, so mpv can't be pointed at local files, devices or
+/// its special protocols (`av://`, `memory://`, ...) through a play command. The alarms service
+/// repeats this check in `ScheduledAlarm::from_fbs`, keep the two in sync.
+pub fn validate_url(url: &str) -> Result<&str, String> {
+    let has_scheme = url.starts_with("http://") || url.starts_with("https://");
+    if !has_scheme || url.chars().any(|c| c.is_whitespace() || c.is_control()) {
+        return Err(format!("Invalid URL {}", url));
+    }
+    Ok(url)
+}
+
+/// Resolves `path` inside the music directory (`MUSIC_DIR`), refusing anything that escapes it.
+pub fn resolve_local_file(path: &str) -> Result<String, String> {
+    let music_dir =
+        PathBuf::from(std::env::var("MUSIC_DIR").unwrap_or_else(|_| DEFAULT_MUSIC_DIR.to_string()));
+    let music_dir = music_dir
+        .canonicalize()
+        .map_err(|e| format!("Music directory {} unavailable: {}", music_dir.display(), e))?;
+
+    // Symlinks and .. are resolved before checking where the file really is
+    let full_path = music_dir
+        .join(Path::new(path.trim_start_matches('/')))
+        .canonicalize()
+        .map_err(|e| format!("Local file {} not found: {}", path, e))?;
+    if !full_path.starts_with(&music_dir) || !full_path.is_file() {
+        return Err(format!("Local file {} is not in the music directory", path));
+    }
+
+    full_path
+        .to_str()
+        .map(str::to_string)
+        .ok_or(format!("Local file {} is not valid UTF-8", path))
+}
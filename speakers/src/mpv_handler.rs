@@ -1,90 +1,593 @@
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    thread,
+    time::Duration,
+};
 
 use crate::{
-    fbs::construct_speaker_list_event_message, mpv_process::MpvProcess, PlayContent,
-    SpeakerCommand, SpeakerCommandContent, SpeakerQuery, SpeakerQueryContent,
+    alsa_devices::AlsaDevices,
+    backend::{DeviceEnumerator, GroupRole, Player, PlayerBackend},
+    device_filter::DeviceFilter,
+    fbs::{
+        construct_audio_filter_changed_event_message,
+        construct_hardware_volume_changed_event_message, construct_sleep_timer_fired_event_message,
+        construct_sleep_timer_set_event_message, construct_speaker_group_changed_event_message,
+        construct_speaker_list_event_message, EventPublisher, NcSendable,
+    },
+    media_source::PlaySource,
+    mpv_process::{MpvBackend, MpvChannel, AUDIO_FILTER_PRESETS},
+    sleep_timer::SleepTimer,
+    speaker_group::SpeakerGroup,
+    speaker_registry::{RegisteredSpeaker, SpeakerRegistry},
+    speaker_store::{SavedSpeaker, SpeakerStore},
+    PlayContent, SpeakerCommand, SpeakerCommandContent, SpeakerQuery, SpeakerQueryContent,
 };
 
-use alsa::{device_name::HintIter, Direction};
+const DEVICE_POLL_INTERVAL: Duration = Duration::from_secs(2);
+const STATE_SAVE_INTERVAL: Duration = Duration::from_secs(5);
+const SLEEP_TIMER_TICK: Duration = Duration::from_millis(250);
+
+/// Music volume of speakers without a default in the registry
+const DEFAULT_VOLUME: f32 = 100.0;
+
+// Music drops to 30% of its volume during announcements unless configured otherwise
+const DEFAULT_DUCKING: f32 = 0.7;
+
+/// Longest overlap between tracks, in seconds
+const MAX_CROSSFADE: f32 = 30.0;
 
 struct SpeakerState {
     music_volume: f32,
-    mpv_process: Option<MpvProcess>,
+    crossfade: f32,
+    ducking: f32,
+    muted: bool,
+    speed: f32,
+    /// Preset from `AUDIO_FILTER_PRESETS`, or "none"
+    audio_filter: String,
+    sleep_timer: Option<SleepTimer>,
+    mpv_process: Option<Box<dyn Player>>,
+    announcement_process: Option<Box<dyn Player>>,
+    /// Plays out the end of each track while the next one fades in, only while crossfading
+    crossfade_process: Option<Box<dyn Player>>,
 }
 
-pub struct MpvHandler {
-    state: HashMap<String, SpeakerState>,
-    nc: Arc<nats::Connection>,
+/// Drops the handle of a player that exited on its own.
+fn reap(mpv_process: &mut Option<Box<dyn Player>>) -> Result<(), String> {
+    if let Some(process) = mpv_process {
+        if !process.is_running() {
+            process.kill()?;
+            *mpv_process = None;
+        }
+    }
+    Ok(())
 }
 
-pub fn list_pcm_devices(direction: Direction) -> Vec<String> {
-    let hints = HintIter::new_str(None, "pcm").unwrap();
-    let mut devices = Vec::new();
-    for hint in hints {
-        let name = hint.name.unwrap_or_default();
-        let desc = hint.desc.unwrap_or_default();
-        if (name.starts_with("plughw:"))
-            && (!name.contains("NVidia"))
-            && (!desc.contains(" Alt "))
-            && (hint.direction.is_none()
-                || hint
-                    .direction
-                    .map(|dir| dir == direction)
-                    .unwrap_or_default())
+/// A speaker's registry entry and playback state, as reported in `SpeakerListEvent`.
+pub struct SpeakerStatus {
+    pub device_id: String,
+    pub info: RegisteredSpeaker,
+    pub music_volume: f32,
+    /// What is playing, `None` when idle
+    pub url: Option<String>,
+    pub paused: bool,
+}
+
+impl SpeakerState {
+    fn new(music_volume: f32) -> Self {
+        Self {
+            music_volume,
+            crossfade: 0.0,
+            ducking: DEFAULT_DUCKING,
+            muted: false,
+            speed: 1.0,
+            audio_filter: "none".to_string(),
+            sleep_timer: None,
+            mpv_process: None,
+            announcement_process: None,
+            crossfade_process: None,
+        }
+    }
+
+    fn restore(saved: &SavedSpeaker) -> Self {
+        Self {
+            crossfade: saved.crossfade,
+            ducking: saved.ducking,
+            muted: saved.muted,
+            speed: saved.speed,
+            audio_filter: saved.audio_filter.clone(),
+            ..Self::new(saved.music_volume)
+        }
+    }
+
+    fn to_saved(&self) -> SavedSpeaker {
+        let playing = self
+            .mpv_process
+            .as_ref()
+            .filter(|mpv_process| mpv_process.is_running())
+            .and_then(|mpv_process| {
+                let (url, kind, position) = mpv_process.resumable_position()?;
+                Some((url, kind, position, mpv_process.is_paused()))
+            });
+
+        SavedSpeaker {
+            music_volume: self.music_volume,
+            crossfade: self.crossfade,
+            ducking: self.ducking,
+            muted: self.muted,
+            speed: self.speed,
+            audio_filter: self.audio_filter.clone(),
+            url: playing.as_ref().map(|(url, _, _, _)| url.clone()),
+            kind: playing
+                .as_ref()
+                .and_then(|(_, kind, _, _)| kind.variant_name())
+                .map(str::to_string),
+            position: playing.as_ref().and_then(|(_, _, position, _)| *position),
+            paused: playing.map(|(_, _, _, paused)| paused).unwrap_or(false),
+        }
+    }
+
+    fn reap_mpv_process(&mut self) -> Result<(), String> {
+        reap(&mut self.mpv_process)?;
+        reap(&mut self.announcement_process)?;
+        reap(&mut self.crossfade_process)
+    }
+
+    fn kill(&mut self) -> Result<(), String> {
+        for mpv_process in [
+            &mut self.mpv_process,
+            &mut self.announcement_process,
+            &mut self.crossfade_process,
+        ]
+        .into_iter()
+        .flatten()
         {
-            println!("Device: {:<35} Description: {:?}", name, desc);
-            devices.push(name);
+            mpv_process.kill()?;
         }
+        Ok(())
+    }
+
+    /// Returns the speaker's long-lived music player, respawning it if it is not running.
+    fn mpv_process(
+        &mut self,
+        device_id: &str,
+        backend: &dyn PlayerBackend,
+    ) -> Result<&mut dyn Player, String> {
+        self.reap_mpv_process()?;
+
+        // A new music player, or a crossfade player that exited, needs (re)connecting
+        let mut connect_crossfade = self.crossfade > 0.0 && self.crossfade_process.is_none();
+        if self.mpv_process.is_none() {
+            let mut mpv_process = backend.spawn(device_id, MpvChannel::Music)?;
+            mpv_process.set_property("volume", self.music_volume.into())?;
+            if self.muted {
+                mpv_process.set_property("mute", true.into())?;
+            }
+            if self.speed != 1.0 {
+                mpv_process.set_property("speed", self.speed.into())?;
+            }
+            if self.audio_filter != "none" {
+                mpv_process.set_audio_filter(&self.audio_filter)?;
+            }
+            // A respawn in the middle of a fade picks it up where it was
+            if let Some(sleep_timer) = &self.sleep_timer {
+                mpv_process.set_sleep_gain(sleep_timer.gain(), true)?;
+            }
+            self.mpv_process = Some(mpv_process);
+            connect_crossfade = true;
+        }
+        if connect_crossfade {
+            self.connect_crossfade(device_id, backend)?;
+        }
+
+        Ok(self.mpv_process.as_deref_mut().unwrap())
+    }
+
+    /// Hands the music player a crossfade player to play out the end of its tracks on, or takes
+    /// it away and kills it when crossfading is off.
+    fn connect_crossfade(
+        &mut self,
+        device_id: &str,
+        backend: &dyn PlayerBackend,
+    ) -> Result<(), String> {
+        if self.crossfade <= 0.0 {
+            if let Some(mut crossfade_process) = self.crossfade_process.take() {
+                crossfade_process.kill()?;
+            }
+        }
+        let Some(mpv_process) = &mut self.mpv_process else {
+            return Ok(());
+        };
+        if self.crossfade <= 0.0 {
+            return mpv_process.set_crossfade(None, 0.0);
+        }
+
+        if self.crossfade_process.is_none() {
+            self.crossfade_process = Some(backend.spawn(device_id, MpvChannel::Crossfade)?);
+        }
+        let tail = self
+            .crossfade_process
+            .as_ref()
+            .map(|crossfade_process| crossfade_process.remote());
+        mpv_process.set_crossfade(tail, self.crossfade)
+    }
+
+    /// Drops the sleep timer and undoes its fade.
+    fn cancel_sleep_timer(&mut self) -> Result<(), String> {
+        if self.sleep_timer.take().is_none() {
+            return Ok(());
+        }
+        if let Some(mpv_process) = &mut self.mpv_process {
+            mpv_process.set_sleep_gain(1.0, true)?;
+        }
+        Ok(())
+    }
+
+    /// Stops playback and drops the sleep timer, leaving the volume as it was before the fade.
+    fn fire_sleep_timer(&mut self) -> Result<(), String> {
+        // Dropped first, a stop that fails must not leave the timer firing on every tick
+        self.sleep_timer = None;
+        let Some(mpv_process) = &mut self.mpv_process else {
+            return Ok(());
+        };
+        let stopped = mpv_process.stop();
+        mpv_process.set_sleep_gain(1.0, true)?;
+        stopped
+    }
+
+    fn sleep_timer_message(&self, device_id: &str) -> Vec<u8> {
+        let (remaining, fade) = self
+            .sleep_timer
+            .as_ref()
+            .map(|sleep_timer| {
+                (
+                    sleep_timer.remaining().as_secs_f32(),
+                    sleep_timer.fade().as_secs_f32(),
+                )
+            })
+            .unwrap_or((0.0, 0.0));
+        construct_sleep_timer_set_event_message(remaining, fade, device_id)
+    }
+
+    /// Returns the player announcements play on, set up to duck the current music.
+    fn announcement_process(
+        &mut self,
+        device_id: &str,
+        backend: &dyn PlayerBackend,
+    ) -> Result<&mut dyn Player, String> {
+        self.reap_mpv_process()?;
+
+        if self.announcement_process.is_none() {
+            let mpv_process = backend.spawn(device_id, MpvChannel::Announcement)?;
+            self.announcement_process = Some(mpv_process);
+        }
+
+        let music = self
+            .mpv_process
+            .as_ref()
+            .map(|mpv_process| mpv_process.remote());
+        let announcement_process = self.announcement_process.as_deref_mut().unwrap();
+        announcement_process.set_ducking(music, self.ducking);
+        Ok(announcement_process)
     }
-    devices
+}
+
+pub struct MpvHandler {
+    /// Playback devices in ALSA's order
+    devices: Vec<String>,
+    state: HashMap<String, SpeakerState>,
+    groups: HashMap<String, SpeakerGroup>,
+    registry: SpeakerRegistry,
+    store: SpeakerStore,
+    device_filter: DeviceFilter,
+    backend: Box<dyn PlayerBackend>,
+    device_enumerator: Box<dyn DeviceEnumerator>,
+    events: Arc<dyn EventPublisher>,
+}
+
+/// Polls ALSA for added and removed playback devices and pushes a `SpeakerListEvent` whenever
+/// the set changes.
+pub fn make_device_watcher_thread(mpv_handler: Arc<Mutex<MpvHandler>>) {
+    thread::spawn(move || loop {
+        thread::sleep(DEVICE_POLL_INTERVAL);
+
+        let mut mpv_handler = mpv_handler.lock().unwrap();
+        if let Err(e) = mpv_handler.refresh_devices() {
+            eprintln!("Error refreshing devices: {}", e);
+        }
+    });
+}
+
+/// Periodically writes volumes and playback positions to disk so they survive a restart.
+pub fn make_state_saver_thread(mpv_handler: Arc<Mutex<MpvHandler>>) {
+    thread::spawn(move || loop {
+        thread::sleep(STATE_SAVE_INTERVAL);
+
+        let mut mpv_handler = mpv_handler.lock().unwrap();
+        if let Err(e) = mpv_handler.save_state() {
+            eprintln!("Error saving speaker state: {}", e);
+        }
+    });
+}
+
+/// Fades out and fires the speakers' sleep timers.
+pub fn make_sleep_timer_thread(mpv_handler: Arc<Mutex<MpvHandler>>) {
+    thread::spawn(move || loop {
+        thread::sleep(SLEEP_TIMER_TICK);
+
+        let mut mpv_handler = mpv_handler.lock().unwrap();
+        mpv_handler.tick_sleep_timers();
+    });
 }
 
 impl MpvHandler {
     pub fn new(nc: Arc<nats::Connection>) -> Result<Self, String> {
-        Ok(Self {
-            state: HashMap::new(),
+        Ok(Self::with_backend(
+            Box::new(MpvBackend::new(nc.clone())),
+            Box::new(AlsaDevices),
             nc,
-        })
+            SpeakerRegistry::load()?,
+            SpeakerStore::load()?,
+            DeviceFilter::load()?,
+        ))
+    }
+
+    /// Builds a handler on any backend, e.g. the fake one in tests.
+    pub fn with_backend(
+        backend: Box<dyn PlayerBackend>,
+        device_enumerator: Box<dyn DeviceEnumerator>,
+        events: Arc<dyn EventPublisher>,
+        registry: SpeakerRegistry,
+        store: SpeakerStore,
+        device_filter: DeviceFilter,
+    ) -> Self {
+        Self {
+            devices: Vec::new(),
+            state: HashMap::new(),
+            groups: HashMap::new(),
+            registry,
+            store,
+            device_filter,
+            backend,
+            device_enumerator,
+            events,
+        }
     }
 
     pub fn handle_speaker_list_query(&mut self) -> Result<Option<Vec<u8>>, String> {
-        let playback_devices = list_pcm_devices(Direction::Playback);
+        self.refresh_devices()?;
+        Ok(Some(construct_speaker_list_event_message(
+            &self.speaker_statuses(),
+        )))
+    }
 
-        let mut devices_to_remove = Vec::new();
-        for (device_id, _) in self.state.iter() {
-            if !playback_devices.contains(&device_id) {
-                devices_to_remove.push(device_id.clone());
+    fn speaker_statuses(&self) -> Vec<SpeakerStatus> {
+        self.devices
+            .iter()
+            .filter_map(|device_id| {
+                let speaker_state = self.state.get(device_id)?;
+                let mpv_process = speaker_state
+                    .mpv_process
+                    .as_ref()
+                    .filter(|mpv_process| mpv_process.is_running());
+                Some(SpeakerStatus {
+                    device_id: device_id.clone(),
+                    info: self.registry.get(device_id),
+                    music_volume: speaker_state.music_volume,
+                    url: mpv_process.and_then(|mpv_process| mpv_process.current_path()),
+                    paused: mpv_process
+                        .map(|mpv_process| mpv_process.is_paused())
+                        .unwrap_or(false),
+                })
+            })
+            .collect()
+    }
+
+    /// Syncs the speakers with ALSA's playback devices, killing mpv for vanished ones.
+    /// Publishes a `SpeakerListEvent` if anything changed.
+    pub fn refresh_devices(&mut self) -> Result<(), String> {
+        let playback_devices = self
+            .device_enumerator
+            .playback_devices(&self.device_filter)?;
+        if playback_devices == self.devices {
+            return Ok(());
+        }
+
+        let devices_to_remove: Vec<String> = self
+            .state
+            .keys()
+            .filter(|device_id| !playback_devices.contains(device_id))
+            .cloned()
+            .collect();
+
+        for device_id in &devices_to_remove {
+            println!("Device removed: {}", device_id);
+            if let Some(mut speaker_state) = self.state.remove(device_id) {
+                speaker_state.kill()?;
             }
         }
 
         for device_id in &playback_devices {
             if !self.state.contains_key(device_id) {
-                self.state.insert(
-                    device_id.clone(),
-                    SpeakerState {
-                        music_volume: 100.0,
-                        mpv_process: None,
-                    },
-                );
-                // No need to start mpv_handler_thread here as it's already started in new()
+                println!("Device added: {}", device_id);
+                let speaker_state = match self.store.get(device_id) {
+                    Some(saved) => SpeakerState::restore(saved),
+                    None => SpeakerState::new(
+                        self.registry
+                            .get(device_id)
+                            .default_volume
+                            .unwrap_or(DEFAULT_VOLUME),
+                    ),
+                };
+                self.state.insert(device_id.clone(), speaker_state);
+                self.resume_playback(device_id);
+            }
+        }
+
+        self.devices = playback_devices;
+        self.drop_removed_group_members(&devices_to_remove);
+        let message = Ok(Some(construct_speaker_list_event_message(
+            &self.speaker_statuses(),
+        )));
+        message.send(&*self.events, "speaker.event");
+
+        Ok(())
+    }
+
+    /// Restarts whatever the speaker was playing before the service went down.
+    fn resume_playback(&mut self, device_id: &str) {
+        let Some(saved) = self.store.take_resume(device_id) else {
+            return;
+        };
+        let (Some(url), Some(speaker_state)) = (saved.url, self.state.get_mut(device_id)) else {
+            return;
+        };
+
+        let Some(kind) = PlayContent::ENUM_VALUES
+            .iter()
+            .copied()
+            .find(|kind| kind.variant_name() == saved.kind.as_deref())
+        else {
+            eprintln!(
+                "Not resuming {} on {}, unknown kind {:?}",
+                url, device_id, saved.kind
+            );
+            return;
+        };
+
+        println!("Resuming {} on {}", url, device_id);
+        let resumed = speaker_state
+            .mpv_process(device_id, &*self.backend)
+            .and_then(|mpv_process| mpv_process.resume(&url, kind, saved.position, saved.paused));
+        if let Err(e) = resumed {
+            eprintln!("Error resuming playback on {}: {}", device_id, e);
+        }
+    }
+
+    pub fn save_state(&mut self) -> Result<(), String> {
+        let speakers = self
+            .state
+            .iter()
+            .map(|(device_id, speaker_state)| (device_id.clone(), speaker_state.to_saved()))
+            .collect();
+        self.store.save(speakers)
+    }
+
+    /// Lowers the music of speakers whose sleep timer is fading, and stops the ones that fired.
+    pub fn tick_sleep_timers(&mut self) {
+        for (device_id, speaker_state) in &mut self.state {
+            if speaker_state.sleep_timer.is_none() {
+                continue;
             }
+            // A player that exited would fail every fade step until something else reaped it
+            if let Err(e) = speaker_state.reap_mpv_process() {
+                Err(e).send(&*self.events, "speaker.event");
+                continue;
+            }
+            let Some(sleep_timer) = &mut speaker_state.sleep_timer else {
+                continue;
+            };
+
+            let message = if sleep_timer.has_fired() {
+                println!("Sleep timer fired on {}", device_id);
+                speaker_state
+                    .fire_sleep_timer()
+                    .map(|_| Some(construct_sleep_timer_fired_event_message(device_id)))
+            } else {
+                match (sleep_timer.next_gain(), &mut speaker_state.mpv_process) {
+                    (Some(gain), Some(mpv_process)) => {
+                        mpv_process.set_sleep_gain(gain, false).map(|_| None)
+                    }
+                    _ => Ok(None),
+                }
+            };
+            message.send(&*self.events, "speaker.event");
         }
+    }
+
+    /// Takes vanished devices out of their groups, dissolving groups left empty.
+    fn drop_removed_group_members(&mut self, removed: &[String]) {
+        let group_ids: Vec<String> = self
+            .groups
+            .iter()
+            .filter(|(_, group)| group.device_ids.iter().any(|id| removed.contains(id)))
+            .map(|(group_id, _)| group_id.clone())
+            .collect();
 
-        Ok(Some(construct_speaker_list_event_message(playback_devices)))
+        for group_id in &group_ids {
+            let group = self.groups.get_mut(group_id).unwrap();
+            group
+                .device_ids
+                .retain(|device_id| !removed.contains(device_id));
+            let device_ids = group.device_ids.clone();
+
+            let message = if device_ids.is_empty() {
+                self.dissolve_speaker_group(group_id)
+            } else {
+                self.refresh_group(group_id).map(|_| {
+                    Some(construct_speaker_group_changed_event_message(
+                        &device_ids,
+                        group_id,
+                    ))
+                })
+            };
+            message.send(&*self.events, "speaker.event");
+        }
     }
 
     pub fn handle_speaker_command(
         &mut self,
         command: SpeakerCommand,
     ) -> Result<Option<Vec<u8>>, String> {
-        self.handle_speaker_list_query()?;
+        if command.command_type() == SpeakerCommandContent::ReloadDeviceFilter {
+            self.device_filter = DeviceFilter::load()?;
+            println!("Device filter reloaded");
+            self.refresh_devices()?;
+            return Ok(None);
+        }
+
+        self.refresh_devices()?;
 
         let device_id: &str = command.device_id().ok_or("No device_id")?;
 
+        match command.command_type() {
+            SpeakerCommandContent::CreateSpeakerGroup => {
+                return self.create_speaker_group(device_id, command)
+            }
+            SpeakerCommandContent::DissolveSpeakerGroup => {
+                return self.dissolve_speaker_group(device_id)
+            }
+            SpeakerCommandContent::UpdateSpeakerInfo => {
+                return self.update_speaker_info(device_id, command)
+            }
+            _ => {}
+        }
+
+        if self.groups.contains_key(device_id) {
+            return self.handle_group_command(device_id, command);
+        }
+
         let speaker_state = self
             .state
             .get_mut(device_id)
             .ok_or("Device does not exist")?;
+        Self::apply_command(
+            speaker_state,
+            device_id,
+            command,
+            &*self.backend,
+            &*self.device_enumerator,
+        )
+    }
+
+    fn apply_command(
+        speaker_state: &mut SpeakerState,
+        device_id: &str,
+        command: SpeakerCommand,
+        backend: &dyn PlayerBackend,
+        device_enumerator: &dyn DeviceEnumerator,
+    ) -> Result<Option<Vec<u8>>, String> {
+        speaker_state.reap_mpv_process()?;
 
         match command.command_type() {
             SpeakerCommandContent::SetMusicVolume => {
@@ -94,13 +597,9 @@ impl MpvHandler {
                     .volume();
                 speaker_state.music_volume = volume;
 
+                // Reported by the player's volume observer
                 if let Some(mpv_process) = &mut speaker_state.mpv_process {
-                    mpv_process
-                        .set_property("volume", volume)
-                        .map_err(|e| e.to_string())?;
-                    mpv_process
-                        .query_property("volume")
-                        .map_err(|e| e.to_string())?;
+                    mpv_process.set_property("volume", volume.into())?;
                 }
 
                 Ok(None)
@@ -108,47 +607,42 @@ impl MpvHandler {
             SpeakerCommandContent::TogglePause => {
                 println!("Toggling pause for device {}", device_id);
 
+                let mpv_process = speaker_state.mpv_process.as_mut().ok_or("No mpv process")?;
+                mpv_process.cycle_property("pause")?;
+
                 Ok(None)
             }
             SpeakerCommandContent::Play => {
-                if speaker_state.mpv_process.is_some() {
-                    // Terminate the current mpv process
-                    let mpv_process = speaker_state.mpv_process.as_mut().ok_or("No mpv process")?;
-                    mpv_process.kill().map_err(|e| e.to_string())?;
-                    speaker_state.mpv_process = None;
-                }
-
                 let play_command = command.command_as_play().ok_or("No play command")?;
+                let source = PlaySource::from_play(play_command)?;
+                let mpv_process = speaker_state.mpv_process(device_id, backend)?;
 
-                match play_command.content_type() {
-                    PlayContent::PlayYoutube => {
-                        let url = play_command
-                            .content_as_play_youtube()
-                            .ok_or("No YouTube URL")?
-                            .url()
-                            .ok_or("URL is None")?;
-
-                        speaker_state.mpv_process = Some(
-                            MpvProcess::new(url, device_id, self.nc.clone())
-                                .map_err(|e| e.to_string())?,
-                        );
-
-                        speaker_state
-                            .mpv_process
-                            .as_mut()
-                            .unwrap()
-                            .set_property("volume", speaker_state.music_volume)
-                            .map_err(|e| e.to_string())?;
-
-                        Ok(None)
-                    }
-                    _ => Err("Unsupported play content".to_string()),
+                Self::play_content(mpv_process, source)
+            }
+            SpeakerCommandContent::PlayAnnouncement => {
+                let announcement = command
+                    .command_as_play_announcement()
+                    .ok_or("No announcement command")?;
+                let source = PlaySource::from_announcement(announcement)?;
+                let mpv_process = speaker_state.announcement_process(device_id, backend)?;
+
+                Self::play_content(mpv_process, source)
+            }
+            SpeakerCommandContent::SetDucking => {
+                let amount = command
+                    .command_as_set_ducking()
+                    .ok_or("No ducking command")?
+                    .amount();
+                // NaN would get past the clamp and reach mpv as the music's gain
+                if !amount.is_finite() {
+                    return Err(format!("Ducking {} is out of range", amount));
                 }
+                speaker_state.ducking = amount.clamp(0.0, 1.0);
+                Ok(None)
             }
             SpeakerCommandContent::Stop => {
                 if let Some(mpv_process) = &mut speaker_state.mpv_process {
-                    mpv_process.kill().map_err(|e| e.to_string())?;
-                    speaker_state.mpv_process = None;
+                    mpv_process.stop()?;
                 }
                 Ok(None)
             }
@@ -157,49 +651,388 @@ impl MpvHandler {
                 let seek_time = seek_command.seek();
 
                 if let Some(mpv_process) = &mut speaker_state.mpv_process {
-                    mpv_process
-                        .set_property("time-pos", seek_time)
-                        .map_err(|e| e.to_string())?;
-                    mpv_process
-                        .query_property("time-pos")
-                        .map_err(|e| e.to_string())?;
+                    mpv_process.set_property("time-pos", seek_time.into())?;
+                }
+
+                Ok(None)
+            }
+            SpeakerCommandContent::Preload => {
+                let preload = command.command_as_preload().ok_or("No preload command")?;
+                let PlaySource::Url(kind, url) = PlaySource::from_preload(preload)? else {
+                    // mpv can only queue something it loads by URL
+                    return Err("WAV data can't be preloaded".to_string());
+                };
+
+                speaker_state
+                    .mpv_process(device_id, backend)?
+                    .preload(&url, kind)?;
+
+                Ok(None)
+            }
+            SpeakerCommandContent::SetCrossfade => {
+                let crossfade = command
+                    .command_as_set_crossfade()
+                    .ok_or("No crossfade command")?
+                    .duration();
+                if !(0.0..=MAX_CROSSFADE).contains(&crossfade) {
+                    return Err(format!("Crossfade {} is out of range", crossfade));
                 }
+                speaker_state.crossfade = crossfade;
+
+                // Connected when the music player is next spawned otherwise
+                speaker_state.reap_mpv_process()?;
+                speaker_state.connect_crossfade(device_id, backend)?;
 
                 Ok(None)
             }
+            SpeakerCommandContent::SetHardwareVolume => {
+                let volume = command
+                    .command_as_set_hardware_volume()
+                    .ok_or("No hardware volume command")?
+                    .volume();
+                // NaN would get past the mixer's clamp and end up as its minimum
+                if !(0.0..=100.0).contains(&volume) {
+                    return Err(format!("Hardware volume {} is out of range", volume));
+                }
+                device_enumerator.set_hardware_volume(device_id, volume)?;
+
+                Ok(Some(construct_hardware_volume_changed_event_message(
+                    device_enumerator.hardware_volume(device_id)?,
+                    device_id,
+                )))
+            }
+            SpeakerCommandContent::SetMute => {
+                let muted = command
+                    .command_as_set_mute()
+                    .ok_or("No mute command")?
+                    .muted();
+                speaker_state.muted = muted;
+
+                if let Some(mpv_process) = &mut speaker_state.mpv_process {
+                    mpv_process.set_property("mute", muted.into())?;
+                }
+
+                Ok(None)
+            }
+            SpeakerCommandContent::SetSpeed => {
+                let speed = command
+                    .command_as_set_speed()
+                    .ok_or("No speed command")?
+                    .speed();
+                // mpv's limits for the speed property
+                if !(0.01..=100.0).contains(&speed) {
+                    return Err(format!("Speed {} is out of range", speed));
+                }
+                speaker_state.speed = speed;
+
+                if let Some(mpv_process) = &mut speaker_state.mpv_process {
+                    mpv_process.set_property("speed", speed.into())?;
+                }
+
+                Ok(None)
+            }
+            SpeakerCommandContent::SeekRelative => {
+                let offset = command
+                    .command_as_seek_relative()
+                    .ok_or("No relative seek command")?
+                    .offset();
+
+                if let Some(mpv_process) = &mut speaker_state.mpv_process {
+                    mpv_process.seek_relative(offset)?;
+                }
+
+                Ok(None)
+            }
+            SpeakerCommandContent::SetAudioFilter => {
+                let preset = command
+                    .command_as_set_audio_filter()
+                    .ok_or("No audio filter command")?
+                    .preset()
+                    .unwrap_or("none");
+                if preset != "none" && !AUDIO_FILTER_PRESETS.iter().any(|(name, _)| *name == preset)
+                {
+                    return Err(format!("Unknown audio filter preset {}", preset));
+                }
+                speaker_state.audio_filter = preset.to_string();
+
+                if let Some(mpv_process) = &mut speaker_state.mpv_process {
+                    mpv_process.set_audio_filter(preset)?;
+                }
+
+                Ok(Some(construct_audio_filter_changed_event_message(
+                    preset, device_id,
+                )))
+            }
+            SpeakerCommandContent::SetSleepTimer => {
+                let set_sleep_timer = command
+                    .command_as_set_sleep_timer()
+                    .ok_or("No sleep timer command")?;
+                let sleep_timer =
+                    SleepTimer::new(set_sleep_timer.duration(), set_sleep_timer.fade())?;
+
+                // Start from full volume in case a previous timer was fading
+                speaker_state.cancel_sleep_timer()?;
+                println!(
+                    "Sleep timer on {} in {:?}",
+                    device_id,
+                    sleep_timer.remaining()
+                );
+                speaker_state.sleep_timer = Some(sleep_timer);
+
+                Ok(Some(speaker_state.sleep_timer_message(device_id)))
+            }
+            SpeakerCommandContent::CancelSleepTimer => {
+                speaker_state.cancel_sleep_timer()?;
+                Ok(Some(speaker_state.sleep_timer_message(device_id)))
+            }
             _ => Err("Unknown command".to_string()),
         }
     }
 
+    fn play_content(
+        mpv_process: &mut dyn Player,
+        source: PlaySource,
+    ) -> Result<Option<Vec<u8>>, String> {
+        match source {
+            PlaySource::Url(kind, url) => mpv_process.play(&url, kind),
+            PlaySource::Wav(data) => mpv_process.play_wav(data),
+        }?;
+
+        Ok(None)
+    }
+
+    fn update_speaker_info(
+        &mut self,
+        device_id: &str,
+        command: SpeakerCommand,
+    ) -> Result<Option<Vec<u8>>, String> {
+        let update = command
+            .command_as_update_speaker_info()
+            .ok_or("No update speaker info command")?;
+
+        self.registry.update(
+            device_id,
+            RegisteredSpeaker {
+                name: update.name().map(str::to_string),
+                room: update.room().map(str::to_string),
+                icon: update.icon().map(str::to_string),
+                // Also false for NaN
+                default_volume: Some(update.default_volume()).filter(|volume| *volume >= 0.0),
+                hidden: update.hidden(),
+            },
+        )?;
+
+        Ok(Some(construct_speaker_list_event_message(
+            &self.speaker_statuses(),
+        )))
+    }
+
+    fn create_speaker_group(
+        &mut self,
+        group_id: &str,
+        command: SpeakerCommand,
+    ) -> Result<Option<Vec<u8>>, String> {
+        if self.state.contains_key(group_id) {
+            return Err(format!("{} is already a device id", group_id));
+        }
+
+        let mut device_ids: Vec<String> = Vec::new();
+        for device_id in command
+            .command_as_create_speaker_group()
+            .ok_or("No create group command")?
+            .device_ids()
+            .ok_or("No device_ids")?
+        {
+            // A member listed twice would lose the role it got as leader
+            if !device_ids.iter().any(|id| id == device_id) {
+                device_ids.push(device_id.to_string());
+            }
+        }
+        if device_ids.is_empty() {
+            return Err("A group needs at least one device".to_string());
+        }
+        for device_id in &device_ids {
+            if !self.state.contains_key(device_id) {
+                return Err(format!("Device {} does not exist", device_id));
+            }
+            // Each group sets its members' roles, so a member of two would lose one of them
+            if let Some((other_id, _)) = self.groups.iter().find(|(other_id, group)| {
+                other_id.as_str() != group_id && group.device_ids.contains(device_id)
+            }) {
+                return Err(format!(
+                    "Device {} is already in group {}",
+                    device_id, other_id
+                ));
+            }
+        }
+
+        println!("Creating group {} with {:?}", group_id, device_ids);
+        self.dissolve_speaker_group(group_id)?;
+        self.groups
+            .insert(group_id.to_string(), SpeakerGroup::new(device_ids.clone()));
+        self.refresh_group(group_id)?;
+
+        Ok(Some(construct_speaker_group_changed_event_message(
+            &device_ids,
+            group_id,
+        )))
+    }
+
+    fn dissolve_speaker_group(&mut self, group_id: &str) -> Result<Option<Vec<u8>>, String> {
+        let Some(group) = self.groups.remove(group_id) else {
+            return Ok(None);
+        };
+
+        for device_id in &group.device_ids {
+            if let Some(mpv_process) = self
+                .state
+                .get_mut(device_id)
+                .and_then(|speaker_state| speaker_state.mpv_process.as_mut())
+            {
+                mpv_process.set_group_role(None);
+            }
+        }
+
+        Ok(Some(construct_speaker_group_changed_event_message(
+            &[],
+            group_id,
+        )))
+    }
+
+    /// Points the group at its members' current mpv instances, which may have been respawned,
+    /// and lets the leader's events stand in for the whole group.
+    fn refresh_group(&mut self, group_id: &str) -> Result<(), String> {
+        let group = self.groups.get(group_id).ok_or("Group does not exist")?;
+
+        let mut remotes = Vec::new();
+        for device_id in &group.device_ids {
+            let Some(speaker_state) = self.state.get_mut(device_id) else {
+                continue;
+            };
+            speaker_state.reap_mpv_process()?;
+            if let Some(mpv_process) = &mut speaker_state.mpv_process {
+                if remotes.is_empty() {
+                    mpv_process.set_group_role(Some(GroupRole::Leader(group_id.to_string())));
+                } else {
+                    mpv_process.set_group_role(Some(GroupRole::Member));
+                }
+                remotes.push(mpv_process.remote());
+            }
+        }
+        group.set_remotes(remotes);
+
+        Ok(())
+    }
+
+    fn handle_group_command(
+        &mut self,
+        group_id: &str,
+        command: SpeakerCommand,
+    ) -> Result<Option<Vec<u8>>, String> {
+        let device_ids = self.groups[group_id].device_ids.clone();
+
+        let source = command
+            .command_as_play()
+            .map(PlaySource::from_play)
+            .transpose()?;
+
+        match (command.command_type(), source) {
+            (SpeakerCommandContent::Play, Some(PlaySource::Url(kind, url))) => {
+                let url = url.as_str();
+                let already_playing = device_ids.iter().all(|device_id| {
+                    self.state
+                        .get(device_id)
+                        .and_then(|speaker_state| speaker_state.mpv_process.as_ref())
+                        .map(|mpv_process| mpv_process.is_playing_preloaded(url))
+                        .unwrap_or(false)
+                });
+
+                for device_id in &device_ids {
+                    let speaker_state = self
+                        .state
+                        .get_mut(device_id)
+                        .ok_or("Device does not exist")?;
+                    let mpv_process = speaker_state.mpv_process(device_id, &*self.backend)?;
+                    if already_playing {
+                        mpv_process.play(url, kind)?;
+                    } else {
+                        mpv_process.loadfile_paused(url, kind)?;
+                    }
+                }
+
+                self.refresh_group(group_id)?;
+                if !already_playing {
+                    self.groups[group_id].start_together();
+                }
+            }
+            (SpeakerCommandContent::TogglePause, _) => {
+                self.refresh_group(group_id)?;
+                self.groups[group_id].toggle_pause()?;
+            }
+            _ => {
+                for device_id in &device_ids {
+                    let speaker_state = self
+                        .state
+                        .get_mut(device_id)
+                        .ok_or("Device does not exist")?;
+                    let message = Self::apply_command(
+                        speaker_state,
+                        device_id,
+                        command,
+                        &*self.backend,
+                        &*self.device_enumerator,
+                    )?;
+                    Ok(message).send(&*self.events, "speaker.event");
+                }
+                self.refresh_group(group_id)?;
+            }
+        }
+
+        Ok(None)
+    }
+
     pub fn handle_speaker_query(&mut self, query: SpeakerQuery) -> Result<Option<Vec<u8>>, String> {
         let device_id: &str = query.device_id().ok_or("No device_id")?;
+        // Groups answer with their leader's state, published under the group id
+        if self.groups.contains_key(device_id) {
+            self.refresh_group(device_id)?;
+        }
+        let device_id = match self.groups.get(device_id) {
+            Some(group) => group.device_ids[0].clone(),
+            None => device_id.to_string(),
+        };
+
         let speaker_state = self
             .state
-            .get_mut(device_id)
+            .get_mut(&device_id)
             .ok_or("Device does not exist")?;
-
-        let mpv_process = speaker_state.mpv_process.as_mut().ok_or("No mpv process")?;
-
-        match query.query_type() {
-            SpeakerQueryContent::QueryMusicVolume => {
-                mpv_process
-                    .query_property("volume")
-                    .map_err(|e| e.to_string())?;
-                Ok(None)
-            }
-            SpeakerQueryContent::QuerySeek => {
-                mpv_process
-                    .query_property("time-pos")
-                    .map_err(|e| e.to_string())?;
-                Ok(None)
+        // Only these need the player, the others shouldn't spawn one
+        let property = match query.query_type() {
+            SpeakerQueryContent::QueryMusicVolume => "volume",
+            SpeakerQueryContent::QueryPause => "pause",
+            SpeakerQueryContent::QuerySeek => "time-pos",
+            SpeakerQueryContent::QueryDuration => "duration",
+            SpeakerQueryContent::QueryHardwareVolume => {
+                // Answered under the id that was asked, which may be a group's
+                let requested_id = query.device_id().unwrap_or(&device_id);
+                return Ok(Some(construct_hardware_volume_changed_event_message(
+                    self.device_enumerator.hardware_volume(&device_id)?,
+                    requested_id,
+                )));
             }
-            SpeakerQueryContent::QueryDuration => {
-                mpv_process
-                    .query_property("duration")
-                    .map_err(|e| e.to_string())?;
-                Ok(None)
+            SpeakerQueryContent::QuerySleepTimer => {
+                let requested_id = query.device_id().unwrap_or(&device_id);
+                return Ok(Some(speaker_state.sleep_timer_message(requested_id)));
             }
-            _ => Err("Unknown query".to_string()),
-        }
+            _ => return Err("Unknown query".to_string()),
+        };
+
+        speaker_state
+            .mpv_process(&device_id, &*self.backend)?
+            .query_property(property)?;
+        Ok(None)
     }
 }
+
+#[cfg(test)]
+mod tests;
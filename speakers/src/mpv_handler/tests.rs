@@ -0,0 +1,1654 @@
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    thread,
+    time::Duration,
+};
+
+use flatbuffers::{FlatBufferBuilder, UnionWIPOffset, WIPOffset};
+
+use super::MpvHandler;
+use crate::{
+    device_filter::DeviceFilter,
+    fake_backend::{FakeBackend, RecordedEvents},
+    root_as_message,
+    speaker_registry::SpeakerRegistry,
+    speaker_store::SpeakerStore,
+    CancelSleepTimer, CancelSleepTimerArgs, CreateSpeakerGroup, CreateSpeakerGroupArgs,
+    DissolveSpeakerGroup, DissolveSpeakerGroupArgs, Message, MessageArgs, MessageContent, Play,
+    PlayAnnouncement, PlayAnnouncementArgs, PlayArgs, PlayContent, PlayStream, PlayStreamArgs,
+    PlayWav, PlayWavArgs, PlayYoutube, PlayYoutubeArgs, Preload, PreloadArgs, QueryDuration,
+    QueryDurationArgs, QueryHardwareVolume, QueryHardwareVolumeArgs, QueryMusicVolume,
+    QueryMusicVolumeArgs, QueryPause, QueryPauseArgs, QueryPlay, QueryPlayArgs, QuerySeek,
+    QuerySeekArgs, QuerySleepTimer, QuerySleepTimerArgs, ReloadDeviceFilter,
+    ReloadDeviceFilterArgs, Seek, SeekArgs, SeekRelative, SeekRelativeArgs, SetAudioFilter,
+    SetAudioFilterArgs, SetCrossfade, SetCrossfadeArgs, SetDucking, SetDuckingArgs,
+    SetHardwareVolume, SetHardwareVolumeArgs, SetMusicVolume, SetMusicVolumeArgs, SetMute,
+    SetMuteArgs, SetSleepTimer, SetSleepTimerArgs, SetSpeed, SetSpeedArgs, SpeakerCommand,
+    SpeakerCommandArgs, SpeakerCommandContent, SpeakerEventContent, SpeakerQuery, SpeakerQueryArgs,
+    SpeakerQueryContent, Stop, StopArgs, TogglePause, TogglePauseArgs, UpdateSpeakerInfo,
+    UpdateSpeakerInfoArgs,
+};
+
+const KITCHEN: &str = "plughw:CARD=Kitchen,DEV=0";
+const BEDROOM: &str = "plughw:CARD=Bedroom,DEV=0";
+const URL: &str = "https://example.com/song.mp3";
+
+type Content = WIPOffset<UnionWIPOffset>;
+
+/// A path in the temp dir no other test uses.
+fn temp_path(name: &str) -> String {
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+    let n = COUNTER.fetch_add(1, Ordering::SeqCst);
+    std::env::temp_dir()
+        .join(format!(
+            "speakers-test-{}-{}-{}",
+            std::process::id(),
+            n,
+            name
+        ))
+        .to_str()
+        .unwrap()
+        .to_string()
+}
+
+struct Harness {
+    handler: MpvHandler,
+    backend: FakeBackend,
+    events: Arc<RecordedEvents>,
+    /// Files the handler may write, removed with the harness
+    paths: Vec<String>,
+}
+
+impl Harness {
+    fn new() -> Self {
+        Self::with_state(temp_path("state.json"), false)
+    }
+
+    /// A handler whose speaker state lives at `state_path`, resuming saved playback if `resume`.
+    fn with_state(state_path: String, resume: bool) -> Self {
+        let registry_path = temp_path("speakers.json");
+        let events = Arc::new(RecordedEvents::default());
+        let backend = FakeBackend::new(&[KITCHEN, BEDROOM], events.clone());
+        let mut handler = MpvHandler::with_backend(
+            Box::new(backend.clone()),
+            Box::new(backend.clone()),
+            events.clone(),
+            SpeakerRegistry::load_from(registry_path.clone()).unwrap(),
+            SpeakerStore::load_from(state_path.clone(), resume).unwrap(),
+            DeviceFilter::load_from(&temp_path("device_filter.json")).unwrap(),
+        );
+        handler.refresh_devices().unwrap();
+        events.take();
+
+        Self {
+            handler,
+            backend,
+            events,
+            paths: vec![state_path, registry_path],
+        }
+    }
+
+    fn command(
+        &mut self,
+        device_id: &str,
+        command_type: SpeakerCommandContent,
+        build: impl FnOnce(&mut FlatBufferBuilder<'static>) -> Content,
+    ) -> Result<Option<Vec<u8>>, String> {
+        let mut fbb = FlatBufferBuilder::new();
+        let command = build(&mut fbb);
+        let device_id = fbb.create_string(device_id);
+        let content = SpeakerCommand::create(
+            &mut fbb,
+            &SpeakerCommandArgs {
+                device_id: Some(device_id),
+                command_type,
+                // A NONE union must not carry a table
+                command: (command_type != SpeakerCommandContent::NONE).then_some(command),
+            },
+        );
+        let data = finish(
+            fbb,
+            MessageContent::SpeakerCommand,
+            content.as_union_value(),
+        );
+
+        let message = root_as_message(&data).unwrap();
+        self.handler
+            .handle_speaker_command(message.content_as_speaker_command().unwrap())
+    }
+
+    fn query(
+        &mut self,
+        device_id: &str,
+        query_type: SpeakerQueryContent,
+        build: impl FnOnce(&mut FlatBufferBuilder<'static>) -> Content,
+    ) -> Result<Option<Vec<u8>>, String> {
+        let mut fbb = FlatBufferBuilder::new();
+        let query = build(&mut fbb);
+        let device_id = fbb.create_string(device_id);
+        let content = SpeakerQuery::create(
+            &mut fbb,
+            &SpeakerQueryArgs {
+                device_id: Some(device_id),
+                query_type,
+                query: Some(query),
+            },
+        );
+        let data = finish(fbb, MessageContent::SpeakerQuery, content.as_union_value());
+
+        let message = root_as_message(&data).unwrap();
+        self.handler
+            .handle_speaker_query(message.content_as_speaker_query().unwrap())
+    }
+
+    fn play(&mut self, device_id: &str, url: &str) -> Result<Option<Vec<u8>>, String> {
+        self.command(device_id, SpeakerCommandContent::Play, |fbb| {
+            play_youtube(fbb, url)
+        })
+    }
+
+    fn calls(&self) -> Vec<String> {
+        self.backend.take_calls()
+    }
+
+    /// Speaker events published since the last call, as (type, device id).
+    fn events(&self) -> Vec<(SpeakerEventContent, String)> {
+        self.events
+            .take()
+            .iter()
+            .filter(|(topic, _)| topic == "speaker.event")
+            .filter_map(|(_, message)| speaker_event(message))
+            .collect()
+    }
+}
+
+impl Drop for Harness {
+    fn drop(&mut self) {
+        for path in &self.paths {
+            let _ = std::fs::remove_file(path);
+        }
+    }
+}
+
+fn finish(
+    mut fbb: FlatBufferBuilder<'static>,
+    content_type: MessageContent,
+    content: Content,
+) -> Vec<u8> {
+    let root = Message::create(
+        &mut fbb,
+        &MessageArgs {
+            timestamp: 0,
+            content_type,
+            content: Some(content),
+        },
+    );
+    fbb.finish(root, None);
+    fbb.finished_data().to_vec()
+}
+
+fn speaker_event(message: &[u8]) -> Option<(SpeakerEventContent, String)> {
+    let event = root_as_message(message).ok()?.content_as_speaker_event()?;
+    Some((event.event_type(), event.device_id()?.to_string()))
+}
+
+fn play_youtube(fbb: &mut FlatBufferBuilder<'static>, url: &str) -> Content {
+    let url = fbb.create_string(url);
+    let content = PlayYoutube::create(fbb, &PlayYoutubeArgs { url: Some(url) });
+    Play::create(
+        fbb,
+        &PlayArgs {
+            content_type: PlayContent::PlayYoutube,
+            content: Some(content.as_union_value()),
+        },
+    )
+    .as_union_value()
+}
+
+fn set_music_volume(fbb: &mut FlatBufferBuilder<'static>, volume: f32) -> Content {
+    SetMusicVolume::create(fbb, &SetMusicVolumeArgs { volume }).as_union_value()
+}
+
+fn toggle_pause(fbb: &mut FlatBufferBuilder<'static>) -> Content {
+    TogglePause::create(fbb, &TogglePauseArgs {}).as_union_value()
+}
+
+fn create_group(fbb: &mut FlatBufferBuilder<'static>, device_ids: &[&str]) -> Content {
+    let device_ids: Vec<_> = device_ids.iter().map(|id| fbb.create_string(id)).collect();
+    let device_ids = fbb.create_vector(&device_ids);
+    CreateSpeakerGroup::create(
+        fbb,
+        &CreateSpeakerGroupArgs {
+            device_ids: Some(device_ids),
+        },
+    )
+    .as_union_value()
+}
+
+fn set_sleep_timer(fbb: &mut FlatBufferBuilder<'static>, duration: f32, fade: f32) -> Content {
+    SetSleepTimer::create(fbb, &SetSleepTimerArgs { duration, fade }).as_union_value()
+}
+
+fn set_crossfade(fbb: &mut FlatBufferBuilder<'static>, duration: f32) -> Content {
+    SetCrossfade::create(fbb, &SetCrossfadeArgs { duration }).as_union_value()
+}
+
+fn set_ducking(fbb: &mut FlatBufferBuilder<'static>, amount: f32) -> Content {
+    SetDucking::create(fbb, &SetDuckingArgs { amount }).as_union_value()
+}
+
+fn play_announcement(fbb: &mut FlatBufferBuilder<'static>) -> Content {
+    let url = fbb.create_string("https://example.com/doorbell.mp3");
+    let content = PlayYoutube::create(fbb, &PlayYoutubeArgs { url: Some(url) });
+    PlayAnnouncement::create(
+        fbb,
+        &PlayAnnouncementArgs {
+            content_type: PlayContent::PlayYoutube,
+            content: Some(content.as_union_value()),
+        },
+    )
+    .as_union_value()
+}
+
+fn event_type(message: Option<Vec<u8>>) -> (SpeakerEventContent, String) {
+    speaker_event(&message.expect("no response")).expect("not a speaker event")
+}
+
+#[test]
+fn set_music_volume_applies_to_the_running_player() {
+    let mut harness = Harness::new();
+    harness.play(KITCHEN, URL).unwrap();
+    harness.calls();
+    harness.events();
+
+    let response = harness.command(KITCHEN, SpeakerCommandContent::SetMusicVolume, |fbb| {
+        set_music_volume(fbb, 40.0)
+    });
+
+    assert_eq!(response, Ok(None));
+    assert_eq!(
+        harness.calls(),
+        [format!("{}/music: set_property volume 40.0", KITCHEN)]
+    );
+    assert_eq!(
+        harness.events(),
+        [(SpeakerEventContent::MusicVolumeChanged, KITCHEN.to_string())]
+    );
+}
+
+#[test]
+fn set_music_volume_is_applied_when_the_player_spawns() {
+    let mut harness = Harness::new();
+
+    harness
+        .command(KITCHEN, SpeakerCommandContent::SetMusicVolume, |fbb| {
+            set_music_volume(fbb, 25.0)
+        })
+        .unwrap();
+    assert!(harness.calls().is_empty());
+
+    harness.play(KITCHEN, URL).unwrap();
+    assert_eq!(
+        harness.calls(),
+        [
+            format!("{}/music: spawn", KITCHEN),
+            format!("{}/music: set_property volume 25.0", KITCHEN),
+            format!("{}/music: set_crossfade false 0", KITCHEN),
+            format!("{}/music: play {}", KITCHEN, URL),
+        ]
+    );
+}
+
+#[test]
+fn toggle_pause_needs_a_player() {
+    let mut harness = Harness::new();
+
+    let response = harness.command(KITCHEN, SpeakerCommandContent::TogglePause, toggle_pause);
+
+    assert_eq!(response, Err("No mpv process".to_string()));
+}
+
+#[test]
+fn toggle_pause_cycles_and_reports_pause() {
+    let mut harness = Harness::new();
+    harness.play(KITCHEN, URL).unwrap();
+    harness.calls();
+    harness.events();
+
+    harness
+        .command(KITCHEN, SpeakerCommandContent::TogglePause, toggle_pause)
+        .unwrap();
+
+    assert_eq!(
+        harness.calls(),
+        [format!("{}/music: cycle_property pause", KITCHEN)]
+    );
+    assert_eq!(
+        harness.events(),
+        [(SpeakerEventContent::PauseChanged, KITCHEN.to_string())]
+    );
+}
+
+#[test]
+fn play_spawns_a_player_and_plays() {
+    let mut harness = Harness::new();
+
+    assert_eq!(harness.play(KITCHEN, URL), Ok(None));
+
+    let calls = harness.calls();
+    assert_eq!(calls.first().unwrap(), &format!("{}/music: spawn", KITCHEN));
+    assert_eq!(
+        calls.last().unwrap(),
+        &format!("{}/music: play {}", KITCHEN, URL)
+    );
+    // The volume the new player starts with is reported by its observer
+    assert_eq!(
+        harness.events(),
+        [
+            (SpeakerEventContent::MusicVolumeChanged, KITCHEN.to_string()),
+            (SpeakerEventContent::PlayStarted, KITCHEN.to_string())
+        ]
+    );
+}
+
+#[test]
+fn play_started_reports_what_was_requested() {
+    let mut harness = Harness::new();
+
+    harness
+        .command(KITCHEN, SpeakerCommandContent::Play, |fbb| {
+            let url = fbb.create_string(URL);
+            let content = PlayStream::create(fbb, &PlayStreamArgs { url: Some(url) });
+            Play::create(
+                fbb,
+                &PlayArgs {
+                    content_type: PlayContent::PlayStream,
+                    content: Some(content.as_union_value()),
+                },
+            )
+            .as_union_value()
+        })
+        .unwrap();
+
+    let events = harness.events.take();
+    let play_started = events
+        .iter()
+        .filter_map(|(_, message)| root_as_message(message).ok()?.content_as_speaker_event())
+        .find_map(|event| event.event_as_play_started())
+        .unwrap();
+    assert_eq!(play_started.content_type(), PlayContent::PlayStream);
+    assert_eq!(
+        play_started.content_as_play_stream().unwrap().url(),
+        Some(URL)
+    );
+}
+
+#[test]
+fn play_reuses_the_running_player() {
+    let mut harness = Harness::new();
+    harness.play(KITCHEN, URL).unwrap();
+    harness.calls();
+
+    harness.play(KITCHEN, URL).unwrap();
+
+    assert_eq!(
+        harness.calls(),
+        [format!("{}/music: play {}", KITCHEN, URL)]
+    );
+}
+
+#[test]
+fn play_respawns_a_player_that_exited() {
+    let mut harness = Harness::new();
+    harness.play(KITCHEN, URL).unwrap();
+    harness.calls();
+    harness.backend.crash(KITCHEN);
+
+    harness.play(KITCHEN, URL).unwrap();
+
+    let calls = harness.calls();
+    assert_eq!(calls[0], format!("{}/music: kill", KITCHEN));
+    assert_eq!(calls[1], format!("{}/music: spawn", KITCHEN));
+}
+
+#[test]
+fn play_rejects_urls_mpv_should_not_open() {
+    let mut harness = Harness::new();
+
+    let response = harness.play(KITCHEN, "file:///etc/passwd");
+
+    assert_eq!(response, Err("Invalid URL file:///etc/passwd".to_string()));
+    assert!(harness.calls().is_empty());
+}
+
+#[test]
+fn play_wav_plays_the_data() {
+    let mut harness = Harness::new();
+
+    harness
+        .command(KITCHEN, SpeakerCommandContent::Play, |fbb| {
+            let data = fbb.create_vector(&[1u8, 2, 3]);
+            let content = PlayWav::create(fbb, &PlayWavArgs { data: Some(data) });
+            Play::create(
+                fbb,
+                &PlayArgs {
+                    content_type: PlayContent::PlayWav,
+                    content: Some(content.as_union_value()),
+                },
+            )
+            .as_union_value()
+        })
+        .unwrap();
+
+    assert_eq!(
+        harness.calls().last().unwrap(),
+        &format!("{}/music: play_wav 3 bytes", KITCHEN)
+    );
+}
+
+#[test]
+fn play_without_content_is_rejected() {
+    let mut harness = Harness::new();
+
+    let response = harness.command(KITCHEN, SpeakerCommandContent::Play, |fbb| {
+        Play::create(fbb, &PlayArgs::default()).as_union_value()
+    });
+
+    assert_eq!(response, Err("Unsupported play content".to_string()));
+}
+
+#[test]
+fn stop_stops_the_player() {
+    let mut harness = Harness::new();
+    harness
+        .command(KITCHEN, SpeakerCommandContent::Stop, |fbb| {
+            Stop::create(fbb, &StopArgs {}).as_union_value()
+        })
+        .unwrap();
+    // Nothing to stop without a player
+    assert!(harness.calls().is_empty());
+
+    harness.play(KITCHEN, URL).unwrap();
+    harness.calls();
+    harness
+        .command(KITCHEN, SpeakerCommandContent::Stop, |fbb| {
+            Stop::create(fbb, &StopArgs {}).as_union_value()
+        })
+        .unwrap();
+
+    assert_eq!(harness.calls(), [format!("{}/music: stop", KITCHEN)]);
+}
+
+#[test]
+fn seek_sets_and_reports_the_position() {
+    let mut harness = Harness::new();
+    harness.play(KITCHEN, URL).unwrap();
+    harness.calls();
+    harness.events();
+
+    harness
+        .command(KITCHEN, SpeakerCommandContent::Seek, |fbb| {
+            Seek::create(fbb, &SeekArgs { seek: 12.5 }).as_union_value()
+        })
+        .unwrap();
+
+    assert_eq!(
+        harness.calls(),
+        [format!("{}/music: set_property time-pos 12.5", KITCHEN)]
+    );
+    assert_eq!(
+        harness.events(),
+        [(SpeakerEventContent::SeekChanged, KITCHEN.to_string())]
+    );
+}
+
+#[test]
+fn preload_queues_the_next_url() {
+    let mut harness = Harness::new();
+
+    harness
+        .command(KITCHEN, SpeakerCommandContent::Preload, |fbb| {
+            let url = fbb.create_string(URL);
+            let content = PlayYoutube::create(fbb, &PlayYoutubeArgs { url: Some(url) });
+            Preload::create(
+                fbb,
+                &PreloadArgs {
+                    content_type: PlayContent::PlayYoutube,
+                    content: Some(content.as_union_value()),
+                },
+            )
+            .as_union_value()
+        })
+        .unwrap();
+
+    assert_eq!(
+        harness.calls().last().unwrap(),
+        &format!("{}/music: preload {} PlayYoutube", KITCHEN, URL)
+    );
+}
+
+#[test]
+fn preload_keeps_the_kind_of_its_content() {
+    let mut harness = Harness::new();
+
+    harness
+        .command(KITCHEN, SpeakerCommandContent::Preload, |fbb| {
+            let url = fbb.create_string(URL);
+            let content = PlayStream::create(fbb, &PlayStreamArgs { url: Some(url) });
+            Preload::create(
+                fbb,
+                &PreloadArgs {
+                    content_type: PlayContent::PlayStream,
+                    content: Some(content.as_union_value()),
+                },
+            )
+            .as_union_value()
+        })
+        .unwrap();
+
+    assert_eq!(
+        harness.calls().last().unwrap(),
+        &format!("{}/music: preload {} PlayStream", KITCHEN, URL)
+    );
+}
+
+#[test]
+fn preload_rejects_wav_data() {
+    let mut harness = Harness::new();
+
+    let response = harness.command(KITCHEN, SpeakerCommandContent::Preload, |fbb| {
+        let data = fbb.create_vector(&[0u8; 4]);
+        let content = PlayWav::create(fbb, &PlayWavArgs { data: Some(data) });
+        Preload::create(
+            fbb,
+            &PreloadArgs {
+                content_type: PlayContent::PlayWav,
+                content: Some(content.as_union_value()),
+            },
+        )
+        .as_union_value()
+    });
+
+    assert_eq!(response, Err("WAV data can't be preloaded".to_string()));
+}
+
+#[test]
+fn preload_rejects_invalid_urls() {
+    let mut harness = Harness::new();
+
+    let response = harness.command(KITCHEN, SpeakerCommandContent::Preload, |fbb| {
+        let url = fbb.create_string("memory://x");
+        let content = PlayYoutube::create(fbb, &PlayYoutubeArgs { url: Some(url) });
+        Preload::create(
+            fbb,
+            &PreloadArgs {
+                content_type: PlayContent::PlayYoutube,
+                content: Some(content.as_union_value()),
+            },
+        )
+        .as_union_value()
+    });
+
+    assert_eq!(response, Err("Invalid URL memory://x".to_string()));
+}
+
+#[test]
+fn set_crossfade_is_kept_for_respawns() {
+    let mut harness = Harness::new();
+
+    harness
+        .command(KITCHEN, SpeakerCommandContent::SetCrossfade, |fbb| {
+            set_crossfade(fbb, 3.0)
+        })
+        .unwrap();
+    assert!(harness.calls().is_empty());
+    harness.play(KITCHEN, URL).unwrap();
+
+    let calls = harness.calls();
+    assert!(calls.contains(&format!("{}/crossfade: spawn", KITCHEN)));
+    assert!(calls.contains(&format!("{}/music: set_crossfade true 3", KITCHEN)));
+}
+
+#[test]
+fn set_crossfade_connects_a_crossfade_player_to_the_music() {
+    let mut harness = Harness::new();
+    harness.play(KITCHEN, URL).unwrap();
+    harness.calls();
+
+    harness
+        .command(KITCHEN, SpeakerCommandContent::SetCrossfade, |fbb| {
+            set_crossfade(fbb, 3.0)
+        })
+        .unwrap();
+
+    assert_eq!(
+        harness.calls(),
+        [
+            format!("{}/crossfade: spawn", KITCHEN),
+            format!("{}/music: set_crossfade true 3", KITCHEN),
+        ]
+    );
+}
+
+#[test]
+fn turning_crossfade_off_kills_the_crossfade_player() {
+    let mut harness = Harness::new();
+    harness
+        .command(KITCHEN, SpeakerCommandContent::SetCrossfade, |fbb| {
+            set_crossfade(fbb, 3.0)
+        })
+        .unwrap();
+    harness.play(KITCHEN, URL).unwrap();
+    harness.calls();
+
+    harness
+        .command(KITCHEN, SpeakerCommandContent::SetCrossfade, |fbb| {
+            set_crossfade(fbb, 0.0)
+        })
+        .unwrap();
+
+    assert_eq!(
+        harness.calls(),
+        [
+            format!("{}/crossfade: kill", KITCHEN),
+            format!("{}/music: set_crossfade false 0", KITCHEN),
+        ]
+    );
+}
+
+#[test]
+fn set_crossfade_rejects_out_of_range_durations() {
+    let mut harness = Harness::new();
+
+    for duration in [-1.0, 31.0, f32::NAN] {
+        assert_eq!(
+            harness.command(KITCHEN, SpeakerCommandContent::SetCrossfade, |fbb| {
+                set_crossfade(fbb, duration)
+            }),
+            Err(format!("Crossfade {} is out of range", duration))
+        );
+    }
+    assert!(harness.calls().is_empty());
+}
+
+#[test]
+fn create_speaker_group_makes_the_first_member_lead() {
+    let mut harness = Harness::new();
+    harness.play(KITCHEN, URL).unwrap();
+    harness.play(BEDROOM, URL).unwrap();
+    harness.calls();
+
+    let response = harness.command(
+        "downstairs",
+        SpeakerCommandContent::CreateSpeakerGroup,
+        |fbb| create_group(fbb, &[KITCHEN, BEDROOM]),
+    );
+
+    assert_eq!(
+        event_type(response.unwrap()),
+        (
+            SpeakerEventContent::SpeakerGroupChanged,
+            "downstairs".to_string()
+        )
+    );
+    assert_eq!(
+        harness.calls(),
+        [
+            format!(
+                "{}/music: set_group_role Some(Leader(\"downstairs\"))",
+                KITCHEN
+            ),
+            format!("{}/music: set_group_role Some(Member)", BEDROOM),
+        ]
+    );
+}
+
+#[test]
+fn grouped_speakers_report_track_endings_only_for_the_group() {
+    let mut harness = Harness::new();
+    harness.play(KITCHEN, URL).unwrap();
+    harness.play(BEDROOM, URL).unwrap();
+    harness
+        .command(
+            "downstairs",
+            SpeakerCommandContent::CreateSpeakerGroup,
+            |fbb| create_group(fbb, &[KITCHEN, BEDROOM]),
+        )
+        .unwrap();
+    harness.events();
+
+    harness.backend.end_file(KITCHEN);
+    harness.backend.end_file(BEDROOM);
+
+    assert_eq!(
+        harness.events(),
+        [(SpeakerEventContent::FileEnded, "downstairs".to_string())]
+    );
+}
+
+#[test]
+fn create_speaker_group_rejects_unknown_devices() {
+    let mut harness = Harness::new();
+
+    let response = harness.command(
+        "downstairs",
+        SpeakerCommandContent::CreateSpeakerGroup,
+        |fbb| create_group(fbb, &[KITCHEN, "plughw:CARD=Garage,DEV=0"]),
+    );
+
+    assert_eq!(
+        response,
+        Err("Device plughw:CARD=Garage,DEV=0 does not exist".to_string())
+    );
+}
+
+#[test]
+fn create_speaker_group_ignores_repeated_members() {
+    let mut harness = Harness::new();
+    harness.play(KITCHEN, URL).unwrap();
+    harness.calls();
+
+    harness
+        .command(
+            "downstairs",
+            SpeakerCommandContent::CreateSpeakerGroup,
+            |fbb| create_group(fbb, &[KITCHEN, KITCHEN]),
+        )
+        .unwrap();
+
+    assert_eq!(
+        harness.calls(),
+        [format!(
+            "{}/music: set_group_role Some(Leader(\"downstairs\"))",
+            KITCHEN
+        )]
+    );
+    harness.events();
+    harness.play(KITCHEN, URL).unwrap();
+    assert!(harness
+        .events()
+        .contains(&(SpeakerEventContent::PlayStarted, "downstairs".to_string())));
+}
+
+#[test]
+fn create_speaker_group_rejects_members_of_other_groups() {
+    let mut harness = Harness::new();
+    harness.play(KITCHEN, URL).unwrap();
+    harness
+        .command(
+            "downstairs",
+            SpeakerCommandContent::CreateSpeakerGroup,
+            |fbb| create_group(fbb, &[KITCHEN]),
+        )
+        .unwrap();
+    harness.calls();
+
+    let response = harness.command(
+        "everywhere",
+        SpeakerCommandContent::CreateSpeakerGroup,
+        |fbb| create_group(fbb, &[BEDROOM, KITCHEN]),
+    );
+
+    assert_eq!(
+        response,
+        Err(format!("Device {} is already in group downstairs", KITCHEN))
+    );
+    assert!(harness.calls().is_empty());
+    harness.events();
+    harness.play(KITCHEN, URL).unwrap();
+    assert!(harness
+        .events()
+        .contains(&(SpeakerEventContent::PlayStarted, "downstairs".to_string())));
+
+    // Recreating a group with its own members is fine
+    assert!(harness
+        .command(
+            "downstairs",
+            SpeakerCommandContent::CreateSpeakerGroup,
+            |fbb| create_group(fbb, &[KITCHEN, BEDROOM]),
+        )
+        .is_ok());
+}
+
+#[test]
+fn group_commands_go_to_every_member() {
+    let mut harness = Harness::new();
+    harness
+        .command(
+            "downstairs",
+            SpeakerCommandContent::CreateSpeakerGroup,
+            |fbb| create_group(fbb, &[KITCHEN, BEDROOM]),
+        )
+        .unwrap();
+
+    harness.play("downstairs", URL).unwrap();
+    let calls = harness.calls();
+    for device_id in [KITCHEN, BEDROOM] {
+        assert!(calls.contains(&format!("{}/music: loadfile_paused {}", device_id, URL)));
+    }
+
+    harness
+        .command("downstairs", SpeakerCommandContent::SetMusicVolume, |fbb| {
+            set_music_volume(fbb, 60.0)
+        })
+        .unwrap();
+    let calls = harness.calls();
+    for device_id in [KITCHEN, BEDROOM] {
+        assert!(calls.contains(&format!("{}/music: set_property volume 60.0", device_id)));
+    }
+    // The leader's events stand in for the group
+    assert!(harness.events().contains(&(
+        SpeakerEventContent::MusicVolumeChanged,
+        "downstairs".to_string()
+    )));
+}
+
+#[test]
+fn group_toggle_pause_follows_the_leader() {
+    let mut harness = Harness::new();
+    harness
+        .command(
+            "downstairs",
+            SpeakerCommandContent::CreateSpeakerGroup,
+            |fbb| create_group(fbb, &[KITCHEN, BEDROOM]),
+        )
+        .unwrap();
+    harness.play(KITCHEN, URL).unwrap();
+    harness.play(BEDROOM, URL).unwrap();
+    harness.calls();
+
+    harness
+        .command(
+            "downstairs",
+            SpeakerCommandContent::TogglePause,
+            toggle_pause,
+        )
+        .unwrap();
+
+    let calls = harness.calls();
+    for device_id in [KITCHEN, BEDROOM] {
+        assert!(calls.contains(&format!(
+            "{}/music remote: set_property pause true",
+            device_id
+        )));
+    }
+}
+
+#[test]
+fn dissolve_speaker_group_clears_the_roles() {
+    let mut harness = Harness::new();
+    harness.play(KITCHEN, URL).unwrap();
+    harness
+        .command(
+            "downstairs",
+            SpeakerCommandContent::CreateSpeakerGroup,
+            |fbb| create_group(fbb, &[KITCHEN]),
+        )
+        .unwrap();
+    harness.calls();
+
+    let response = harness.command(
+        "downstairs",
+        SpeakerCommandContent::DissolveSpeakerGroup,
+        |fbb| DissolveSpeakerGroup::create(fbb, &DissolveSpeakerGroupArgs {}).as_union_value(),
+    );
+
+    assert_eq!(
+        event_type(response.unwrap()),
+        (
+            SpeakerEventContent::SpeakerGroupChanged,
+            "downstairs".to_string()
+        )
+    );
+    assert_eq!(
+        harness.calls(),
+        [format!("{}/music: set_group_role None", KITCHEN)]
+    );
+    assert_eq!(
+        harness.play("downstairs", URL),
+        Err("Device does not exist".to_string())
+    );
+}
+
+#[test]
+fn play_announcement_ducks_the_music() {
+    let mut harness = Harness::new();
+    harness.play(KITCHEN, URL).unwrap();
+    harness
+        .command(KITCHEN, SpeakerCommandContent::SetDucking, |fbb| {
+            set_ducking(fbb, 1.5)
+        })
+        .unwrap();
+    harness.calls();
+
+    harness
+        .command(
+            KITCHEN,
+            SpeakerCommandContent::PlayAnnouncement,
+            play_announcement,
+        )
+        .unwrap();
+
+    // The ducking amount is clamped to 1
+    assert_eq!(
+        harness.calls(),
+        [
+            format!("{}/announcement: spawn", KITCHEN),
+            format!("{}/announcement: set_ducking true 1", KITCHEN),
+            format!(
+                "{}/announcement: play https://example.com/doorbell.mp3",
+                KITCHEN
+            ),
+            format!(
+                "{}/music remote: command [\"af\",\"add\",\"@duck:lavfi=[volume=0]\"]",
+                KITCHEN
+            ),
+        ]
+    );
+}
+
+#[test]
+fn announcements_duck_the_music_until_they_end() {
+    let mut harness = Harness::new();
+    harness.play(KITCHEN, URL).unwrap();
+    harness
+        .command(KITCHEN, SpeakerCommandContent::SetDucking, |fbb| {
+            set_ducking(fbb, 0.5)
+        })
+        .unwrap();
+    harness.calls();
+
+    harness
+        .command(
+            KITCHEN,
+            SpeakerCommandContent::PlayAnnouncement,
+            play_announcement,
+        )
+        .unwrap();
+    assert!(harness.calls().contains(&format!(
+        "{}/music remote: command [\"af\",\"add\",\"@duck:lavfi=[volume=0.5]\"]",
+        KITCHEN
+    )));
+    assert_eq!(harness.backend.filter_gain(KITCHEN, "duck"), "0.5");
+
+    harness.backend.end_announcement(KITCHEN);
+    assert_eq!(
+        harness.calls(),
+        [format!(
+            "{}/music remote: command [\"af\",\"add\",\"@duck:lavfi=[volume=1]\"]",
+            KITCHEN
+        )]
+    );
+    assert_eq!(harness.backend.filter_gain(KITCHEN, "duck"), "1");
+}
+
+#[test]
+fn announcements_duck_idle_music() {
+    let mut harness = Harness::new();
+    harness.play(KITCHEN, URL).unwrap();
+    harness
+        .command(KITCHEN, SpeakerCommandContent::Stop, |fbb| {
+            Stop::create(fbb, &StopArgs {}).as_union_value()
+        })
+        .unwrap();
+    harness
+        .command(KITCHEN, SpeakerCommandContent::SetDucking, |fbb| {
+            set_ducking(fbb, 0.5)
+        })
+        .unwrap();
+
+    harness
+        .command(
+            KITCHEN,
+            SpeakerCommandContent::PlayAnnouncement,
+            play_announcement,
+        )
+        .unwrap();
+
+    // Music started during the announcement plays ducked
+    harness.play(KITCHEN, URL).unwrap();
+    assert_eq!(harness.backend.filter_gain(KITCHEN, "duck"), "0.5");
+}
+
+#[test]
+fn ducking_outlasts_a_track_change_mid_announcement() {
+    let mut harness = Harness::new();
+    harness.play(KITCHEN, URL).unwrap();
+    harness
+        .command(KITCHEN, SpeakerCommandContent::SetDucking, |fbb| {
+            set_ducking(fbb, 0.5)
+        })
+        .unwrap();
+    harness
+        .command(
+            KITCHEN,
+            SpeakerCommandContent::PlayAnnouncement,
+            play_announcement,
+        )
+        .unwrap();
+
+    harness
+        .play(KITCHEN, "https://example.com/next.mp3")
+        .unwrap();
+    assert_eq!(harness.backend.filter_gain(KITCHEN, "duck"), "0.5");
+
+    harness.backend.end_announcement(KITCHEN);
+    assert_eq!(harness.backend.filter_gain(KITCHEN, "duck"), "1");
+}
+
+#[test]
+fn set_ducking_rejects_non_finite_amounts() {
+    let mut harness = Harness::new();
+    harness.play(KITCHEN, URL).unwrap();
+
+    for amount in [f32::NAN, f32::INFINITY, f32::NEG_INFINITY] {
+        assert_eq!(
+            harness.command(KITCHEN, SpeakerCommandContent::SetDucking, |fbb| {
+                set_ducking(fbb, amount)
+            }),
+            Err(format!("Ducking {} is out of range", amount))
+        );
+    }
+    harness.calls();
+
+    // The default amount is left in place
+    harness
+        .command(
+            KITCHEN,
+            SpeakerCommandContent::PlayAnnouncement,
+            play_announcement,
+        )
+        .unwrap();
+    assert!(harness
+        .calls()
+        .contains(&format!("{}/announcement: set_ducking true 0.7", KITCHEN)));
+}
+
+#[test]
+fn update_speaker_info_answers_with_the_speaker_list() {
+    let mut harness = Harness::new();
+
+    let response = harness
+        .command(KITCHEN, SpeakerCommandContent::UpdateSpeakerInfo, |fbb| {
+            let name = fbb.create_string("Kitchen");
+            UpdateSpeakerInfo::create(
+                fbb,
+                &UpdateSpeakerInfoArgs {
+                    name: Some(name),
+                    default_volume: 50.0,
+                    ..Default::default()
+                },
+            )
+            .as_union_value()
+        })
+        .unwrap()
+        .unwrap();
+
+    let speakers = root_as_message(&response)
+        .unwrap()
+        .content_as_speaker_list_event()
+        .unwrap()
+        .speakers()
+        .unwrap();
+    let kitchen = speakers
+        .iter()
+        .find(|speaker| speaker.device_id() == Some(KITCHEN))
+        .unwrap();
+    assert_eq!(kitchen.name(), Some("Kitchen"));
+    assert_eq!(kitchen.default_volume(), 50.0);
+}
+
+#[test]
+fn update_speaker_info_can_clear_the_default_volume() {
+    let mut harness = Harness::new();
+
+    for default_volume in [50.0, -1.0] {
+        let response = harness
+            .command(KITCHEN, SpeakerCommandContent::UpdateSpeakerInfo, |fbb| {
+                UpdateSpeakerInfo::create(
+                    fbb,
+                    &UpdateSpeakerInfoArgs {
+                        default_volume,
+                        ..Default::default()
+                    },
+                )
+                .as_union_value()
+            })
+            .unwrap()
+            .unwrap();
+
+        let speakers = root_as_message(&response)
+            .unwrap()
+            .content_as_speaker_list_event()
+            .unwrap()
+            .speakers()
+            .unwrap();
+        let kitchen = speakers
+            .iter()
+            .find(|speaker| speaker.device_id() == Some(KITCHEN))
+            .unwrap();
+        assert_eq!(kitchen.default_volume(), default_volume);
+    }
+    assert_eq!(harness.handler.registry.get(KITCHEN).default_volume, None);
+}
+
+#[test]
+fn reload_device_filter_drops_excluded_devices() {
+    let path = temp_path("device_filter.json");
+    std::fs::write(&path, r#"{"exclude_names": ["*Bedroom*"]}"#).unwrap();
+    // The only test reading DEVICE_FILTER_PATH
+    std::env::set_var("DEVICE_FILTER_PATH", &path);
+    let mut harness = Harness::new();
+    harness.play(BEDROOM, URL).unwrap();
+    harness.calls();
+
+    let response = harness.command("", SpeakerCommandContent::ReloadDeviceFilter, |fbb| {
+        ReloadDeviceFilter::create(fbb, &ReloadDeviceFilterArgs {}).as_union_value()
+    });
+
+    assert_eq!(response, Ok(None));
+    assert_eq!(harness.calls(), [format!("{}/music: kill", BEDROOM)]);
+    assert_eq!(
+        harness.play(BEDROOM, URL),
+        Err("Device does not exist".to_string())
+    );
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn set_hardware_volume_answers_with_the_mixer_volume() {
+    let mut harness = Harness::new();
+
+    let response = harness.command(KITCHEN, SpeakerCommandContent::SetHardwareVolume, |fbb| {
+        SetHardwareVolume::create(fbb, &SetHardwareVolumeArgs { volume: 60.0 }).as_union_value()
+    });
+
+    let response = response.unwrap().unwrap();
+    let event = root_as_message(&response)
+        .unwrap()
+        .content_as_speaker_event()
+        .unwrap();
+    assert_eq!(
+        event.event_as_hardware_volume_changed().unwrap().volume(),
+        60.0
+    );
+    assert_eq!(event.device_id(), Some(KITCHEN));
+}
+
+#[test]
+fn set_hardware_volume_rejects_out_of_range_volumes() {
+    let mut harness = Harness::new();
+
+    for volume in [140.0, -1.0, f32::NAN] {
+        let response = harness.command(KITCHEN, SpeakerCommandContent::SetHardwareVolume, |fbb| {
+            SetHardwareVolume::create(fbb, &SetHardwareVolumeArgs { volume }).as_union_value()
+        });
+        assert_eq!(
+            response,
+            Err(format!("Hardware volume {} is out of range", volume))
+        );
+    }
+}
+
+#[test]
+fn set_mute_is_applied_when_the_player_spawns() {
+    let mut harness = Harness::new();
+
+    harness
+        .command(KITCHEN, SpeakerCommandContent::SetMute, |fbb| {
+            SetMute::create(fbb, &SetMuteArgs { muted: true }).as_union_value()
+        })
+        .unwrap();
+    assert!(harness.calls().is_empty());
+
+    harness.play(KITCHEN, URL).unwrap();
+    assert!(harness
+        .calls()
+        .contains(&format!("{}/music: set_property mute true", KITCHEN)));
+}
+
+#[test]
+fn set_speed_checks_mpvs_limits() {
+    let mut harness = Harness::new();
+
+    let response = harness.command(KITCHEN, SpeakerCommandContent::SetSpeed, |fbb| {
+        SetSpeed::create(fbb, &SetSpeedArgs { speed: 0.0 }).as_union_value()
+    });
+    assert_eq!(response, Err("Speed 0 is out of range".to_string()));
+
+    harness.play(KITCHEN, URL).unwrap();
+    harness
+        .command(KITCHEN, SpeakerCommandContent::SetSpeed, |fbb| {
+            SetSpeed::create(fbb, &SetSpeedArgs { speed: 1.5 }).as_union_value()
+        })
+        .unwrap();
+    assert_eq!(
+        harness.calls().last().unwrap(),
+        &format!("{}/music: set_property speed 1.5", KITCHEN)
+    );
+}
+
+#[test]
+fn seek_relative_moves_the_position() {
+    let mut harness = Harness::new();
+    harness.play(KITCHEN, URL).unwrap();
+    harness.calls();
+
+    harness
+        .command(KITCHEN, SpeakerCommandContent::SeekRelative, |fbb| {
+            SeekRelative::create(fbb, &SeekRelativeArgs { offset: -10.0 }).as_union_value()
+        })
+        .unwrap();
+
+    assert_eq!(
+        harness.calls(),
+        [format!("{}/music: seek_relative -10", KITCHEN)]
+    );
+}
+
+#[test]
+fn set_audio_filter_accepts_only_presets() {
+    let mut harness = Harness::new();
+
+    let response = harness.command(KITCHEN, SpeakerCommandContent::SetAudioFilter, |fbb| {
+        let preset = fbb.create_string("night");
+        SetAudioFilter::create(
+            fbb,
+            &SetAudioFilterArgs {
+                preset: Some(preset),
+            },
+        )
+        .as_union_value()
+    });
+    assert_eq!(
+        event_type(response.unwrap()),
+        (SpeakerEventContent::AudioFilterChanged, KITCHEN.to_string())
+    );
+    harness.play(KITCHEN, URL).unwrap();
+    assert!(harness
+        .calls()
+        .contains(&format!("{}/music: set_audio_filter night", KITCHEN)));
+
+    let response = harness.command(KITCHEN, SpeakerCommandContent::SetAudioFilter, |fbb| {
+        let preset = fbb.create_string("karaoke");
+        SetAudioFilter::create(
+            fbb,
+            &SetAudioFilterArgs {
+                preset: Some(preset),
+            },
+        )
+        .as_union_value()
+    });
+    assert_eq!(
+        response,
+        Err("Unknown audio filter preset karaoke".to_string())
+    );
+}
+
+#[test]
+fn set_sleep_timer_answers_with_the_timer() {
+    let mut harness = Harness::new();
+
+    let response = harness
+        .command(KITCHEN, SpeakerCommandContent::SetSleepTimer, |fbb| {
+            set_sleep_timer(fbb, 600.0, 30.0)
+        })
+        .unwrap()
+        .unwrap();
+
+    let sleep_timer_set = root_as_message(&response)
+        .unwrap()
+        .content_as_speaker_event()
+        .unwrap()
+        .event_as_sleep_timer_set()
+        .unwrap();
+    assert!(sleep_timer_set.remaining() > 590.0);
+    assert_eq!(sleep_timer_set.fade(), 30.0);
+
+    let response = harness.command(KITCHEN, SpeakerCommandContent::SetSleepTimer, |fbb| {
+        set_sleep_timer(fbb, -1.0, 30.0)
+    });
+    assert_eq!(
+        response,
+        Err("Sleep timer duration -1 is invalid".to_string())
+    );
+
+    let response = harness.command(KITCHEN, SpeakerCommandContent::SetSleepTimer, |fbb| {
+        set_sleep_timer(fbb, 1e20, 30.0)
+    });
+    assert_eq!(
+        response,
+        Err("Sleep timer duration 100000000000000000000 is invalid".to_string())
+    );
+}
+
+#[test]
+fn sleep_timer_fades_out_and_stops() {
+    let mut harness = Harness::new();
+    harness.play(KITCHEN, URL).unwrap();
+    harness
+        .command(KITCHEN, SpeakerCommandContent::SetSleepTimer, |fbb| {
+            set_sleep_timer(fbb, 0.2, 0.2)
+        })
+        .unwrap();
+    harness.calls();
+    harness.events();
+
+    thread::sleep(Duration::from_millis(100));
+    harness.handler.tick_sleep_timers();
+    let calls = harness.calls();
+    assert_eq!(calls.len(), 1);
+    assert!(calls[0].starts_with(&format!("{}/music: set_sleep_gain 0.", KITCHEN)));
+    assert!(calls[0].ends_with("false"));
+
+    thread::sleep(Duration::from_millis(150));
+    harness.handler.tick_sleep_timers();
+    assert_eq!(
+        harness.calls(),
+        [
+            format!("{}/music: stop", KITCHEN),
+            format!("{}/music: set_sleep_gain 1.00 true", KITCHEN),
+        ]
+    );
+    assert_eq!(
+        harness.events(),
+        [(SpeakerEventContent::SleepTimerFired, KITCHEN.to_string())]
+    );
+}
+
+#[test]
+fn sleep_timer_fires_once_when_stop_fails() {
+    let mut harness = Harness::new();
+    harness.play(KITCHEN, URL).unwrap();
+    harness
+        .command(KITCHEN, SpeakerCommandContent::SetSleepTimer, |fbb| {
+            set_sleep_timer(fbb, 0.05, 0.0)
+        })
+        .unwrap();
+    harness.backend.fail(&format!("{}/music: stop", KITCHEN));
+    harness.calls();
+    harness.events.take();
+
+    thread::sleep(Duration::from_millis(100));
+    harness.handler.tick_sleep_timers();
+    assert_eq!(
+        harness.calls(),
+        [
+            format!("{}/music: stop", KITCHEN),
+            format!("{}/music: set_sleep_gain 1.00 true", KITCHEN),
+        ]
+    );
+    let topics: Vec<String> = harness
+        .events
+        .take()
+        .into_iter()
+        .map(|(topic, _)| topic)
+        .collect();
+    assert_eq!(topics, ["error"]);
+
+    harness.handler.tick_sleep_timers();
+    assert!(harness.calls().is_empty());
+    assert!(harness.events.take().is_empty());
+}
+
+#[test]
+fn sleep_timer_reaps_a_player_that_exited_mid_fade() {
+    let mut harness = Harness::new();
+    harness.play(KITCHEN, URL).unwrap();
+    harness
+        .command(KITCHEN, SpeakerCommandContent::SetSleepTimer, |fbb| {
+            set_sleep_timer(fbb, 0.2, 0.2)
+        })
+        .unwrap();
+    harness.backend.crash(KITCHEN);
+    harness.calls();
+    harness.events.take();
+
+    thread::sleep(Duration::from_millis(100));
+    harness.handler.tick_sleep_timers();
+    assert_eq!(harness.calls(), [format!("{}/music: kill", KITCHEN)]);
+
+    thread::sleep(Duration::from_millis(150));
+    harness.handler.tick_sleep_timers();
+    harness.handler.tick_sleep_timers();
+    assert!(harness.calls().is_empty());
+    // Fired once, without errors from the dead player
+    let messages = harness.events.take();
+    assert_eq!(messages.len(), 1);
+    assert_eq!(
+        speaker_event(&messages[0].1),
+        Some((SpeakerEventContent::SleepTimerFired, KITCHEN.to_string()))
+    );
+}
+
+#[test]
+fn cancel_sleep_timer_restores_the_volume() {
+    let mut harness = Harness::new();
+    harness.play(KITCHEN, URL).unwrap();
+    harness
+        .command(KITCHEN, SpeakerCommandContent::SetSleepTimer, |fbb| {
+            set_sleep_timer(fbb, 600.0, 30.0)
+        })
+        .unwrap();
+    harness.calls();
+
+    let response = harness
+        .command(KITCHEN, SpeakerCommandContent::CancelSleepTimer, |fbb| {
+            CancelSleepTimer::create(fbb, &CancelSleepTimerArgs {}).as_union_value()
+        })
+        .unwrap()
+        .unwrap();
+
+    let sleep_timer_set = root_as_message(&response)
+        .unwrap()
+        .content_as_speaker_event()
+        .unwrap()
+        .event_as_sleep_timer_set()
+        .unwrap();
+    assert_eq!(sleep_timer_set.remaining(), 0.0);
+    assert_eq!(
+        harness.calls(),
+        [format!("{}/music: set_sleep_gain 1.00 true", KITCHEN)]
+    );
+}
+
+#[test]
+fn commands_need_a_known_device_and_command() {
+    let mut harness = Harness::new();
+
+    let response = harness.play("plughw:CARD=Garage,DEV=0", URL);
+    assert_eq!(response, Err("Device does not exist".to_string()));
+
+    let response = harness.command(KITCHEN, SpeakerCommandContent::NONE, toggle_pause);
+    assert_eq!(response, Err("Unknown command".to_string()));
+}
+
+#[test]
+fn player_queries_are_answered_by_the_player() {
+    let queries: [(SpeakerQueryContent, &str, SpeakerEventContent); 4] = [
+        (
+            SpeakerQueryContent::QueryMusicVolume,
+            "volume",
+            SpeakerEventContent::MusicVolumeChanged,
+        ),
+        (
+            SpeakerQueryContent::QueryPause,
+            "pause",
+            SpeakerEventContent::PauseChanged,
+        ),
+        (
+            SpeakerQueryContent::QuerySeek,
+            "time-pos",
+            SpeakerEventContent::SeekChanged,
+        ),
+        (
+            SpeakerQueryContent::QueryDuration,
+            "duration",
+            SpeakerEventContent::DurationChanged,
+        ),
+    ];
+
+    for (query_type, property, event) in queries {
+        let mut harness = Harness::new();
+
+        let response = harness.query(KITCHEN, query_type, |fbb| match query_type {
+            SpeakerQueryContent::QueryMusicVolume => {
+                QueryMusicVolume::create(fbb, &QueryMusicVolumeArgs {}).as_union_value()
+            }
+            SpeakerQueryContent::QueryPause => {
+                QueryPause::create(fbb, &QueryPauseArgs {}).as_union_value()
+            }
+            SpeakerQueryContent::QuerySeek => {
+                QuerySeek::create(fbb, &QuerySeekArgs {}).as_union_value()
+            }
+            _ => QueryDuration::create(fbb, &QueryDurationArgs {}).as_union_value(),
+        });
+
+        assert_eq!(response, Ok(None));
+        assert_eq!(
+            harness.calls().last().unwrap(),
+            &format!("{}/music: query_property {}", KITCHEN, property)
+        );
+        assert_eq!(harness.events().last(), Some(&(event, KITCHEN.to_string())));
+    }
+}
+
+#[test]
+fn query_hardware_volume_answers_under_the_requested_id() {
+    let mut harness = Harness::new();
+    harness
+        .command(
+            "downstairs",
+            SpeakerCommandContent::CreateSpeakerGroup,
+            |fbb| create_group(fbb, &[KITCHEN, BEDROOM]),
+        )
+        .unwrap();
+
+    let response = harness.query(
+        "downstairs",
+        SpeakerQueryContent::QueryHardwareVolume,
+        |fbb| QueryHardwareVolume::create(fbb, &QueryHardwareVolumeArgs {}).as_union_value(),
+    );
+
+    assert_eq!(
+        event_type(response.unwrap()),
+        (
+            SpeakerEventContent::HardwareVolumeChanged,
+            "downstairs".to_string()
+        )
+    );
+    assert!(!harness
+        .calls()
+        .iter()
+        .any(|call| call.ends_with("music: spawn")));
+}
+
+#[test]
+fn query_sleep_timer_reports_no_timer() {
+    let mut harness = Harness::new();
+
+    let response = harness
+        .query(KITCHEN, SpeakerQueryContent::QuerySleepTimer, |fbb| {
+            QuerySleepTimer::create(fbb, &QuerySleepTimerArgs {}).as_union_value()
+        })
+        .unwrap()
+        .unwrap();
+
+    let event = root_as_message(&response)
+        .unwrap()
+        .content_as_speaker_event()
+        .unwrap();
+    assert_eq!(event.event_as_sleep_timer_set().unwrap().remaining(), 0.0);
+    assert_eq!(event.device_id(), Some(KITCHEN));
+    assert!(harness.calls().is_empty());
+}
+
+#[test]
+fn query_play_is_not_answered() {
+    let mut harness = Harness::new();
+
+    let response = harness.query(KITCHEN, SpeakerQueryContent::QueryPlay, |fbb| {
+        QueryPlay::create(fbb, &QueryPlayArgs {}).as_union_value()
+    });
+
+    assert_eq!(response, Err("Unknown query".to_string()));
+}
+
+#[test]
+fn removed_devices_are_killed_and_reported() {
+    let mut harness = Harness::new();
+    harness.play(BEDROOM, URL).unwrap();
+    harness.calls();
+    harness.events();
+
+    harness.backend.set_devices(&[KITCHEN]);
+    harness.handler.refresh_devices().unwrap();
+
+    assert_eq!(harness.calls(), [format!("{}/music: kill", BEDROOM)]);
+    let messages = harness.events.take();
+    assert_eq!(messages.len(), 1);
+    assert_eq!(
+        root_as_message(&messages[0].1).unwrap().content_type(),
+        MessageContent::SpeakerListEvent
+    );
+}
+
+#[test]
+fn saved_playback_is_resumed_on_discovery() {
+    let path = temp_path("state.json");
+    std::fs::write(
+        &path,
+        format!(
+            r#"{{"{}": {{"music_volume": 30, "crossfade": 0, "ducking": 0.7,
+                "url": "{}", "kind": "PlayStream", "position": 42.0, "paused": true}}}}"#,
+            KITCHEN, URL
+        ),
+    )
+    .unwrap();
+
+    let harness = Harness::with_state(path, true);
+
+    assert_eq!(
+        harness.calls(),
+        [
+            format!("{}/music: spawn", KITCHEN),
+            format!("{}/music: set_property volume 30.0", KITCHEN),
+            format!("{}/music: set_crossfade false 0", KITCHEN),
+            format!(
+                "{}/music: resume {} PlayStream Some(42.0) true",
+                KITCHEN, URL
+            ),
+        ]
+    );
+}
+
+#[test]
+fn saved_playback_keeps_its_kind() {
+    let path = temp_path("kind.json");
+    let mut harness = Harness::with_state(path.clone(), true);
+    harness
+        .command(KITCHEN, SpeakerCommandContent::Play, |fbb| {
+            let url = fbb.create_string(URL);
+            let content = PlayStream::create(fbb, &PlayStreamArgs { url: Some(url) });
+            Play::create(
+                fbb,
+                &PlayArgs {
+                    content_type: PlayContent::PlayStream,
+                    content: Some(content.as_union_value()),
+                },
+            )
+            .as_union_value()
+        })
+        .unwrap();
+    harness.handler.save_state().unwrap();
+
+    let saved = SpeakerStore::load_from(path.clone(), true)
+        .unwrap()
+        .take_resume(KITCHEN)
+        .unwrap();
+    assert_eq!(saved.url.as_deref(), Some(URL));
+    assert_eq!(saved.kind.as_deref(), Some("PlayStream"));
+}
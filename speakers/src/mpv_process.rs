@@ -1,39 +1,231 @@
+use crate::backend::{GroupRole, Player, PlayerBackend, PlayerRemote};
+use crate::fbs::construct_announcement_ended_event_message;
+use crate::fbs::construct_announcement_started_event_message;
 use crate::fbs::construct_duration_changed_event_message;
 use crate::fbs::construct_file_ended_event_message;
+use crate::fbs::construct_mute_changed_event_message;
+use crate::fbs::construct_now_playing_event_message;
+use crate::fbs::construct_pause_changed_event_message;
+use crate::fbs::construct_play_started_event_message;
+use crate::fbs::construct_playback_failed_event_message;
 use crate::fbs::construct_seek_changed_event_message;
+use crate::fbs::construct_speed_changed_event_message;
 use crate::fbs::NcSendable;
-use serde_json::Value;
+use crate::PlayContent;
+use serde_json::{json, Value};
+use std::collections::HashMap;
 use std::io::BufRead;
 use std::io::Write;
 use std::os::unix::net::UnixStream;
 use std::sync::{
-    atomic::{AtomicBool, Ordering},
-    Arc,
+    atomic::{AtomicBool, AtomicU64, Ordering},
+    mpsc, Arc, Mutex,
 };
 use std::thread::{self, JoinHandle};
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 use crate::fbs::construct_volume_changed_event_message;
 
 pub struct MpvProcess {
-    process: std::process::Child,
-    socket: UnixStream,
-    kill_signal: Arc<AtomicBool>,
-}
-
-const PROPERTY_MAP: &[(&str, u64)] = &[
-    ("volume", 1),
-    ("mute", 2),
-    ("pause", 3),
-    ("time-pos", 4),
-    ("duration", 5),
-    ("filename", 6),
+    process: Arc<Mutex<std::process::Child>>,
+    shared: Arc<MpvShared>,
+    socket_path: String,
+}
+
+/// What an mpv instance plays, which decides the events it publishes.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum MpvChannel {
+    Music,
+    /// Plays over the music and only reports AnnouncementStarted/AnnouncementEnded
+    Announcement,
+    /// Plays out the end of the music's track while the next one fades in, reports nothing
+    Crossfade,
+}
+
+/// IPC and playback state shared between an `MpvProcess` and its recv thread.
+struct MpvShared {
+    channel: MpvChannel,
+    socket: Mutex<UnixStream>,
+    kill_signal: AtomicBool,
+    next_request_id: AtomicU64,
+    pending_requests: Mutex<HashMap<u64, PendingRequest>>,
+    playback: Mutex<Playback>,
+    /// Decides the ids events are published under while the speaker is grouped
+    group_role: Mutex<Option<GroupRole>>,
+    /// Music instance to duck while an announcement plays
+    ducking: Mutex<Option<Ducking>>,
+    /// Instance the end of each track plays out on while the next one starts here
+    crossfade: Mutex<Option<Crossfade>>,
+}
+
+struct Ducking {
+    music: Arc<dyn PlayerRemote>,
+    /// Fraction the music volume is lowered by
+    amount: f32,
+}
+
+struct Crossfade {
+    tail: Arc<dyn PlayerRemote>,
+    /// Seconds the end of a track and the start of the next one overlap
+    duration: f64,
+}
+
+/// What the crossfade instance is doing for the music instance.
+#[derive(Default)]
+enum CrossfadeStage {
+    #[default]
+    Idle,
+    /// `path` is loaded paused at `start`, where its end begins overlapping the next track
+    Prepared { path: String, start: f64 },
+    /// The end of the previous track is playing out until `until`
+    PlayingOut { until: Instant },
+}
+
+/// Handle for driving an mpv instance from another thread, e.g. a group's resync thread.
+#[derive(Clone)]
+pub struct MpvRemote {
+    shared: Arc<MpvShared>,
+}
+
+#[derive(Default)]
+struct Playback {
+    /// `path` of the file mpv is playing, as passed to loadfile
+    current_path: Option<String>,
+    /// `PlayContent` member `current_path` was requested as, reported in `PlayStarted`
+    current_kind: PlayContent,
+    /// URL appended behind the current file by `preload`
+    preloaded_url: Option<String>,
+    /// `PlayContent` member `preloaded_url` was requested as
+    preloaded_kind: PlayContent,
+    /// mpv still has `preloaded_url` queued and will move on to it when the current file ends
+    preload_queued: bool,
+    /// `current_path` was set ahead of mpv's `path`, which is unavailable until the file opens.
+    /// Until then a null `path` and the end of the replaced file are not about `current_path`
+    awaiting_path: bool,
+    /// Temp file a WAV payload was spooled to, removed once it is no longer played
+    spool_path: Option<String>,
+    crossfade_stage: CrossfadeStage,
+    /// The current file is being cut short for a crossfade, its end counts as reaching eof
+    crossfade_ending: bool,
+    /// The current file has been loaded and playback (or pause) has begun
+    restarted: bool,
+    paused: bool,
+    time_pos: Option<f64>,
+    duration: Option<f64>,
+    /// Copied to the crossfade instance when it takes over the end of a track
+    volume: Option<f64>,
+    muted: bool,
+    /// Position to seek to once the file being resumed has loaded
+    resume_at: Option<f64>,
+    media_info: MediaInfo,
+}
+
+/// Titles and chapters of the current file, published as `NowPlaying`.
+#[derive(Clone, PartialEq)]
+pub struct MediaInfo {
+    pub title: Option<String>,
+    /// ICY title of an internet radio stream
+    pub stream_title: Option<String>,
+    pub chapter: i32,
+    pub chapters: i32,
+    pub chapter_title: Option<String>,
+}
+
+impl Default for MediaInfo {
+    fn default() -> Self {
+        Self {
+            title: None,
+            stream_title: None,
+            chapter: -1,
+            chapters: 0,
+            chapter_title: None,
+        }
+    }
+}
+
+/// Result of an mpv IPC request: the reply's `data` on success, mpv's error string otherwise.
+pub type MpvReply = Result<Value, String>;
+
+/// What the recv thread should do with the reply to a request.
+enum ReplyHandler {
+    /// Publish the returned value as the matching speaker event
+    Property(String),
+    /// Hand the reply back to a caller waiting in `request_blocking`, e.g. a remote's get_property
+    Caller(mpsc::Sender<MpvReply>),
+    /// Nothing to do on success
+    Ignore,
+}
+
+struct PendingRequest {
+    command: Value,
+    handler: ReplyHandler,
+}
+
+const OBSERVED_PROPERTIES: &[&str] = &[
+    "volume",
+    "pause",
+    "time-pos",
+    "duration",
+    "filename",
+    "path",
+    "mute",
+    "speed",
+    "media-title",
+    "metadata/by-key/icy-title",
+    "chapter",
+    "chapters",
+    "chapter-metadata/by-key/title",
+];
+
+// Spooled WAVs are named after this, they aren't worth reporting as what is playing
+const SPOOL_PREFIX: &str = "/tmp/mpv-wav-";
+
+/// A spool file name no other player uses, even when a group or several announcements spool
+/// in the same millisecond.
+fn new_spool_path() -> String {
+    static SPOOL_COUNTER: AtomicU64 = AtomicU64::new(0);
+    let time_since_epoch = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap();
+    format!(
+        "{}{}-{}-{}.wav",
+        SPOOL_PREFIX,
+        std::process::id(),
+        time_since_epoch.as_millis(),
+        SPOOL_COUNTER.fetch_add(1, Ordering::SeqCst)
+    )
+}
+
+const TIME_POS_THROTTLE: Duration = Duration::from_millis(500);
+
+// Gain filter on the music instance, so ducking doesn't touch `volume`
+const DUCK_FILTER: &str = "@duck:lavfi=[volume=1]";
+
+/// Replaces the music's duck filter with one at `gain`. Unlike af-command this also works while
+/// the music is idle, and the gain is kept when the next file builds its filter chain.
+pub fn duck_command(gain: f32) -> Value {
+    json!(["af", "add", format!("@duck:lavfi=[volume={}]", gain)])
+}
+
+// Same for the sleep timer's fade-out
+const SLEEP_FILTER: &str = "@sleep:lavfi=[volume=1]";
+
+/// Presets for `set_audio_filter`, "none" removes the filter
+pub const AUDIO_FILTER_PRESETS: &[(&str, &str)] = &[
+    ("loudnorm", "lavfi=[loudnorm=I=-16:TP=-1.5:LRA=11]"),
+    (
+        "night",
+        "lavfi=[acompressor=threshold=0.1:ratio=4:makeup=2]",
+    ),
+    ("bass", "lavfi=[bass=g=6]"),
+    ("treble", "lavfi=[treble=g=4]"),
+    ("voice", "lavfi=[highpass=f=200,lowpass=f=3000]"),
 ];
 
 impl MpvProcess {
-    pub fn new<'a>(
-        primary_arg: &str,
+    pub fn new(
         device_id: &str,
+        channel: MpvChannel,
         nc: Arc<nats::Connection>,
     ) -> Result<Self, Box<dyn std::error::Error>> {
         let time_since_epoch = std::time::SystemTime::now()
@@ -41,8 +233,10 @@ impl MpvProcess {
             .unwrap();
         let socket_path = format!("/tmp/mpv-socket-{}", time_since_epoch.as_millis());
         println!("starting mpv with socket path: {}", socket_path);
-        let mpv_process = std::process::Command::new("mpv")
-            .arg(primary_arg)
+        // Stay alive between tracks, files are loaded over IPC
+        let mut mpv_process = std::process::Command::new("mpv")
+            .arg("--idle=yes")
+            .arg("--prefetch-playlist=yes")
             .arg("--no-video")
             .arg(format!("--audio-device=alsa/{device_id}"))
             .arg(format!("--input-ipc-server={}", socket_path))
@@ -54,9 +248,15 @@ impl MpvProcess {
         let start = Instant::now();
         loop {
             if start.elapsed() >= timeout {
+                let _ = mpv_process.kill();
+                let _ = mpv_process.wait();
                 return Err("Timeout waiting for mpv socket".into());
             }
 
+            if let Some(status) = mpv_process.try_wait()? {
+                return Err(format!("mpv exited on startup with {}", status).into());
+            }
+
             if std::fs::metadata(&socket_path).is_ok() {
                 break;
             }
@@ -65,96 +265,392 @@ impl MpvProcess {
             std::thread::sleep(std::time::Duration::from_millis(100));
         }
         println!("Mpv socket created.");
-        let socket = UnixStream::connect(socket_path)?;
+        let socket = UnixStream::connect(&socket_path)?;
 
         println!("socket connected");
-        let kill_signal = Arc::new(AtomicBool::new(false));
 
         println!("Creating recv_thread...");
-        Self::make_recv_thread(socket.try_clone()?, nc, Arc::clone(&kill_signal), device_id);
+        let process = Arc::new(Mutex::new(mpv_process));
+        let shared = Arc::new(MpvShared {
+            channel,
+            socket: Mutex::new(socket.try_clone()?),
+            kill_signal: AtomicBool::new(false),
+            next_request_id: AtomicU64::new(1),
+            pending_requests: Mutex::new(HashMap::new()),
+            playback: Mutex::new(Playback::default()),
+            group_role: Mutex::new(None),
+            ducking: Mutex::new(None),
+            crossfade: Mutex::new(None),
+        });
+        Self::make_recv_thread(
+            socket,
+            nc,
+            Arc::clone(&shared),
+            Arc::clone(&process),
+            socket_path.clone(),
+            device_id,
+        );
         println!("recv_thread created.");
 
-        Ok(Self {
-            process: mpv_process,
-            socket,
-            kill_signal,
-        })
+        let mut mpv_process = Self {
+            process,
+            shared,
+            socket_path,
+        };
+
+        match channel {
+            MpvChannel::Music => {
+                for property in OBSERVED_PROPERTIES {
+                    mpv_process.observe_property(property)?;
+                }
+                mpv_process.command(json!(["af", "add", DUCK_FILTER]))?;
+                mpv_process.command(json!(["af", "add", SLEEP_FILTER]))?;
+            }
+            // Only to tell when a spooled WAV has finished
+            MpvChannel::Announcement => mpv_process.observe_property("path")?,
+            // Driven entirely by the music instance
+            MpvChannel::Crossfade => {}
+        }
+
+        Ok(mpv_process)
     }
 
-    pub fn kill(&mut self) -> Result<(), Box<dyn std::error::Error>> {
-        //is the process already dead?
-        if self.process.try_wait()?.is_some() {
+    /// Replaces whatever is playing with `url`.
+    pub fn loadfile(
+        &mut self,
+        url: &str,
+        kind: PlayContent,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        self.remove_spooled_file();
+        {
+            let mut playback = self.shared.playback.lock().unwrap();
+            playback.current_kind = kind;
+            playback.preloaded_url = None;
+            playback.preload_queued = false;
+            // Reported right away, mpv's `path` lags behind until the file opens
+            playback.current_path = Some(url.to_string());
+            playback.awaiting_path = true;
+            playback.resume_at = None;
+        }
+        self.command(json!(["loadfile", url, "replace"]))?;
+        // The instance outlives tracks, don't let a previous pause carry over
+        self.set_property("pause", false)?;
+        Ok(())
+    }
+
+    /// Plays `url` unless mpv already moved on to it from a preload.
+    pub fn play(&mut self, url: &str, kind: PlayContent) -> Result<(), Box<dyn std::error::Error>> {
+        {
+            let mut playback = self.shared.playback.lock().unwrap();
+            if playback.preloaded_url.as_deref() == Some(url)
+                && playback.current_path.as_deref() == Some(url)
+            {
+                println!("{} is already playing from preload", url);
+                playback.preloaded_url = None;
+                return Ok(());
+            }
+        }
+
+        self.loadfile(url, kind)
+    }
+
+    /// Queues `url` behind the current file so mpv can prefetch it and start it without a gap.
+    /// Replaces any previously preloaded file.
+    pub fn preload(
+        &mut self,
+        url: &str,
+        kind: PlayContent,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        {
+            let mut playback = self.shared.playback.lock().unwrap();
+            if playback.preloaded_url.as_deref() == Some(url) {
+                return Ok(());
+            }
+            playback.preloaded_url = Some(url.to_string());
+            playback.preloaded_kind = kind;
+            playback.preload_queued = true;
+        }
+
+        // Drops everything but the current file, including an older preload
+        self.command(json!(["playlist-clear"]))?;
+        self.command(json!(["loadfile", url, "append"]))?;
+        Ok(())
+    }
+
+    /// Continues `url` from `position`, e.g. after the service restarted.
+    pub fn resume(
+        &mut self,
+        url: &str,
+        kind: PlayContent,
+        position: Option<f64>,
+        paused: bool,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        self.remove_spooled_file();
+        {
+            let mut playback = self.shared.playback.lock().unwrap();
+            playback.current_kind = kind;
+            playback.preloaded_url = None;
+            playback.preload_queued = false;
+            playback.current_path = Some(url.to_string());
+            playback.awaiting_path = true;
+            playback.resume_at = position;
+        }
+        self.command(json!(["loadfile", url, "replace"]))?;
+        self.set_property("pause", paused)?;
+        Ok(())
+    }
+
+    /// Loads `url` paused so several speakers can be started at the same moment.
+    pub fn loadfile_paused(
+        &mut self,
+        url: &str,
+        kind: PlayContent,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        self.remove_spooled_file();
+        {
+            let mut playback = self.shared.playback.lock().unwrap();
+            playback.current_kind = kind;
+            playback.preloaded_url = None;
+            playback.preload_queued = false;
+            playback.current_path = Some(url.to_string());
+            playback.awaiting_path = true;
+            playback.resume_at = None;
+            playback.restarted = false;
+        }
+        self.set_property("pause", true)?;
+        self.command(json!(["loadfile", url, "replace"]))?;
+        Ok(())
+    }
+
+    pub fn is_playing_preloaded(&self, url: &str) -> bool {
+        let playback = self.shared.playback.lock().unwrap();
+        playback.preloaded_url.as_deref() == Some(url)
+            && playback.current_path.as_deref() == Some(url)
+    }
+
+    /// Path of the file being played, if any.
+    pub fn current_path(&self) -> Option<String> {
+        self.shared.playback.lock().unwrap().current_path.clone()
+    }
+
+    /// What is playing and where, unless it is a spooled WAV that won't outlive the process.
+    pub fn resumable_position(&self) -> Option<(String, PlayContent, Option<f64>)> {
+        let playback = self.shared.playback.lock().unwrap();
+        let path = playback.current_path.clone()?;
+        if playback.spool_path.as_ref() == Some(&path) {
+            return None;
+        }
+        Some((path, playback.current_kind, playback.time_pos))
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.shared.playback.lock().unwrap().paused
+    }
+
+    pub fn set_group_role(&mut self, role: Option<GroupRole>) {
+        *self.shared.group_role.lock().unwrap() = role;
+    }
+
+    /// Replaces the preset filter with one from `AUDIO_FILTER_PRESETS`, or removes it for "none".
+    pub fn set_audio_filter(&mut self, preset: &str) -> Result<(), Box<dyn std::error::Error>> {
+        if preset == "none" {
+            self.command(json!(["af", "remove", "@preset"]))?;
             return Ok(());
         }
 
+        let (_, filter) = AUDIO_FILTER_PRESETS
+            .iter()
+            .find(|(name, _)| *name == preset)
+            .ok_or(format!("Unknown audio filter preset {}", preset))?;
+        self.command(json!(["af", "add", format!("@preset:{}", filter)]))?;
+        Ok(())
+    }
+
+    /// Sets the gain of the sleep timer's fade. af-command only reaches a filter chain that is
+    /// playing, `rebuild` replaces the filter instead for when nothing may be.
+    pub fn set_sleep_gain(
+        &mut self,
+        gain: f32,
+        rebuild: bool,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let command = if rebuild {
+            json!(["af", "add", format!("@sleep:lavfi=[volume={}]", gain)])
+        } else {
+            json!(["af-command", "sleep", "volume", gain.to_string()])
+        };
+        self.command(command)?;
+        Ok(())
+    }
+
+    pub fn seek_relative(&mut self, offset: f32) -> Result<(), Box<dyn std::error::Error>> {
+        self.command(json!(["seek", offset, "relative"]))?;
+        Ok(())
+    }
+
+    /// Overlaps the end of each track with the preloaded next one for `duration` seconds, the
+    /// end playing out on `tail` while the next track fades in here. `tail` loads each track a
+    /// second time, ahead of its end. No tail or a `duration` of 0 turns crossfading off.
+    pub fn set_crossfade(
+        &mut self,
+        tail: Option<Arc<dyn PlayerRemote>>,
+        duration: f32,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let crossfade = tail.filter(|_| duration > 0.0).map(|tail| Crossfade {
+            tail,
+            duration: duration as f64,
+        });
+        let enabled = crossfade.is_some();
+        let was_enabled =
+            std::mem::replace(&mut *self.shared.crossfade.lock().unwrap(), crossfade).is_some();
+        self.shared.playback.lock().unwrap().crossfade_stage = CrossfadeStage::Idle;
+
+        if enabled {
+            // A filter with the same label gets replaced
+            let filter = format!("@crossfade:lavfi=[afade=t=in:d={}]", duration);
+            self.command(json!(["af", "add", filter]))?;
+        } else if was_enabled {
+            self.command(json!(["af", "remove", "@crossfade"]))?;
+        }
+        Ok(())
+    }
+
+    pub fn play_wav(&mut self, data: &[u8]) -> Result<(), Box<dyn std::error::Error>> {
+        if data.len() < 12 || &data[0..4] != b"RIFF" || &data[8..12] != b"WAVE" {
+            return Err("Data is not a WAV file".into());
+        }
+
+        // mpv can't read from our memory, so spool the payload to a temp file
+        let spool_path = new_spool_path();
+        println!("spooling wav data to: {}", spool_path);
+        std::fs::write(&spool_path, data)?;
+
+        if let Err(e) = self.loadfile(&spool_path, PlayContent::PlayWav) {
+            // Not handed to mpv, so nothing else will remove it
+            let _ = std::fs::remove_file(&spool_path);
+            return Err(e);
+        }
+        self.shared.playback.lock().unwrap().spool_path = Some(spool_path);
+        Ok(())
+    }
+
+    /// Stops playback and clears mpv's playlist, the process stays idle.
+    pub fn stop(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        let crossfade_stage = {
+            let mut playback = self.shared.playback.lock().unwrap();
+            playback.preloaded_url = None;
+            playback.preload_queued = false;
+            playback.awaiting_path = false;
+            std::mem::take(&mut playback.crossfade_stage)
+        };
+        self.command(json!(["stop"]))?;
+        // The end of the previous track shouldn't go on playing after a stop
+        if let CrossfadeStage::PlayingOut { .. } = crossfade_stage {
+            if let Some(crossfade) = self.shared.crossfade.lock().unwrap().as_ref() {
+                crossfade.tail.command(json!(["stop"]))?;
+            }
+        }
+        self.remove_spooled_file();
+        Ok(())
+    }
+
+    fn remove_spooled_file(&mut self) {
+        let spool_path = self.shared.playback.lock().unwrap().spool_path.take();
+        if let Some(spool_path) = spool_path {
+            println!("Removing spooled file {}...", spool_path);
+            let _ = std::fs::remove_file(spool_path);
+        }
+    }
+
+    pub fn kill(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        self.remove_spooled_file();
+
         // Set the kill signal to true to notify the recv_thread to stop
         println!("Setting kill signal to true...");
-        self.kill_signal.store(true, Ordering::SeqCst);
+        self.shared.kill_signal.store(true, Ordering::SeqCst);
+
+        let mut process = self.process.lock().unwrap();
+
+        //is the process already dead?
+        if process.try_wait()?.is_some() {
+            let _ = std::fs::remove_file(&self.socket_path);
+            return Ok(());
+        }
 
         println!("Shutting down socket and killing mpv process...");
         // Shutdown the socket (this will interrupt any blocking read)
-        self.socket.shutdown(std::net::Shutdown::Both)?;
+        self.shared
+            .socket
+            .lock()
+            .unwrap()
+            .shutdown(std::net::Shutdown::Both)?;
 
         // Kill the mpv process
         println!("Killing mpv process...");
-        self.process.kill()?;
+        process.kill()?;
         println!("Waiting for mpv process to terminate...");
-        self.process.wait()?; // Ensure process is fully terminated
+        process.wait()?; // Ensure process is fully terminated
         println!("Mpv process terminated.");
 
+        let _ = std::fs::remove_file(&self.socket_path);
+
         Ok(())
     }
 
+    pub fn is_running(&self) -> bool {
+        matches!(self.process.lock().unwrap().try_wait(), Ok(None))
+    }
+
+    /// Sends an arbitrary mpv command, e.g. `json!(["loadfile", url])`. Errors are published on
+    /// the error topic once mpv replies.
+    pub fn command(&mut self, command: Value) -> Result<u64, Box<dyn std::error::Error>> {
+        self.shared.send_request(command, ReplyHandler::Ignore)
+    }
+
     pub fn query_property(&mut self, property: &str) -> Result<(), Box<dyn std::error::Error>> {
-        let property_number = PROPERTY_MAP
-            .iter()
-            .find(|(name, _)| name == &property)
-            .map(|(_, number)| *number)
-            .ok_or("Property not found")?;
-        let request_id = property_number;
-
-        let query = format!(
-            "{{\"command\":[\"get_property\",\"{}\"],\"request_id\":{}}}\n",
-            property, request_id
-        );
-        self.socket.write_all(query.as_bytes())?;
+        self.shared.send_request(
+            json!(["get_property", property]),
+            ReplyHandler::Property(property.to_string()),
+        )?;
+        Ok(())
+    }
 
+    pub fn observe_property(&mut self, property: &str) -> Result<(), Box<dyn std::error::Error>> {
+        // Observer ids live in their own namespace, property-change events carry the name anyway
+        let observer_id = self.shared.next_request_id.load(Ordering::SeqCst);
+        self.command(json!(["observe_property", observer_id, property]))?;
         Ok(())
     }
 
     pub fn set_property(
         &mut self,
         property: &str,
-        value: f32,
+        value: impl Into<Value>,
     ) -> Result<(), Box<dyn std::error::Error>> {
-        let property_number = PROPERTY_MAP
-            .iter()
-            .find(|(name, _)| name == &property)
-            .map(|(_, number)| *number)
-            .ok_or("Property not found")?;
-        let request_id = property_number;
-
-        let query = format!(
-            "{{\"command\":[\"set_property\",\"{}\",{}],\"request_id\":{}}}\n",
-            property, value, request_id
-        );
-        self.socket.write_all(query.as_bytes())?;
+        self.command(json!(["set_property", property, value.into()]))?;
+        Ok(())
+    }
 
+    pub fn cycle_property(&mut self, property: &str) -> Result<(), Box<dyn std::error::Error>> {
+        self.command(json!(["cycle", property]))?;
         Ok(())
     }
 
     fn make_recv_thread(
         socket: UnixStream,
         nc: Arc<nats::Connection>,
-        kill_signal: Arc<AtomicBool>,
+        shared: Arc<MpvShared>,
+        process: Arc<Mutex<std::process::Child>>,
+        socket_path: String,
         device_id: &str,
     ) -> JoinHandle<()> {
         let device_id = device_id.to_string();
         thread::spawn(move || {
             let mut reader = std::io::BufReader::new(socket);
+            let mut last_time_pos_sent: Option<Instant> = None;
+            let mut playing = false;
             loop {
                 // Check if the kill signal has been set
-                if kill_signal.load(Ordering::SeqCst) {
+                if shared.kill_signal.load(Ordering::SeqCst) {
                     println!("Kill signal received. Exiting recv_thread...");
                     break;
                 }
@@ -170,68 +666,286 @@ impl MpvProcess {
                         // Attempt to parse the received data as JSON
                         match serde_json::from_slice::<Value>(&buffer) {
                             Ok(event_obj) => {
+                                let device_ids = shared.event_device_ids(&device_id);
                                 let event = &event_obj["event"];
                                 let data = &event_obj["data"];
                                 let request_id = &event_obj["request_id"];
-                                if let Value::String(event) = event {
+                                if let (Value::String(event), MpvChannel::Announcement) =
+                                    (event, shared.channel)
+                                {
                                     match event.as_str() {
+                                        "start-file" => {
+                                            playing = true;
+                                            shared.duck(true);
+                                            Self::publish_event(
+                                                &nc,
+                                                &device_ids,
+                                                construct_announcement_started_event_message,
+                                            );
+                                        }
                                         "end-file" => {
-                                            println!("End of file reached.");
-                                            let message = Ok(Some(
-                                                construct_file_ended_event_message(&device_id),
-                                            ));
-                                            message.send(&nc, "speaker.event");
+                                            playing = false;
+                                            shared.remove_ended_spool();
+                                            if event_obj["reason"] == "error" {
+                                                let message: Result<Option<Vec<u8>>, String> =
+                                                    Err(format!(
+                                                        "Announcement failed on {}: {}",
+                                                        device_id, event_obj["file_error"]
+                                                    ));
+                                                message.send(&nc, "speaker.event");
+                                            }
+                                            shared.duck(false);
+                                            Self::publish_event(
+                                                &nc,
+                                                &device_ids,
+                                                construct_announcement_ended_event_message,
+                                            );
                                         }
-                                        _ => {
-                                            println!("Received event: {}", event);
+                                        "property-change" if event_obj["name"] == "path" => {
+                                            let mut playback = shared.playback.lock().unwrap();
+                                            match data.as_str() {
+                                                Some(path) => {
+                                                    playback.current_path = Some(path.to_string());
+                                                    playback.awaiting_path = false;
+                                                }
+                                                None if !playback.awaiting_path => {
+                                                    playback.current_path = None;
+                                                }
+                                                None => {}
+                                            }
                                         }
+                                        _ => {}
                                     }
-                                } else if let (Value::Number(data), Value::Number(request_id)) =
-                                    (data, request_id)
+                                } else if let (Value::String(_), MpvChannel::Crossfade) =
+                                    (event, shared.channel)
                                 {
-                                    let property_name = PROPERTY_MAP
-                                        .iter()
-                                        .find(|(_, number)| number == &request_id.as_u64().unwrap())
-                                        .map(|(name, _)| name)
-                                        .unwrap_or(&"Unknown");
-
-                                    match property_name {
-                                        &"volume" => {
-                                            let volume = data.as_f64().unwrap();
-                                            println!("Received volume: {}", volume);
-                                            let message =
-                                                Ok(Some(construct_volume_changed_event_message(
-                                                    volume as f32,
-                                                    &device_id,
-                                                )));
-                                            message.send(&nc, "speaker.event");
+                                    // Plays out the end of the music's tracks, nothing to report
+                                } else if let Value::String(event) = event {
+                                    match event.as_str() {
+                                        "start-file" => {
+                                            playing = true;
+                                            let mut playback = shared.playback.lock().unwrap();
+                                            playback.restarted = false;
+                                            playback.duration = None;
+                                            playback.media_info = MediaInfo::default();
                                         }
-                                        &"time-pos" => {
-                                            let time_pos = data.as_f64().unwrap();
-                                            println!("Received time-pos: {}", time_pos);
-                                            let message =
-                                                Ok(Some(construct_seek_changed_event_message(
-                                                    time_pos as f32,
-                                                    &device_id,
-                                                )));
-                                            message.send(&nc, "speaker.event");
+                                        "file-loaded" => {
+                                            let (path, kind) = {
+                                                let playback = shared.playback.lock().unwrap();
+                                                (
+                                                    playback.current_path.clone(),
+                                                    playback.current_kind,
+                                                )
+                                            };
+                                            let url = path
+                                                .as_deref()
+                                                .filter(|path| !path.starts_with(SPOOL_PREFIX));
+                                            Self::publish_event(&nc, &device_ids, |device_id| {
+                                                construct_play_started_event_message(
+                                                    kind, url, device_id,
+                                                )
+                                            });
+
+                                            let resume_at =
+                                                shared.playback.lock().unwrap().resume_at.take();
+                                            if let Some(position) = resume_at {
+                                                println!("Resuming at {}", position);
+                                                let command = json!(["seek", position, "absolute"]);
+                                                if let Err(e) = shared
+                                                    .send_request(command, ReplyHandler::Ignore)
+                                                {
+                                                    eprintln!("Error resuming: {}", e);
+                                                }
+                                            }
                                         }
-                                        &"duration" => {
-                                            let duration = data.as_f64().unwrap();
-                                            println!("Received duration: {}", duration);
-                                            let message =
-                                                Ok(Some(construct_duration_changed_event_message(
-                                                    duration as f32,
-                                                    &device_id,
-                                                )));
-                                            message.send(&nc, "speaker.event");
+                                        "playback-restart" => {
+                                            shared.playback.lock().unwrap().restarted = true;
+                                        }
+                                        "end-file" => {
+                                            playing = false;
+                                            shared.remove_ended_spool();
+                                            let crossfade_ending = std::mem::take(
+                                                &mut shared
+                                                    .playback
+                                                    .lock()
+                                                    .unwrap()
+                                                    .crossfade_ending,
+                                            );
+                                            let reason = match event_obj["reason"]
+                                                .as_str()
+                                                .unwrap_or("unknown")
+                                            {
+                                                // Cut short by playlist-next, but it played to its end
+                                                "stop" if crossfade_ending => "eof",
+                                                reason => reason,
+                                            };
+                                            println!("End of file reached: {}", reason);
+                                            if reason == "eof" || reason == "error" {
+                                                shared.advance_to_preload();
+                                            }
+                                            let device_ids =
+                                                shared.track_event_device_ids(&device_id);
+                                            match reason {
+                                                "eof" => {
+                                                    Self::publish_event(
+                                                        &nc,
+                                                        &device_ids,
+                                                        construct_file_ended_event_message,
+                                                    );
+                                                }
+                                                "error" => {
+                                                    let error = event_obj["file_error"]
+                                                        .as_str()
+                                                        .unwrap_or("unknown error");
+                                                    Self::publish_event(
+                                                        &nc,
+                                                        &device_ids,
+                                                        |device_id| {
+                                                            construct_playback_failed_event_message(
+                                                                reason, error, device_id,
+                                                            )
+                                                        },
+                                                    );
+                                                }
+                                                // mpv is exiting mid-track, which kill() never asks for.
+                                                // The exit itself won't be reported as playing is cleared
+                                                "quit"
+                                                    if !shared
+                                                        .kill_signal
+                                                        .load(Ordering::SeqCst) =>
+                                                {
+                                                    Self::publish_event(
+                                                        &nc,
+                                                        &device_ids,
+                                                        |device_id| {
+                                                            construct_playback_failed_event_message(
+                                                                reason,
+                                                                "mpv quit during playback",
+                                                                device_id,
+                                                            )
+                                                        },
+                                                    );
+                                                }
+                                                // stop/redirect are caused by us or mpv's playlist,
+                                                // nothing to report
+                                                _ => {}
+                                            }
+                                        }
+                                        "seek" => {
+                                            // Let the next time-pos through so seeks show up immediately
+                                            last_time_pos_sent = None;
+                                        }
+                                        "property-change" => {
+                                            let property_name =
+                                                event_obj["name"].as_str().unwrap_or("Unknown");
+
+                                            match (property_name, data) {
+                                                ("path", Value::String(path)) => {
+                                                    let mut playback =
+                                                        shared.playback.lock().unwrap();
+                                                    playback.current_path = Some(path.clone());
+                                                    playback.awaiting_path = false;
+                                                }
+                                                ("path", Value::Null) => {
+                                                    let mut playback =
+                                                        shared.playback.lock().unwrap();
+                                                    if !playback.awaiting_path {
+                                                        playback.current_path = None;
+                                                    }
+                                                }
+                                                ("time-pos", time_pos) => {
+                                                    shared.playback.lock().unwrap().time_pos =
+                                                        time_pos.as_f64();
+                                                    shared.update_crossfade();
+                                                }
+                                                ("pause", Value::Bool(paused)) => {
+                                                    shared.playback.lock().unwrap().paused =
+                                                        *paused;
+                                                }
+                                                ("duration", duration) => {
+                                                    shared.playback.lock().unwrap().duration =
+                                                        duration.as_f64();
+                                                }
+                                                ("volume", volume) => {
+                                                    shared.playback.lock().unwrap().volume =
+                                                        volume.as_f64();
+                                                }
+                                                ("mute", Value::Bool(muted)) => {
+                                                    shared.playback.lock().unwrap().muted = *muted;
+                                                }
+                                                _ => {
+                                                    if let Some(media_info) = shared
+                                                        .update_media_info(property_name, data)
+                                                    {
+                                                        Self::publish_event(
+                                                            &nc,
+                                                            &device_ids,
+                                                            |device_id| {
+                                                                construct_now_playing_event_message(
+                                                                    &media_info,
+                                                                    device_id,
+                                                                )
+                                                            },
+                                                        );
+                                                    }
+                                                }
+                                            }
+
+                                            if property_name == "time-pos" {
+                                                if let Some(last_sent) = last_time_pos_sent {
+                                                    if last_sent.elapsed() < TIME_POS_THROTTLE {
+                                                        continue;
+                                                    }
+                                                }
+                                                last_time_pos_sent = Some(Instant::now());
+                                            }
+
+                                            Self::publish_property(
+                                                property_name,
+                                                data,
+                                                &device_ids,
+                                                &nc,
+                                            );
                                         }
                                         _ => {
-                                            println!(
-                                                "Received property: {} with value: {}",
-                                                property_name, data
+                                            println!("Received event: {}", event);
+                                        }
+                                    }
+                                } else if let Some(request_id) = request_id.as_u64() {
+                                    let pending_request =
+                                        shared.pending_requests.lock().unwrap().remove(&request_id);
+                                    let Some(pending_request) = pending_request else {
+                                        println!("Reply to unknown request {}", request_id);
+                                        continue;
+                                    };
+
+                                    let reply: MpvReply = match event_obj["error"].as_str() {
+                                        Some("success") => Ok(data.clone()),
+                                        Some(error) => Err(error.to_string()),
+                                        None => Err("Malformed reply".to_string()),
+                                    };
+
+                                    match (pending_request.handler, reply) {
+                                        (ReplyHandler::Caller(sender), reply) => {
+                                            let _ = sender.send(reply);
+                                        }
+                                        (ReplyHandler::Property(property_name), Ok(data)) => {
+                                            Self::publish_property(
+                                                &property_name,
+                                                &data,
+                                                &device_ids,
+                                                &nc,
                                             );
                                         }
+                                        (ReplyHandler::Ignore, Ok(_)) => {}
+                                        (_, Err(error)) => {
+                                            let message: Result<Option<Vec<u8>>, String> =
+                                                Err(format!(
+                                                    "mpv command {} failed on {}: {}",
+                                                    pending_request.command, device_id, error
+                                                ));
+                                            message.send(&nc, "speaker.event");
+                                        }
                                     }
                                 }
                             }
@@ -246,6 +960,481 @@ impl MpvProcess {
                     }
                 }
             }
+
+            // kill() reaps the process itself
+            if shared.kill_signal.load(Ordering::SeqCst) {
+                return;
+            }
+
+            // mpv closed the socket on its own, so it is exiting: reap it and clean up
+            let status = process.lock().unwrap().wait();
+            println!("mpv exited with {:?}", status);
+            let _ = std::fs::remove_file(&socket_path);
+
+            if playing && shared.channel == MpvChannel::Announcement {
+                shared.duck(false);
+                Self::publish_event(
+                    &nc,
+                    &shared.event_device_ids(&device_id),
+                    construct_announcement_ended_event_message,
+                );
+            } else if playing {
+                let error = match status {
+                    Ok(status) => format!("mpv exited with {}", status),
+                    Err(e) => format!("mpv exited: {}", e),
+                };
+                Self::publish_event(
+                    &nc,
+                    &shared.track_event_device_ids(&device_id),
+                    |device_id| construct_playback_failed_event_message("exit", &error, device_id),
+                );
+            }
+        })
+    }
+
+    fn publish_property(
+        property_name: &str,
+        data: &Value,
+        device_ids: &[String],
+        nc: &nats::Connection,
+    ) {
+        match (property_name, data) {
+            ("volume", Value::Number(data)) => {
+                let volume = data.as_f64().unwrap();
+                println!("Received volume: {}", volume);
+                Self::publish_event(nc, device_ids, |device_id| {
+                    construct_volume_changed_event_message(volume as f32, device_id)
+                });
+            }
+            ("time-pos", Value::Number(data)) => {
+                let time_pos = data.as_f64().unwrap();
+                println!("Received time-pos: {}", time_pos);
+                Self::publish_event(nc, device_ids, |device_id| {
+                    construct_seek_changed_event_message(time_pos as f32, device_id)
+                });
+            }
+            ("duration", Value::Number(data)) => {
+                let duration = data.as_f64().unwrap();
+                println!("Received duration: {}", duration);
+                Self::publish_event(nc, device_ids, |device_id| {
+                    construct_duration_changed_event_message(duration as f32, device_id)
+                });
+            }
+            ("pause", Value::Bool(paused)) => {
+                println!("Received pause: {}", paused);
+                Self::publish_event(nc, device_ids, |device_id| {
+                    construct_pause_changed_event_message(*paused, device_id)
+                });
+            }
+            ("mute", Value::Bool(muted)) => {
+                println!("Received mute: {}", muted);
+                Self::publish_event(nc, device_ids, |device_id| {
+                    construct_mute_changed_event_message(*muted, device_id)
+                });
+            }
+            ("speed", Value::Number(data)) => {
+                let speed = data.as_f64().unwrap();
+                println!("Received speed: {}", speed);
+                Self::publish_event(nc, device_ids, |device_id| {
+                    construct_speed_changed_event_message(speed as f32, device_id)
+                });
+            }
+            // Tracked by the recv thread, no event for these
+            ("filename", _)
+            | ("path", _)
+            | ("media-title", _)
+            | ("metadata/by-key/icy-title", _)
+            | ("chapter", _)
+            | ("chapters", _)
+            | ("chapter-metadata/by-key/title", _) => {}
+            // Properties that are unavailable (e.g. time-pos while idle) are null
+            (_, Value::Null) => {}
+            _ => {
+                println!("Received property: {} with value: {}", property_name, data);
+            }
+        }
+    }
+
+    fn publish_event(
+        nc: &nats::Connection,
+        device_ids: &[String],
+        construct: impl Fn(&str) -> Vec<u8>,
+    ) {
+        for device_id in device_ids {
+            let message = Ok(Some(construct(device_id)));
+            message.send(nc, "speaker.event");
+        }
+    }
+}
+
+impl MpvShared {
+    fn event_device_ids(&self, device_id: &str) -> Vec<String> {
+        let mut device_ids = vec![device_id.to_string()];
+        if let Some(GroupRole::Leader(group_id)) = &*self.group_role.lock().unwrap() {
+            device_ids.push(group_id.clone());
+        }
+        device_ids
+    }
+
+    /// Ids a track ending or failing is published under. Only the group's, while grouped, so
+    /// listeners queueing per id don't advance a member's queue or the leader's own.
+    fn track_event_device_ids(&self, device_id: &str) -> Vec<String> {
+        match &*self.group_role.lock().unwrap() {
+            Some(GroupRole::Leader(group_id)) => vec![group_id.clone()],
+            Some(GroupRole::Member) => Vec::new(),
+            None => vec![device_id.to_string()],
+        }
+    }
+
+    fn request_blocking(&self, command: Value, timeout: Duration) -> MpvReply {
+        let (sender, receiver) = mpsc::channel();
+        let request_id = self
+            .send_request(command, ReplyHandler::Caller(sender))
+            .map_err(|e| e.to_string())?;
+
+        receiver.recv_timeout(timeout).map_err(|e| {
+            self.pending_requests.lock().unwrap().remove(&request_id);
+            format!("No reply from mpv: {}", e)
+        })?
+    }
+
+    fn send_request(
+        &self,
+        command: Value,
+        handler: ReplyHandler,
+    ) -> Result<u64, Box<dyn std::error::Error>> {
+        let request_id = self.next_request_id.fetch_add(1, Ordering::SeqCst);
+
+        let request = json!({ "command": command, "request_id": request_id });
+        let mut line = serde_json::to_vec(&request)?;
+        line.push(b'\n');
+
+        // Register before writing so the recv thread can't see the reply first
+        self.pending_requests
+            .lock()
+            .unwrap()
+            .insert(request_id, PendingRequest { command, handler });
+
+        if let Err(e) = self.socket.lock().unwrap().write_all(&line) {
+            self.pending_requests.lock().unwrap().remove(&request_id);
+            return Err(e.into());
+        }
+
+        Ok(request_id)
+    }
+
+    /// Applies a change of one of the `MediaInfo` properties, returning the new info if it changed.
+    fn update_media_info(&self, property: &str, data: &Value) -> Option<MediaInfo> {
+        let mut playback = self.playback.lock().unwrap();
+        let mut media_info = playback.media_info.clone();
+
+        let text = data.as_str().map(str::to_string);
+        match property {
+            "media-title" => media_info.title = text,
+            "metadata/by-key/icy-title" => media_info.stream_title = text,
+            // mpv reports no chapter as unavailable rather than -1
+            "chapter" => media_info.chapter = data.as_i64().unwrap_or(-1) as i32,
+            "chapters" => media_info.chapters = data.as_i64().unwrap_or(0) as i32,
+            "chapter-metadata/by-key/title" => media_info.chapter_title = text,
+            _ => return None,
+        }
+
+        if media_info == playback.media_info {
+            return None;
+        }
+        playback.media_info = media_info.clone();
+        Some(media_info)
+    }
+
+    /// mpv moves on to the preloaded file by itself when the current one ends, but reports its
+    /// `path` only after FileEnded has gone out. Marks it as current right away, so the Play that
+    /// FileEnded leads to finds it already playing instead of reloading it.
+    fn advance_to_preload(&self) {
+        let mut playback = self.playback.lock().unwrap();
+        if playback.preload_queued {
+            playback.preload_queued = false;
+            playback.current_path = playback.preloaded_url.clone();
+            playback.current_kind = playback.preloaded_kind;
+            playback.awaiting_path = true;
+        }
+    }
+
+    /// Removes the spooled WAV if it is the file that just ended. Checked against `path` so the
+    /// end-file of a file being replaced can't take the spool replacing it.
+    fn remove_ended_spool(&self) {
+        let mut playback = self.playback.lock().unwrap();
+        if playback.awaiting_path
+            || playback.spool_path.is_none()
+            || playback.spool_path != playback.current_path
+        {
+            return;
+        }
+        if let Some(spool_path) = playback.spool_path.take() {
+            println!("Removing spooled file {}...", spool_path);
+            let _ = std::fs::remove_file(spool_path);
+        }
+    }
+
+    /// Lowers the music this announcement channel plays over, or restores it.
+    fn duck(&self, active: bool) {
+        let ducking = self.ducking.lock().unwrap();
+        let Some(ducking) = ducking.as_ref() else {
+            return;
+        };
+
+        let gain = if active { 1.0 - ducking.amount } else { 1.0 };
+        if let Err(e) = ducking.music.command(duck_command(gain)) {
+            eprintln!("Error ducking music: {}", e);
+        }
+    }
+
+    /// Moves the crossfade instance along as the current file plays: loads the file there ahead
+    /// of its end, then has it play the end out while this instance moves on to the preloaded
+    /// file, which fades in.
+    fn update_crossfade(&self) {
+        let crossfade = self.crossfade.lock().unwrap();
+        let Some(crossfade) = crossfade.as_ref() else {
+            return;
+        };
+        let mut playback = self.playback.lock().unwrap();
+
+        let commands = match &playback.crossfade_stage {
+            CrossfadeStage::PlayingOut { until } if Instant::now() < *until => return,
+            CrossfadeStage::Prepared { path, start }
+                if playback.current_path.as_ref() == Some(path) =>
+            {
+                let start = *start;
+                let Some(time_pos) = playback.time_pos.filter(|time_pos| *time_pos >= start) else {
+                    return;
+                };
+                if !playback.preload_queued || playback.paused {
+                    return;
+                }
+
+                println!("Crossfading at {}", time_pos);
+                playback.crossfade_stage = CrossfadeStage::PlayingOut {
+                    until: Instant::now() + Duration::from_secs_f64(crossfade.duration),
+                };
+                playback.crossfade_ending = true;
+                let fade_out = format!(
+                    "@crossfade:lavfi=[afade=t=out:st={}:d={}]",
+                    time_pos, crossfade.duration
+                );
+                let mut tail_commands = vec![
+                    json!(["set_property", "mute", playback.muted]),
+                    json!(["af", "add", fade_out]),
+                    json!(["seek", time_pos, "absolute"]),
+                    json!(["set_property", "pause", false]),
+                ];
+                if let Some(volume) = playback.volume {
+                    tail_commands.insert(0, json!(["set_property", "volume", volume]));
+                }
+                drop(playback);
+
+                // The preloaded file was prefetched, so it starts right away
+                if let Err(e) = self.send_request(json!(["playlist-next"]), ReplyHandler::Ignore) {
+                    eprintln!("Error starting crossfade: {}", e);
+                }
+                tail_commands
+            }
+            _ => {
+                // Short files would overlap entirely, spooled WAVs are gone once they end
+                let (Some(path), Some(duration)) = (&playback.current_path, playback.duration)
+                else {
+                    playback.crossfade_stage = CrossfadeStage::Idle;
+                    return;
+                };
+                if playback.awaiting_path
+                    || path.starts_with(SPOOL_PREFIX)
+                    || duration < 2.0 * crossfade.duration
+                {
+                    playback.crossfade_stage = CrossfadeStage::Idle;
+                    return;
+                }
+
+                let start = duration - crossfade.duration;
+                let path = path.clone();
+                playback.crossfade_stage = CrossfadeStage::Prepared {
+                    path: path.clone(),
+                    start,
+                };
+                drop(playback);
+
+                // The start option applies to files loaded after it is set
+                vec![
+                    json!(["set_property", "pause", true]),
+                    json!(["set_property", "start", start.to_string()]),
+                    json!(["loadfile", path, "replace"]),
+                ]
+            }
+        };
+
+        for command in commands {
+            if let Err(e) = crossfade.tail.command(command) {
+                eprintln!("Error driving crossfade: {}", e);
+                return;
+            }
+        }
+    }
+}
+
+impl PlayerRemote for MpvRemote {
+    fn get_property(&self, property: &str, timeout: Duration) -> Result<Value, String> {
+        self.shared
+            .request_blocking(json!(["get_property", property]), timeout)
+    }
+
+    fn set_property(&self, property: &str, value: Value) -> Result<(), String> {
+        self.command(json!(["set_property", property, value]))
+    }
+
+    fn command(&self, command: Value) -> Result<(), String> {
+        self.shared
+            .send_request(command, ReplyHandler::Ignore)
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    fn is_ready(&self) -> bool {
+        let playback = self.shared.playback.lock().unwrap();
+        playback.restarted && playback.current_path.is_some()
+    }
+}
+
+/// Runs each player as its own mpv process.
+pub struct MpvBackend {
+    nc: Arc<nats::Connection>,
+}
+
+impl MpvBackend {
+    pub fn new(nc: Arc<nats::Connection>) -> Self {
+        Self { nc }
+    }
+}
+
+impl PlayerBackend for MpvBackend {
+    fn spawn(&self, device_id: &str, channel: MpvChannel) -> Result<Box<dyn Player>, String> {
+        let mpv_process =
+            MpvProcess::new(device_id, channel, self.nc.clone()).map_err(|e| e.to_string())?;
+        Ok(Box::new(mpv_process))
+    }
+}
+
+impl Player for MpvProcess {
+    fn play(&mut self, url: &str, kind: PlayContent) -> Result<(), String> {
+        MpvProcess::play(self, url, kind).map_err(|e| e.to_string())
+    }
+
+    fn play_wav(&mut self, data: &[u8]) -> Result<(), String> {
+        MpvProcess::play_wav(self, data).map_err(|e| e.to_string())
+    }
+
+    fn preload(&mut self, url: &str, kind: PlayContent) -> Result<(), String> {
+        MpvProcess::preload(self, url, kind).map_err(|e| e.to_string())
+    }
+
+    fn loadfile_paused(&mut self, url: &str, kind: PlayContent) -> Result<(), String> {
+        MpvProcess::loadfile_paused(self, url, kind).map_err(|e| e.to_string())
+    }
+
+    fn resume(
+        &mut self,
+        url: &str,
+        kind: PlayContent,
+        position: Option<f64>,
+        paused: bool,
+    ) -> Result<(), String> {
+        MpvProcess::resume(self, url, kind, position, paused).map_err(|e| e.to_string())
+    }
+
+    fn stop(&mut self) -> Result<(), String> {
+        MpvProcess::stop(self).map_err(|e| e.to_string())
+    }
+
+    fn set_property(&mut self, property: &str, value: Value) -> Result<(), String> {
+        MpvProcess::set_property(self, property, value).map_err(|e| e.to_string())
+    }
+
+    fn query_property(&mut self, property: &str) -> Result<(), String> {
+        MpvProcess::query_property(self, property).map_err(|e| e.to_string())
+    }
+
+    fn cycle_property(&mut self, property: &str) -> Result<(), String> {
+        MpvProcess::cycle_property(self, property).map_err(|e| e.to_string())
+    }
+
+    fn seek_relative(&mut self, offset: f32) -> Result<(), String> {
+        MpvProcess::seek_relative(self, offset).map_err(|e| e.to_string())
+    }
+
+    fn set_crossfade(
+        &mut self,
+        tail: Option<Arc<dyn PlayerRemote>>,
+        duration: f32,
+    ) -> Result<(), String> {
+        MpvProcess::set_crossfade(self, tail, duration).map_err(|e| e.to_string())
+    }
+
+    fn set_audio_filter(&mut self, preset: &str) -> Result<(), String> {
+        MpvProcess::set_audio_filter(self, preset).map_err(|e| e.to_string())
+    }
+
+    fn set_sleep_gain(&mut self, gain: f32, rebuild: bool) -> Result<(), String> {
+        MpvProcess::set_sleep_gain(self, gain, rebuild).map_err(|e| e.to_string())
+    }
+
+    fn set_group_role(&mut self, role: Option<GroupRole>) {
+        MpvProcess::set_group_role(self, role)
+    }
+
+    fn set_ducking(&mut self, music: Option<Arc<dyn PlayerRemote>>, amount: f32) {
+        *self.shared.ducking.lock().unwrap() = music.map(|music| Ducking {
+            music,
+            amount: amount.clamp(0.0, 1.0),
+        });
+    }
+
+    fn remote(&self) -> Arc<dyn PlayerRemote> {
+        Arc::new(MpvRemote {
+            shared: Arc::clone(&self.shared),
         })
     }
+
+    fn is_playing_preloaded(&self, url: &str) -> bool {
+        MpvProcess::is_playing_preloaded(self, url)
+    }
+
+    fn current_path(&self) -> Option<String> {
+        MpvProcess::current_path(self)
+    }
+
+    fn resumable_position(&self) -> Option<(String, PlayContent, Option<f64>)> {
+        MpvProcess::resumable_position(self)
+    }
+
+    fn is_paused(&self) -> bool {
+        MpvProcess::is_paused(self)
+    }
+
+    fn is_running(&self) -> bool {
+        MpvProcess::is_running(self)
+    }
+
+    fn kill(&mut self) -> Result<(), String> {
+        MpvProcess::kill(self).map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{new_spool_path, SPOOL_PREFIX};
+
+    #[test]
+    fn spool_paths_in_the_same_millisecond_differ() {
+        let first = new_spool_path();
+        let second = new_spool_path();
+
+        assert!(first.starts_with(SPOOL_PREFIX));
+        assert_ne!(first, second);
+    }
 }
@@ -8,6 +8,7 @@ use crate::msg_echo_generated::*;
 use crate::msg_error_generated::*;
 use crate::msg_print_generated::*;
 use crate::msg_playlists_generated::*;
+use crate::msg_alarms_generated::*;
 use core::mem;
 use core::cmp::Ordering;
 
@@ -17,10 +18,10 @@ use self::flatbuffers::{EndianScalar, Follow};
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
 pub const ENUM_MIN_MESSAGE_CONTENT: u8 = 0;
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
-pub const ENUM_MAX_MESSAGE_CONTENT: u8 = 11;
+pub const ENUM_MAX_MESSAGE_CONTENT: u8 = 14;
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
 #[allow(non_camel_case_types)]
-pub const ENUM_VALUES_MESSAGE_CONTENT: [MessageContent; 12] = [
+pub const ENUM_VALUES_MESSAGE_CONTENT: [MessageContent; 15] = [
   MessageContent::NONE,
   MessageContent::Print,
   MessageContent::Echo,
@@ -33,6 +34,9 @@ pub const ENUM_VALUES_MESSAGE_CONTENT: [MessageContent; 12] = [
   MessageContent::PlaylistQuery,
   MessageContent::PlaylistEvent,
   MessageContent::Error,
+  MessageContent::AlarmCommand,
+  MessageContent::AlarmQuery,
+  MessageContent::AlarmEvent,
 ];
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
@@ -52,9 +56,12 @@ impl MessageContent {
   pub const PlaylistQuery: Self = Self(9);
   pub const PlaylistEvent: Self = Self(10);
   pub const Error: Self = Self(11);
+  pub const AlarmCommand: Self = Self(12);
+  pub const AlarmQuery: Self = Self(13);
+  pub const AlarmEvent: Self = Self(14);
 
   pub const ENUM_MIN: u8 = 0;
-  pub const ENUM_MAX: u8 = 11;
+  pub const ENUM_MAX: u8 = 14;
   pub const ENUM_VALUES: &'static [Self] = &[
     Self::NONE,
     Self::Print,
@@ -68,6 +75,9 @@ impl MessageContent {
     Self::PlaylistQuery,
     Self::PlaylistEvent,
     Self::Error,
+    Self::AlarmCommand,
+    Self::AlarmQuery,
+    Self::AlarmEvent,
   ];
   /// Returns the variant's name or "" if unknown.
   pub fn variant_name(self) -> Option<&'static str> {
@@ -84,6 +94,9 @@ impl MessageContent {
       Self::PlaylistQuery => Some("PlaylistQuery"),
       Self::PlaylistEvent => Some("PlaylistEvent"),
       Self::Error => Some("Error"),
+      Self::AlarmCommand => Some("AlarmCommand"),
+      Self::AlarmQuery => Some("AlarmQuery"),
+      Self::AlarmEvent => Some("AlarmEvent"),
       _ => None,
     }
   }
@@ -364,6 +377,51 @@ impl<'a> Message<'a> {
     }
   }
 
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn content_as_alarm_command(&self) -> Option<AlarmCommand<'a>> {
+    if self.content_type() == MessageContent::AlarmCommand {
+      self.content().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { AlarmCommand::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn content_as_alarm_query(&self) -> Option<AlarmQuery<'a>> {
+    if self.content_type() == MessageContent::AlarmQuery {
+      self.content().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { AlarmQuery::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn content_as_alarm_event(&self) -> Option<AlarmEvent<'a>> {
+    if self.content_type() == MessageContent::AlarmEvent {
+      self.content().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { AlarmEvent::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
 }
 
 impl flatbuffers::Verifiable for Message<'_> {
@@ -387,6 +445,9 @@ impl flatbuffers::Verifiable for Message<'_> {
           MessageContent::PlaylistQuery => v.verify_union_variant::<flatbuffers::ForwardsUOffset<PlaylistQuery>>("MessageContent::PlaylistQuery", pos),
           MessageContent::PlaylistEvent => v.verify_union_variant::<flatbuffers::ForwardsUOffset<PlaylistEvent>>("MessageContent::PlaylistEvent", pos),
           MessageContent::Error => v.verify_union_variant::<flatbuffers::ForwardsUOffset<Error>>("MessageContent::Error", pos),
+          MessageContent::AlarmCommand => v.verify_union_variant::<flatbuffers::ForwardsUOffset<AlarmCommand>>("MessageContent::AlarmCommand", pos),
+          MessageContent::AlarmQuery => v.verify_union_variant::<flatbuffers::ForwardsUOffset<AlarmQuery>>("MessageContent::AlarmQuery", pos),
+          MessageContent::AlarmEvent => v.verify_union_variant::<flatbuffers::ForwardsUOffset<AlarmEvent>>("MessageContent::AlarmEvent", pos),
           _ => Ok(()),
         }
      })?
@@ -525,6 +586,27 @@ impl core::fmt::Debug for Message<'_> {
             ds.field("content", &"InvalidFlatbuffer: Union discriminant does not match value.")
           }
         },
+        MessageContent::AlarmCommand => {
+          if let Some(x) = self.content_as_alarm_command() {
+            ds.field("content", &x)
+          } else {
+            ds.field("content", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        MessageContent::AlarmQuery => {
+          if let Some(x) = self.content_as_alarm_query() {
+            ds.field("content", &x)
+          } else {
+            ds.field("content", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        MessageContent::AlarmEvent => {
+          if let Some(x) = self.content_as_alarm_event() {
+            ds.field("content", &x)
+          } else {
+            ds.field("content", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
         _ => {
           let x: Option<()> = None;
           ds.field("content", &x)
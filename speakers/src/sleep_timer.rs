@@ -0,0 +1,62 @@
+use std::time::{Duration, Instant};
+
+// Fade steps smaller than this aren't worth an af-command
+const GAIN_STEP: f32 = 0.01;
+// Longest timer accepted, anything longer is a mistake and would overflow `Instant`
+const MAX_DURATION: f32 = 24.0 * 60.0 * 60.0;
+
+/// Stops a speaker's playback at a set time, fading the music out before it fires.
+pub struct SleepTimer {
+    fires_at: Instant,
+    fade: Duration,
+    /// Gain last applied to the music, so only real changes are sent to mpv
+    applied_gain: f32,
+}
+
+impl SleepTimer {
+    /// Fires in `duration` seconds with a fade over the last `fade` of them.
+    pub fn new(duration: f32, fade: f32) -> Result<Self, String> {
+        if !duration.is_finite() || duration <= 0.0 || duration > MAX_DURATION {
+            return Err(format!("Sleep timer duration {} is invalid", duration));
+        }
+        if !fade.is_finite() || fade < 0.0 {
+            return Err(format!("Sleep timer fade {} is invalid", fade));
+        }
+
+        Ok(Self {
+            fires_at: Instant::now() + Duration::from_secs_f32(duration),
+            fade: Duration::from_secs_f32(fade.min(duration)),
+            applied_gain: 1.0,
+        })
+    }
+
+    pub fn remaining(&self) -> Duration {
+        self.fires_at.saturating_duration_since(Instant::now())
+    }
+
+    pub fn fade(&self) -> Duration {
+        self.fade
+    }
+
+    pub fn has_fired(&self) -> bool {
+        self.remaining().is_zero()
+    }
+
+    /// Gain for the music right now, 1 until the fade starts and falling to 0 when it fires.
+    pub fn gain(&self) -> f32 {
+        if self.fade.is_zero() {
+            return 1.0;
+        }
+        (self.remaining().as_secs_f32() / self.fade.as_secs_f32()).min(1.0)
+    }
+
+    /// Returns the gain to apply if it moved by at least `GAIN_STEP` since the last call.
+    pub fn next_gain(&mut self) -> Option<f32> {
+        let gain = self.gain();
+        if (self.applied_gain - gain).abs() < GAIN_STEP {
+            return None;
+        }
+        self.applied_gain = gain;
+        Some(gain)
+    }
+}
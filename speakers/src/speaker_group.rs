@@ -0,0 +1,136 @@
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use serde_json::Value;
+
+use crate::backend::PlayerRemote;
+
+const IPC_TIMEOUT: Duration = Duration::from_secs(1);
+const RESYNC_INTERVAL: Duration = Duration::from_secs(5);
+// Members further than this from the leader get seeked back in line
+const RESYNC_THRESHOLD: f64 = 0.15;
+// yt-dlp can take a while to resolve a url
+const READY_TIMEOUT: Duration = Duration::from_secs(30);
+
+pub struct SpeakerGroup {
+    pub device_ids: Vec<String>,
+    /// Players of the members, leader first
+    remotes: Arc<Mutex<Vec<Arc<dyn PlayerRemote>>>>,
+    stop_signal: Arc<AtomicBool>,
+}
+
+impl SpeakerGroup {
+    pub fn new(device_ids: Vec<String>) -> Self {
+        let group = Self {
+            device_ids,
+            remotes: Arc::new(Mutex::new(Vec::new())),
+            stop_signal: Arc::new(AtomicBool::new(false)),
+        };
+        group.make_resync_thread();
+        group
+    }
+
+    pub fn set_remotes(&self, remotes: Vec<Arc<dyn PlayerRemote>>) {
+        *self.remotes.lock().unwrap() = remotes;
+    }
+
+    /// Unpauses every member at once after all of them have loaded the file.
+    pub fn start_together(&self) {
+        let remotes = self.remotes.lock().unwrap().clone();
+        thread::spawn(move || {
+            let start = Instant::now();
+            while !remotes.iter().all(|remote| remote.is_ready()) {
+                if start.elapsed() >= READY_TIMEOUT {
+                    eprintln!("Timeout waiting for group members, starting anyway");
+                    break;
+                }
+                thread::sleep(Duration::from_millis(20));
+            }
+
+            for remote in &remotes {
+                if let Err(e) = remote.set_property("pause", Value::Bool(false)) {
+                    eprintln!("Error starting group member: {}", e);
+                }
+            }
+        });
+    }
+
+    /// Flips every member to the opposite of the leader's pause state.
+    pub fn toggle_pause(&self) -> Result<(), String> {
+        let remotes = self.remotes.lock().unwrap().clone();
+        let leader = remotes.first().ok_or("No mpv process")?;
+        let paused = leader
+            .get_property("pause", IPC_TIMEOUT)?
+            .as_bool()
+            .ok_or("pause is not a bool")?;
+
+        for remote in &remotes {
+            remote.set_property("pause", Value::Bool(!paused))?;
+        }
+        Ok(())
+    }
+
+    fn make_resync_thread(&self) {
+        let remotes = Arc::clone(&self.remotes);
+        let stop_signal = Arc::clone(&self.stop_signal);
+        thread::spawn(move || loop {
+            thread::sleep(RESYNC_INTERVAL);
+            if stop_signal.load(Ordering::SeqCst) {
+                break;
+            }
+
+            let remotes = remotes.lock().unwrap().clone();
+            if let Err(e) = resync(&remotes) {
+                eprintln!("Error resyncing group: {}", e);
+            }
+        });
+    }
+}
+
+impl Drop for SpeakerGroup {
+    fn drop(&mut self) {
+        self.stop_signal.store(true, Ordering::SeqCst);
+    }
+}
+
+fn resync(remotes: &[Arc<dyn PlayerRemote>]) -> Result<(), String> {
+    let Some((leader, members)) = remotes.split_first() else {
+        return Ok(());
+    };
+
+    if leader.get_property("pause", IPC_TIMEOUT)? == Value::Bool(true) {
+        return Ok(());
+    }
+    // time-pos is unavailable while nothing is playing
+    let Ok(Some(leader_time_pos)) = leader
+        .get_property("time-pos", IPC_TIMEOUT)
+        .map(|time_pos| time_pos.as_f64())
+    else {
+        return Ok(());
+    };
+    let read_at = Instant::now();
+
+    for member in members {
+        let Ok(Some(time_pos)) = member
+            .get_property("time-pos", IPC_TIMEOUT)
+            .map(|time_pos| time_pos.as_f64())
+        else {
+            continue;
+        };
+        let expected = leader_time_pos + read_at.elapsed().as_secs_f64();
+        if (time_pos - expected).abs() > RESYNC_THRESHOLD {
+            println!("Resyncing group member from {} to {}", time_pos, expected);
+            member.set_property(
+                "time-pos",
+                Value::from(leader_time_pos + read_at.elapsed().as_secs_f64()),
+            )?;
+        }
+    }
+    Ok(())
+}
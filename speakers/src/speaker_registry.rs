@@ -0,0 +1,63 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::speaker_store::write_atomically;
+
+const DEFAULT_REGISTRY_PATH: &str = "/config/speakers.json";
+
+/// What the family sees for a speaker instead of its ALSA id.
+#[derive(Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct RegisteredSpeaker {
+    pub name: Option<String>,
+    pub room: Option<String>,
+    pub icon: Option<String>,
+    pub default_volume: Option<f32>,
+    pub hidden: bool,
+}
+
+/// Device id to `RegisteredSpeaker` map, persisted as JSON.
+pub struct SpeakerRegistry {
+    path: String,
+    speakers: HashMap<String, RegisteredSpeaker>,
+}
+
+impl SpeakerRegistry {
+    /// Loads the registry from `SPEAKER_REGISTRY_PATH`, starting empty if the file doesn't exist.
+    /// `config/speakers.example.json` shows the format.
+    pub fn load() -> Result<Self, String> {
+        let path = std::env::var("SPEAKER_REGISTRY_PATH")
+            .unwrap_or_else(|_| DEFAULT_REGISTRY_PATH.to_string());
+        Self::load_from(path)
+    }
+
+    pub fn load_from(path: String) -> Result<Self, String> {
+        let speakers = match std::fs::read_to_string(&path) {
+            Ok(json) => serde_json::from_str(&json)
+                .map_err(|e| format!("Error parsing speaker registry {}: {}", path, e))?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                println!("No speaker registry at {}, starting empty", path);
+                HashMap::new()
+            }
+            Err(e) => return Err(format!("Error reading speaker registry {}: {}", path, e)),
+        };
+
+        Ok(Self { path, speakers })
+    }
+
+    pub fn get(&self, device_id: &str) -> RegisteredSpeaker {
+        self.speakers.get(device_id).cloned().unwrap_or_default()
+    }
+
+    pub fn update(&mut self, device_id: &str, speaker: RegisteredSpeaker) -> Result<(), String> {
+        self.speakers.insert(device_id.to_string(), speaker);
+        self.save()
+    }
+
+    fn save(&self) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(&self.speakers).map_err(|e| e.to_string())?;
+        write_atomically(&self.path, &json)
+            .map_err(|e| format!("Error writing speaker registry {}: {}", self.path, e))
+    }
+}
@@ -0,0 +1,117 @@
+use std::collections::{HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+
+const DEFAULT_STATE_PATH: &str = "/config/speaker_state.json";
+
+/// Per-speaker state that survives a restart of the service.
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+pub struct SavedSpeaker {
+    pub music_volume: f32,
+    pub crossfade: f32,
+    pub ducking: f32,
+    #[serde(default)]
+    pub muted: bool,
+    #[serde(default = "normal_speed")]
+    pub speed: f32,
+    #[serde(default = "no_audio_filter")]
+    pub audio_filter: String,
+    /// What was playing, `None` when idle
+    pub url: Option<String>,
+    /// Name of the `PlayContent` member `url` was played as, e.g. "PlayStream"
+    pub kind: Option<String>,
+    /// Seconds into `url`
+    pub position: Option<f64>,
+    pub paused: bool,
+}
+
+/// Writes `contents` next to `path` and renames it over the file, so a crash mid-write leaves
+/// the previous contents rather than half of the new ones.
+pub fn write_atomically(path: &str, contents: &str) -> std::io::Result<()> {
+    let tmp_path = format!("{}.tmp", path);
+    std::fs::write(&tmp_path, contents).and_then(|_| std::fs::rename(&tmp_path, path))
+}
+
+fn no_audio_filter() -> String {
+    "none".to_string()
+}
+
+fn normal_speed() -> f32 {
+    1.0
+}
+
+/// Device id to `SavedSpeaker` map, persisted as JSON. Entries of unplugged devices are kept
+/// so they get their settings back when they return.
+pub struct SpeakerStore {
+    path: String,
+    saved: HashMap<String, SavedSpeaker>,
+    /// Devices whose saved playback is still to be resumed
+    pending_resume: HashSet<String>,
+}
+
+impl SpeakerStore {
+    /// Loads the store from `SPEAKER_STATE_PATH`. Playback is only resumed if
+    /// `SPEAKERS_RESUME_PLAYBACK` is set to true.
+    pub fn load() -> Result<Self, String> {
+        let path =
+            std::env::var("SPEAKER_STATE_PATH").unwrap_or_else(|_| DEFAULT_STATE_PATH.to_string());
+        let resume = std::env::var("SPEAKERS_RESUME_PLAYBACK")
+            .map(|resume| resume == "true" || resume == "1")
+            .unwrap_or(false);
+        Self::load_from(path, resume)
+    }
+
+    pub fn load_from(path: String, resume: bool) -> Result<Self, String> {
+        let saved: HashMap<String, SavedSpeaker> = match std::fs::read_to_string(&path) {
+            Ok(json) => serde_json::from_str(&json)
+                .map_err(|e| format!("Error parsing speaker state {}: {}", path, e))?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => HashMap::new(),
+            Err(e) => return Err(format!("Error reading speaker state {}: {}", path, e)),
+        };
+
+        let pending_resume = if resume {
+            saved.keys().cloned().collect()
+        } else {
+            HashSet::new()
+        };
+
+        Ok(Self {
+            path,
+            saved,
+            pending_resume,
+        })
+    }
+
+    pub fn get(&self, device_id: &str) -> Option<&SavedSpeaker> {
+        self.saved.get(device_id)
+    }
+
+    /// Returns the speaker's saved playback the first time it is asked for, if resuming is on.
+    pub fn take_resume(&mut self, device_id: &str) -> Option<SavedSpeaker> {
+        if !self.pending_resume.remove(device_id) {
+            return None;
+        }
+        self.saved
+            .get(device_id)
+            .filter(|saved| saved.url.is_some())
+            .cloned()
+    }
+
+    /// Merges `speakers` into the store and writes it out if anything changed.
+    pub fn save(&mut self, speakers: HashMap<String, SavedSpeaker>) -> Result<(), String> {
+        let mut changed = false;
+        for (device_id, speaker) in speakers {
+            if self.saved.get(&device_id) != Some(&speaker) {
+                self.saved.insert(device_id, speaker);
+                changed = true;
+            }
+        }
+        if !changed {
+            return Ok(());
+        }
+
+        let json = serde_json::to_string_pretty(&self.saved).map_err(|e| e.to_string())?;
+        write_atomically(&self.path, &json)
+            .map_err(|e| format!("Error writing speaker state {}: {}", self.path, e))
+    }
+}
@@ -0,0 +1,193 @@
+# automatically generated by the FlatBuffers compiler, do not modify
+
+# namespace: 
+
+import flatbuffers
+from flatbuffers.compat import import_numpy
+np = import_numpy()
+
+class Alarm(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = Alarm()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsAlarm(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    # Alarm
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # Alarm
+    def Id(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.String(o + self._tab.Pos)
+        return None
+
+    # Alarm
+    def DeviceId(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.String(o + self._tab.Pos)
+        return None
+
+    # Alarm
+    def Days(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 1))
+        return 0
+
+    # Alarm
+    def DaysAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint8Flags, o)
+        return 0
+
+    # Alarm
+    def DaysLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # Alarm
+    def DaysIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        return o == 0
+
+    # Alarm
+    def Hour(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+        return 0
+
+    # Alarm
+    def Minute(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+        return 0
+
+    # Alarm
+    def Url(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(14))
+        if o != 0:
+            return self._tab.String(o + self._tab.Pos)
+        return None
+
+    # Alarm
+    def Volume(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(16))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 30.0
+
+    # Alarm
+    def RampVolume(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(18))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 0.0
+
+    # Alarm
+    def RampDuration(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(20))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 0.0
+
+    # Alarm
+    def Enabled(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(22))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return True
+
+def AlarmStart(builder):
+    builder.StartObject(10)
+
+def Start(builder):
+    AlarmStart(builder)
+
+def AlarmAddId(builder, id):
+    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(id), 0)
+
+def AddId(builder, id):
+    AlarmAddId(builder, id)
+
+def AlarmAddDeviceId(builder, deviceId):
+    builder.PrependUOffsetTRelativeSlot(1, flatbuffers.number_types.UOffsetTFlags.py_type(deviceId), 0)
+
+def AddDeviceId(builder, deviceId):
+    AlarmAddDeviceId(builder, deviceId)
+
+def AlarmAddDays(builder, days):
+    builder.PrependUOffsetTRelativeSlot(2, flatbuffers.number_types.UOffsetTFlags.py_type(days), 0)
+
+def AddDays(builder, days):
+    AlarmAddDays(builder, days)
+
+def AlarmStartDaysVector(builder, numElems):
+    return builder.StartVector(1, numElems, 1)
+
+def StartDaysVector(builder, numElems: int) -> int:
+    return AlarmStartDaysVector(builder, numElems)
+
+def AlarmAddHour(builder, hour):
+    builder.PrependUint8Slot(3, hour, 0)
+
+def AddHour(builder, hour):
+    AlarmAddHour(builder, hour)
+
+def AlarmAddMinute(builder, minute):
+    builder.PrependUint8Slot(4, minute, 0)
+
+def AddMinute(builder, minute):
+    AlarmAddMinute(builder, minute)
+
+def AlarmAddUrl(builder, url):
+    builder.PrependUOffsetTRelativeSlot(5, flatbuffers.number_types.UOffsetTFlags.py_type(url), 0)
+
+def AddUrl(builder, url):
+    AlarmAddUrl(builder, url)
+
+def AlarmAddVolume(builder, volume):
+    builder.PrependFloat32Slot(6, volume, 30.0)
+
+def AddVolume(builder, volume):
+    AlarmAddVolume(builder, volume)
+
+def AlarmAddRampVolume(builder, rampVolume):
+    builder.PrependFloat32Slot(7, rampVolume, 0.0)
+
+def AddRampVolume(builder, rampVolume):
+    AlarmAddRampVolume(builder, rampVolume)
+
+def AlarmAddRampDuration(builder, rampDuration):
+    builder.PrependFloat32Slot(8, rampDuration, 0.0)
+
+def AddRampDuration(builder, rampDuration):
+    AlarmAddRampDuration(builder, rampDuration)
+
+def AlarmAddEnabled(builder, enabled):
+    builder.PrependBoolSlot(9, enabled, 1)
+
+def AddEnabled(builder, enabled):
+    AlarmAddEnabled(builder, enabled)
+
+def AlarmEnd(builder):
+    return builder.EndObject()
+
+def End(builder):
+    return AlarmEnd(builder)
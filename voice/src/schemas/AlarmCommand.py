@@ -0,0 +1,66 @@
+# automatically generated by the FlatBuffers compiler, do not modify
+
+# namespace: 
+
+import flatbuffers
+from flatbuffers.compat import import_numpy
+np = import_numpy()
+
+class AlarmCommand(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = AlarmCommand()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsAlarmCommand(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    # AlarmCommand
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # AlarmCommand
+    def CommandType(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+        return 0
+
+    # AlarmCommand
+    def Command(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            from flatbuffers.table import Table
+            obj = Table(bytearray(), 0)
+            self._tab.Union(obj, o)
+            return obj
+        return None
+
+def AlarmCommandStart(builder):
+    builder.StartObject(2)
+
+def Start(builder):
+    AlarmCommandStart(builder)
+
+def AlarmCommandAddCommandType(builder, commandType):
+    builder.PrependUint8Slot(0, commandType, 0)
+
+def AddCommandType(builder, commandType):
+    AlarmCommandAddCommandType(builder, commandType)
+
+def AlarmCommandAddCommand(builder, command):
+    builder.PrependUOffsetTRelativeSlot(1, flatbuffers.number_types.UOffsetTFlags.py_type(command), 0)
+
+def AddCommand(builder, command):
+    AlarmCommandAddCommand(builder, command)
+
+def AlarmCommandEnd(builder):
+    return builder.EndObject()
+
+def End(builder):
+    return AlarmCommandEnd(builder)
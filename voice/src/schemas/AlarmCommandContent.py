@@ -0,0 +1,8 @@
+# automatically generated by the FlatBuffers compiler, do not modify
+
+# namespace: 
+
+class AlarmCommandContent(object):
+    NONE = 0
+    CreateAlarm = 1
+    DeleteAlarm = 2
@@ -0,0 +1,8 @@
+# automatically generated by the FlatBuffers compiler, do not modify
+
+# namespace: 
+
+class AlarmEventContent(object):
+    NONE = 0
+    AlarmsChanged = 1
+    AlarmFired = 2
@@ -0,0 +1,66 @@
+# automatically generated by the FlatBuffers compiler, do not modify
+
+# namespace: 
+
+import flatbuffers
+from flatbuffers.compat import import_numpy
+np = import_numpy()
+
+class AlarmQuery(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = AlarmQuery()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsAlarmQuery(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    # AlarmQuery
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # AlarmQuery
+    def QueryType(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+        return 0
+
+    # AlarmQuery
+    def Query(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            from flatbuffers.table import Table
+            obj = Table(bytearray(), 0)
+            self._tab.Union(obj, o)
+            return obj
+        return None
+
+def AlarmQueryStart(builder):
+    builder.StartObject(2)
+
+def Start(builder):
+    AlarmQueryStart(builder)
+
+def AlarmQueryAddQueryType(builder, queryType):
+    builder.PrependUint8Slot(0, queryType, 0)
+
+def AddQueryType(builder, queryType):
+    AlarmQueryAddQueryType(builder, queryType)
+
+def AlarmQueryAddQuery(builder, query):
+    builder.PrependUOffsetTRelativeSlot(1, flatbuffers.number_types.UOffsetTFlags.py_type(query), 0)
+
+def AddQuery(builder, query):
+    AlarmQueryAddQuery(builder, query)
+
+def AlarmQueryEnd(builder):
+    return builder.EndObject()
+
+def End(builder):
+    return AlarmQueryEnd(builder)
@@ -0,0 +1,7 @@
+# automatically generated by the FlatBuffers compiler, do not modify
+
+# namespace: 
+
+class AlarmQueryContent(object):
+    NONE = 0
+    QueryAlarms = 1
@@ -0,0 +1,74 @@
+# automatically generated by the FlatBuffers compiler, do not modify
+
+# namespace: 
+
+import flatbuffers
+from flatbuffers.compat import import_numpy
+np = import_numpy()
+
+class AlarmsChanged(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = AlarmsChanged()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsAlarmsChanged(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    # AlarmsChanged
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # AlarmsChanged
+    def Alarms(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            x = self._tab.Vector(o)
+            x += flatbuffers.number_types.UOffsetTFlags.py_type(j) * 4
+            x = self._tab.Indirect(x)
+            from Alarm import Alarm
+            obj = Alarm()
+            obj.Init(self._tab.Bytes, x)
+            return obj
+        return None
+
+    # AlarmsChanged
+    def AlarmsLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # AlarmsChanged
+    def AlarmsIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        return o == 0
+
+def AlarmsChangedStart(builder):
+    builder.StartObject(1)
+
+def Start(builder):
+    AlarmsChangedStart(builder)
+
+def AlarmsChangedAddAlarms(builder, alarms):
+    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(alarms), 0)
+
+def AddAlarms(builder, alarms):
+    AlarmsChangedAddAlarms(builder, alarms)
+
+def AlarmsChangedStartAlarmsVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def StartAlarmsVector(builder, numElems: int) -> int:
+    return AlarmsChangedStartAlarmsVector(builder, numElems)
+
+def AlarmsChangedEnd(builder):
+    return builder.EndObject()
+
+def End(builder):
+    return AlarmsChangedEnd(builder)
@@ -0,0 +1,50 @@
+# automatically generated by the FlatBuffers compiler, do not modify
+
+# namespace: 
+
+import flatbuffers
+from flatbuffers.compat import import_numpy
+np = import_numpy()
+
+class AudioFilterChanged(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = AudioFilterChanged()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsAudioFilterChanged(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    # AudioFilterChanged
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # AudioFilterChanged
+    def Preset(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.String(o + self._tab.Pos)
+        return None
+
+def AudioFilterChangedStart(builder):
+    builder.StartObject(1)
+
+def Start(builder):
+    AudioFilterChangedStart(builder)
+
+def AudioFilterChangedAddPreset(builder, preset):
+    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(preset), 0)
+
+def AddPreset(builder, preset):
+    AudioFilterChangedAddPreset(builder, preset)
+
+def AudioFilterChangedEnd(builder):
+    return builder.EndObject()
+
+def End(builder):
+    return AudioFilterChangedEnd(builder)
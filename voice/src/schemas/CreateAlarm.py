@@ -0,0 +1,54 @@
+# automatically generated by the FlatBuffers compiler, do not modify
+
+# namespace: 
+
+import flatbuffers
+from flatbuffers.compat import import_numpy
+np = import_numpy()
+
+class CreateAlarm(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = CreateAlarm()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsCreateAlarm(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    # CreateAlarm
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # CreateAlarm
+    def Alarm(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            x = self._tab.Indirect(o + self._tab.Pos)
+            from Alarm import Alarm
+            obj = Alarm()
+            obj.Init(self._tab.Bytes, x)
+            return obj
+        return None
+
+def CreateAlarmStart(builder):
+    builder.StartObject(1)
+
+def Start(builder):
+    CreateAlarmStart(builder)
+
+def CreateAlarmAddAlarm(builder, alarm):
+    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(alarm), 0)
+
+def AddAlarm(builder, alarm):
+    CreateAlarmAddAlarm(builder, alarm)
+
+def CreateAlarmEnd(builder):
+    return builder.EndObject()
+
+def End(builder):
+    return CreateAlarmEnd(builder)
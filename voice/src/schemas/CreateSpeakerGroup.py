@@ -0,0 +1,69 @@
+# automatically generated by the FlatBuffers compiler, do not modify
+
+# namespace: 
+
+import flatbuffers
+from flatbuffers.compat import import_numpy
+np = import_numpy()
+
+class CreateSpeakerGroup(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = CreateSpeakerGroup()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsCreateSpeakerGroup(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    # CreateSpeakerGroup
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # CreateSpeakerGroup
+    def DeviceIds(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.String(a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return ""
+
+    # CreateSpeakerGroup
+    def DeviceIdsLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # CreateSpeakerGroup
+    def DeviceIdsIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        return o == 0
+
+def CreateSpeakerGroupStart(builder):
+    builder.StartObject(1)
+
+def Start(builder):
+    CreateSpeakerGroupStart(builder)
+
+def CreateSpeakerGroupAddDeviceIds(builder, deviceIds):
+    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(deviceIds), 0)
+
+def AddDeviceIds(builder, deviceIds):
+    CreateSpeakerGroupAddDeviceIds(builder, deviceIds)
+
+def CreateSpeakerGroupStartDeviceIdsVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def StartDeviceIdsVector(builder, numElems: int) -> int:
+    return CreateSpeakerGroupStartDeviceIdsVector(builder, numElems)
+
+def CreateSpeakerGroupEnd(builder):
+    return builder.EndObject()
+
+def End(builder):
+    return CreateSpeakerGroupEnd(builder)
@@ -38,8 +38,22 @@ class Error(object):
             return self._tab.String(o + self._tab.Pos)
         return None
 
+    # Error
+    def DeviceId(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.String(o + self._tab.Pos)
+        return None
+
+    # Error
+    def Index(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
+        return -1
+
 def ErrorStart(builder):
-    builder.StartObject(2)
+    builder.StartObject(4)
 
 def Start(builder):
     ErrorStart(builder)
@@ -56,6 +70,18 @@ def ErrorAddMessage(builder, message):
 def AddMessage(builder, message):
     ErrorAddMessage(builder, message)
 
+def ErrorAddDeviceId(builder, deviceId):
+    builder.PrependUOffsetTRelativeSlot(2, flatbuffers.number_types.UOffsetTFlags.py_type(deviceId), 0)
+
+def AddDeviceId(builder, deviceId):
+    ErrorAddDeviceId(builder, deviceId)
+
+def ErrorAddIndex(builder, index):
+    builder.PrependInt32Slot(3, index, -1)
+
+def AddIndex(builder, index):
+    ErrorAddIndex(builder, index)
+
 def ErrorEnd(builder):
     return builder.EndObject()
 
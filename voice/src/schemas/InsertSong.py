@@ -42,8 +42,15 @@ class InsertSong(object):
             return obj
         return None
 
+    # InsertSong
+    def BeforeId(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.String(o + self._tab.Pos)
+        return None
+
 def InsertSongStart(builder):
-    builder.StartObject(2)
+    builder.StartObject(3)
 
 def Start(builder):
     InsertSongStart(builder)
@@ -60,6 +67,12 @@ def InsertSongAddSong(builder, song):
 def AddSong(builder, song):
     InsertSongAddSong(builder, song)
 
+def InsertSongAddBeforeId(builder, beforeId):
+    builder.PrependUOffsetTRelativeSlot(2, flatbuffers.number_types.UOffsetTFlags.py_type(beforeId), 0)
+
+def AddBeforeId(builder, beforeId):
+    InsertSongAddBeforeId(builder, beforeId)
+
 def InsertSongEnd(builder):
     return builder.EndObject()
 
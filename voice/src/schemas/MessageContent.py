@@ -15,3 +15,6 @@ class MessageContent(object):
     PlaylistQuery = 9
     PlaylistEvent = 10
     Error = 11
+    AlarmCommand = 12
+    AlarmQuery = 13
+    AlarmEvent = 14
@@ -0,0 +1,76 @@
+# automatically generated by the FlatBuffers compiler, do not modify
+
+# namespace: 
+
+import flatbuffers
+from flatbuffers.compat import import_numpy
+np = import_numpy()
+
+class MoveSong(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = MoveSong()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsMoveSong(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    # MoveSong
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # MoveSong
+    def From_(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
+        return 0
+
+    # MoveSong
+    def To(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
+        return 0
+
+    # MoveSong
+    def Id(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.String(o + self._tab.Pos)
+        return None
+
+def MoveSongStart(builder):
+    builder.StartObject(3)
+
+def Start(builder):
+    MoveSongStart(builder)
+
+def MoveSongAddFrom_(builder, from_):
+    builder.PrependInt32Slot(0, from_, 0)
+
+def AddFrom_(builder, from_):
+    MoveSongAddFrom_(builder, from_)
+
+def MoveSongAddTo(builder, to):
+    builder.PrependInt32Slot(1, to, 0)
+
+def AddTo(builder, to):
+    MoveSongAddTo(builder, to)
+
+def MoveSongAddId(builder, id):
+    builder.PrependUOffsetTRelativeSlot(2, flatbuffers.number_types.UOffsetTFlags.py_type(id), 0)
+
+def AddId(builder, id):
+    MoveSongAddId(builder, id)
+
+def MoveSongEnd(builder):
+    return builder.EndObject()
+
+def End(builder):
+    return MoveSongEnd(builder)
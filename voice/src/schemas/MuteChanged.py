@@ -0,0 +1,50 @@
+# automatically generated by the FlatBuffers compiler, do not modify
+
+# namespace: 
+
+import flatbuffers
+from flatbuffers.compat import import_numpy
+np = import_numpy()
+
+class MuteChanged(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = MuteChanged()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsMuteChanged(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    # MuteChanged
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # MuteChanged
+    def Muted(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
+
+def MuteChangedStart(builder):
+    builder.StartObject(1)
+
+def Start(builder):
+    MuteChangedStart(builder)
+
+def MuteChangedAddMuted(builder, muted):
+    builder.PrependBoolSlot(0, muted, 0)
+
+def AddMuted(builder, muted):
+    MuteChangedAddMuted(builder, muted)
+
+def MuteChangedEnd(builder):
+    return builder.EndObject()
+
+def End(builder):
+    return MuteChangedEnd(builder)
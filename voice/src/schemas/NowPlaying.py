@@ -0,0 +1,102 @@
+# automatically generated by the FlatBuffers compiler, do not modify
+
+# namespace: 
+
+import flatbuffers
+from flatbuffers.compat import import_numpy
+np = import_numpy()
+
+class NowPlaying(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = NowPlaying()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsNowPlaying(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    # NowPlaying
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # NowPlaying
+    def Title(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.String(o + self._tab.Pos)
+        return None
+
+    # NowPlaying
+    def StreamTitle(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.String(o + self._tab.Pos)
+        return None
+
+    # NowPlaying
+    def Chapter(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
+        return 0
+
+    # NowPlaying
+    def Chapters(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
+        return 0
+
+    # NowPlaying
+    def ChapterTitle(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        if o != 0:
+            return self._tab.String(o + self._tab.Pos)
+        return None
+
+def NowPlayingStart(builder):
+    builder.StartObject(5)
+
+def Start(builder):
+    NowPlayingStart(builder)
+
+def NowPlayingAddTitle(builder, title):
+    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(title), 0)
+
+def AddTitle(builder, title):
+    NowPlayingAddTitle(builder, title)
+
+def NowPlayingAddStreamTitle(builder, streamTitle):
+    builder.PrependUOffsetTRelativeSlot(1, flatbuffers.number_types.UOffsetTFlags.py_type(streamTitle), 0)
+
+def AddStreamTitle(builder, streamTitle):
+    NowPlayingAddStreamTitle(builder, streamTitle)
+
+def NowPlayingAddChapter(builder, chapter):
+    builder.PrependInt32Slot(2, chapter, 0)
+
+def AddChapter(builder, chapter):
+    NowPlayingAddChapter(builder, chapter)
+
+def NowPlayingAddChapters(builder, chapters):
+    builder.PrependInt32Slot(3, chapters, 0)
+
+def AddChapters(builder, chapters):
+    NowPlayingAddChapters(builder, chapters)
+
+def NowPlayingAddChapterTitle(builder, chapterTitle):
+    builder.PrependUOffsetTRelativeSlot(4, flatbuffers.number_types.UOffsetTFlags.py_type(chapterTitle), 0)
+
+def AddChapterTitle(builder, chapterTitle):
+    NowPlayingAddChapterTitle(builder, chapterTitle)
+
+def NowPlayingEnd(builder):
+    return builder.EndObject()
+
+def End(builder):
+    return NowPlayingEnd(builder)
@@ -6,3 +6,6 @@ class PlayContent(object):
     NONE = 0
     PlayYoutube = 1
     PlayWav = 2
+    PlayLocalFile = 3
+    PlayStream = 4
+    PlayDlna = 5
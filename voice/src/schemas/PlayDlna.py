@@ -0,0 +1,50 @@
+# automatically generated by the FlatBuffers compiler, do not modify
+
+# namespace: 
+
+import flatbuffers
+from flatbuffers.compat import import_numpy
+np = import_numpy()
+
+class PlayDlna(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = PlayDlna()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsPlayDlna(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    # PlayDlna
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # PlayDlna
+    def Url(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.String(o + self._tab.Pos)
+        return None
+
+def PlayDlnaStart(builder):
+    builder.StartObject(1)
+
+def Start(builder):
+    PlayDlnaStart(builder)
+
+def PlayDlnaAddUrl(builder, url):
+    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(url), 0)
+
+def AddUrl(builder, url):
+    PlayDlnaAddUrl(builder, url)
+
+def PlayDlnaEnd(builder):
+    return builder.EndObject()
+
+def End(builder):
+    return PlayDlnaEnd(builder)
@@ -0,0 +1,50 @@
+# automatically generated by the FlatBuffers compiler, do not modify
+
+# namespace: 
+
+import flatbuffers
+from flatbuffers.compat import import_numpy
+np = import_numpy()
+
+class PlayLocalFile(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = PlayLocalFile()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsPlayLocalFile(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    # PlayLocalFile
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # PlayLocalFile
+    def Path(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.String(o + self._tab.Pos)
+        return None
+
+def PlayLocalFileStart(builder):
+    builder.StartObject(1)
+
+def Start(builder):
+    PlayLocalFileStart(builder)
+
+def PlayLocalFileAddPath(builder, path):
+    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(path), 0)
+
+def AddPath(builder, path):
+    PlayLocalFileAddPath(builder, path)
+
+def PlayLocalFileEnd(builder):
+    return builder.EndObject()
+
+def End(builder):
+    return PlayLocalFileEnd(builder)
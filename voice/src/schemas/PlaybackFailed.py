@@ -0,0 +1,63 @@
+# automatically generated by the FlatBuffers compiler, do not modify
+
+# namespace: 
+
+import flatbuffers
+from flatbuffers.compat import import_numpy
+np = import_numpy()
+
+class PlaybackFailed(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = PlaybackFailed()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsPlaybackFailed(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    # PlaybackFailed
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # PlaybackFailed
+    def Reason(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.String(o + self._tab.Pos)
+        return None
+
+    # PlaybackFailed
+    def Error(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.String(o + self._tab.Pos)
+        return None
+
+def PlaybackFailedStart(builder):
+    builder.StartObject(2)
+
+def Start(builder):
+    PlaybackFailedStart(builder)
+
+def PlaybackFailedAddReason(builder, reason):
+    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(reason), 0)
+
+def AddReason(builder, reason):
+    PlaybackFailedAddReason(builder, reason)
+
+def PlaybackFailedAddError(builder, error):
+    builder.PrependUOffsetTRelativeSlot(1, flatbuffers.number_types.UOffsetTFlags.py_type(error), 0)
+
+def AddError(builder, error):
+    PlaybackFailedAddError(builder, error)
+
+def PlaybackFailedEnd(builder):
+    return builder.EndObject()
+
+def End(builder):
+    return PlaybackFailedEnd(builder)
@@ -48,8 +48,15 @@ class PlaylistCommand(object):
             return obj
         return None
 
+    # PlaylistCommand
+    def ExpectedRevision(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Int64Flags, o + self._tab.Pos)
+        return -1
+
 def PlaylistCommandStart(builder):
-    builder.StartObject(3)
+    builder.StartObject(4)
 
 def Start(builder):
     PlaylistCommandStart(builder)
@@ -72,6 +79,12 @@ def PlaylistCommandAddCommand(builder, command):
 def AddCommand(builder, command):
     PlaylistCommandAddCommand(builder, command)
 
+def PlaylistCommandAddExpectedRevision(builder, expectedRevision):
+    builder.PrependInt64Slot(3, expectedRevision, -1)
+
+def AddExpectedRevision(builder, expectedRevision):
+    PlaylistCommandAddExpectedRevision(builder, expectedRevision)
+
 def PlaylistCommandEnd(builder):
     return builder.EndObject()
 
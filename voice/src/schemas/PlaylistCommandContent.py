@@ -8,3 +8,10 @@ class PlaylistCommandContent(object):
     ReplaceSong = 2
     RemoveSong = 3
     InsertSong = 4
+    SetRepeatMode = 5
+    SetShuffle = 6
+    MoveSong = 7
+    ClearPlaylist = 8
+    SkipToIndex = 9
+    Next = 10
+    Previous = 11
@@ -49,8 +49,29 @@ class PlaylistStateChanged(object):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         return o == 0
 
+    # PlaylistStateChanged
+    def Repeat(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Int8Flags, o + self._tab.Pos)
+        return 0
+
+    # PlaylistStateChanged
+    def Shuffle(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
+
+    # PlaylistStateChanged
+    def Revision(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint64Flags, o + self._tab.Pos)
+        return 0
+
 def PlaylistStateChangedStart(builder):
-    builder.StartObject(1)
+    builder.StartObject(4)
 
 def Start(builder):
     PlaylistStateChangedStart(builder)
@@ -67,6 +88,24 @@ def PlaylistStateChangedStartSongsVector(builder, numElems):
 def StartSongsVector(builder, numElems: int) -> int:
     return PlaylistStateChangedStartSongsVector(builder, numElems)
 
+def PlaylistStateChangedAddRepeat(builder, repeat):
+    builder.PrependInt8Slot(1, repeat, 0)
+
+def AddRepeat(builder, repeat):
+    PlaylistStateChangedAddRepeat(builder, repeat)
+
+def PlaylistStateChangedAddShuffle(builder, shuffle):
+    builder.PrependBoolSlot(2, shuffle, 0)
+
+def AddShuffle(builder, shuffle):
+    PlaylistStateChangedAddShuffle(builder, shuffle)
+
+def PlaylistStateChangedAddRevision(builder, revision):
+    builder.PrependUint64Slot(3, revision, 0)
+
+def AddRevision(builder, revision):
+    PlaylistStateChangedAddRevision(builder, revision)
+
 def PlaylistStateChangedEnd(builder):
     return builder.EndObject()
 
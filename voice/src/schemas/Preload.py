@@ -0,0 +1,66 @@
+# automatically generated by the FlatBuffers compiler, do not modify
+
+# namespace: 
+
+import flatbuffers
+from flatbuffers.compat import import_numpy
+np = import_numpy()
+
+class Preload(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = Preload()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsPreload(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    # Preload
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # Preload
+    def ContentType(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+        return 0
+
+    # Preload
+    def Content(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            from flatbuffers.table import Table
+            obj = Table(bytearray(), 0)
+            self._tab.Union(obj, o)
+            return obj
+        return None
+
+def PreloadStart(builder):
+    builder.StartObject(2)
+
+def Start(builder):
+    PreloadStart(builder)
+
+def PreloadAddContentType(builder, contentType):
+    builder.PrependUint8Slot(0, contentType, 0)
+
+def AddContentType(builder, contentType):
+    PreloadAddContentType(builder, contentType)
+
+def PreloadAddContent(builder, content):
+    builder.PrependUOffsetTRelativeSlot(1, flatbuffers.number_types.UOffsetTFlags.py_type(content), 0)
+
+def AddContent(builder, content):
+    PreloadAddContent(builder, content)
+
+def PreloadEnd(builder):
+    return builder.EndObject()
+
+def End(builder):
+    return PreloadEnd(builder)
@@ -0,0 +1,37 @@
+# automatically generated by the FlatBuffers compiler, do not modify
+
+# namespace: 
+
+import flatbuffers
+from flatbuffers.compat import import_numpy
+np = import_numpy()
+
+class QueryAlarms(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = QueryAlarms()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsQueryAlarms(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    # QueryAlarms
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+def QueryAlarmsStart(builder):
+    builder.StartObject(0)
+
+def Start(builder):
+    QueryAlarmsStart(builder)
+
+def QueryAlarmsEnd(builder):
+    return builder.EndObject()
+
+def End(builder):
+    return QueryAlarmsEnd(builder)
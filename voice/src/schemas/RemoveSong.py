@@ -31,8 +31,15 @@ class RemoveSong(object):
             return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
         return 0
 
+    # RemoveSong
+    def Id(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.String(o + self._tab.Pos)
+        return None
+
 def RemoveSongStart(builder):
-    builder.StartObject(1)
+    builder.StartObject(2)
 
 def Start(builder):
     RemoveSongStart(builder)
@@ -43,6 +50,12 @@ def RemoveSongAddIndex(builder, index):
 def AddIndex(builder, index):
     RemoveSongAddIndex(builder, index)
 
+def RemoveSongAddId(builder, id):
+    builder.PrependUOffsetTRelativeSlot(1, flatbuffers.number_types.UOffsetTFlags.py_type(id), 0)
+
+def AddId(builder, id):
+    RemoveSongAddId(builder, id)
+
 def RemoveSongEnd(builder):
     return builder.EndObject()
 
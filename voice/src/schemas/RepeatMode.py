@@ -0,0 +1,8 @@
+# automatically generated by the FlatBuffers compiler, do not modify
+
+# namespace: 
+
+class RepeatMode(object):
+    Off = 0
+    One = 1
+    All = 2
@@ -42,8 +42,15 @@ class ReplaceSong(object):
             return obj
         return None
 
+    # ReplaceSong
+    def Id(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.String(o + self._tab.Pos)
+        return None
+
 def ReplaceSongStart(builder):
-    builder.StartObject(2)
+    builder.StartObject(3)
 
 def Start(builder):
     ReplaceSongStart(builder)
@@ -60,6 +67,12 @@ def ReplaceSongAddSong(builder, song):
 def AddSong(builder, song):
     ReplaceSongAddSong(builder, song)
 
+def ReplaceSongAddId(builder, id):
+    builder.PrependUOffsetTRelativeSlot(2, flatbuffers.number_types.UOffsetTFlags.py_type(id), 0)
+
+def AddId(builder, id):
+    ReplaceSongAddId(builder, id)
+
 def ReplaceSongEnd(builder):
     return builder.EndObject()
 
@@ -0,0 +1,50 @@
+# automatically generated by the FlatBuffers compiler, do not modify
+
+# namespace: 
+
+import flatbuffers
+from flatbuffers.compat import import_numpy
+np = import_numpy()
+
+class SetAudioFilter(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = SetAudioFilter()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsSetAudioFilter(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    # SetAudioFilter
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # SetAudioFilter
+    def Preset(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.String(o + self._tab.Pos)
+        return None
+
+def SetAudioFilterStart(builder):
+    builder.StartObject(1)
+
+def Start(builder):
+    SetAudioFilterStart(builder)
+
+def SetAudioFilterAddPreset(builder, preset):
+    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(preset), 0)
+
+def AddPreset(builder, preset):
+    SetAudioFilterAddPreset(builder, preset)
+
+def SetAudioFilterEnd(builder):
+    return builder.EndObject()
+
+def End(builder):
+    return SetAudioFilterEnd(builder)
@@ -0,0 +1,50 @@
+# automatically generated by the FlatBuffers compiler, do not modify
+
+# namespace: 
+
+import flatbuffers
+from flatbuffers.compat import import_numpy
+np = import_numpy()
+
+class SetRepeatMode(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = SetRepeatMode()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsSetRepeatMode(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    # SetRepeatMode
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # SetRepeatMode
+    def Mode(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Int8Flags, o + self._tab.Pos)
+        return 0
+
+def SetRepeatModeStart(builder):
+    builder.StartObject(1)
+
+def Start(builder):
+    SetRepeatModeStart(builder)
+
+def SetRepeatModeAddMode(builder, mode):
+    builder.PrependInt8Slot(0, mode, 0)
+
+def AddMode(builder, mode):
+    SetRepeatModeAddMode(builder, mode)
+
+def SetRepeatModeEnd(builder):
+    return builder.EndObject()
+
+def End(builder):
+    return SetRepeatModeEnd(builder)
@@ -0,0 +1,63 @@
+# automatically generated by the FlatBuffers compiler, do not modify
+
+# namespace: 
+
+import flatbuffers
+from flatbuffers.compat import import_numpy
+np = import_numpy()
+
+class SetSleepTimer(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = SetSleepTimer()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsSetSleepTimer(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    # SetSleepTimer
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # SetSleepTimer
+    def Duration(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 0.0
+
+    # SetSleepTimer
+    def Fade(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 30.0
+
+def SetSleepTimerStart(builder):
+    builder.StartObject(2)
+
+def Start(builder):
+    SetSleepTimerStart(builder)
+
+def SetSleepTimerAddDuration(builder, duration):
+    builder.PrependFloat32Slot(0, duration, 0.0)
+
+def AddDuration(builder, duration):
+    SetSleepTimerAddDuration(builder, duration)
+
+def SetSleepTimerAddFade(builder, fade):
+    builder.PrependFloat32Slot(1, fade, 30.0)
+
+def AddFade(builder, fade):
+    SetSleepTimerAddFade(builder, fade)
+
+def SetSleepTimerEnd(builder):
+    return builder.EndObject()
+
+def End(builder):
+    return SetSleepTimerEnd(builder)
@@ -0,0 +1,50 @@
+# automatically generated by the FlatBuffers compiler, do not modify
+
+# namespace: 
+
+import flatbuffers
+from flatbuffers.compat import import_numpy
+np = import_numpy()
+
+class SetSpeed(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = SetSpeed()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsSetSpeed(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    # SetSpeed
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # SetSpeed
+    def Speed(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 0.0
+
+def SetSpeedStart(builder):
+    builder.StartObject(1)
+
+def Start(builder):
+    SetSpeedStart(builder)
+
+def SetSpeedAddSpeed(builder, speed):
+    builder.PrependFloat32Slot(0, speed, 0.0)
+
+def AddSpeed(builder, speed):
+    SetSpeedAddSpeed(builder, speed)
+
+def SetSpeedEnd(builder):
+    return builder.EndObject()
+
+def End(builder):
+    return SetSpeedEnd(builder)
@@ -0,0 +1,37 @@
+# automatically generated by the FlatBuffers compiler, do not modify
+
+# namespace: 
+
+import flatbuffers
+from flatbuffers.compat import import_numpy
+np = import_numpy()
+
+class SleepTimerFired(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = SleepTimerFired()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsSleepTimerFired(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    # SleepTimerFired
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+def SleepTimerFiredStart(builder):
+    builder.StartObject(0)
+
+def Start(builder):
+    SleepTimerFiredStart(builder)
+
+def SleepTimerFiredEnd(builder):
+    return builder.EndObject()
+
+def End(builder):
+    return SleepTimerFiredEnd(builder)
@@ -0,0 +1,63 @@
+# automatically generated by the FlatBuffers compiler, do not modify
+
+# namespace: 
+
+import flatbuffers
+from flatbuffers.compat import import_numpy
+np = import_numpy()
+
+class SleepTimerSet(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = SleepTimerSet()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsSleepTimerSet(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    # SleepTimerSet
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # SleepTimerSet
+    def Remaining(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 0.0
+
+    # SleepTimerSet
+    def Fade(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 0.0
+
+def SleepTimerSetStart(builder):
+    builder.StartObject(2)
+
+def Start(builder):
+    SleepTimerSetStart(builder)
+
+def SleepTimerSetAddRemaining(builder, remaining):
+    builder.PrependFloat32Slot(0, remaining, 0.0)
+
+def AddRemaining(builder, remaining):
+    SleepTimerSetAddRemaining(builder, remaining)
+
+def SleepTimerSetAddFade(builder, fade):
+    builder.PrependFloat32Slot(1, fade, 0.0)
+
+def AddFade(builder, fade):
+    SleepTimerSetAddFade(builder, fade)
+
+def SleepTimerSetEnd(builder):
+    return builder.EndObject()
+
+def End(builder):
+    return SleepTimerSetEnd(builder)
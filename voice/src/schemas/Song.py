@@ -45,8 +45,22 @@ class Song(object):
             return self._tab.String(o + self._tab.Pos)
         return None
 
+    # Song
+    def Id(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            return self._tab.String(o + self._tab.Pos)
+        return None
+
+    # Song
+    def OneShot(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
+
 def SongStart(builder):
-    builder.StartObject(3)
+    builder.StartObject(5)
 
 def Start(builder):
     SongStart(builder)
@@ -69,6 +83,18 @@ def SongAddTitle(builder, title):
 def AddTitle(builder, title):
     SongAddTitle(builder, title)
 
+def SongAddId(builder, id):
+    builder.PrependUOffsetTRelativeSlot(3, flatbuffers.number_types.UOffsetTFlags.py_type(id), 0)
+
+def AddId(builder, id):
+    SongAddId(builder, id)
+
+def SongAddOneShot(builder, oneShot):
+    builder.PrependBoolSlot(4, oneShot, 0)
+
+def AddOneShot(builder, oneShot):
+    SongAddOneShot(builder, oneShot)
+
 def SongEnd(builder):
     return builder.EndObject()
 
@@ -9,3 +9,18 @@ class SpeakerCommandContent(object):
     Play = 3
     Stop = 4
     Seek = 5
+    Preload = 6
+    SetCrossfade = 7
+    CreateSpeakerGroup = 8
+    DissolveSpeakerGroup = 9
+    PlayAnnouncement = 10
+    SetDucking = 11
+    UpdateSpeakerInfo = 12
+    ReloadDeviceFilter = 13
+    SetHardwareVolume = 14
+    SetMute = 15
+    SetSpeed = 16
+    SeekRelative = 17
+    SetAudioFilter = 18
+    SetSleepTimer = 19
+    CancelSleepTimer = 20
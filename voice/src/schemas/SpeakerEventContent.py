@@ -11,3 +11,14 @@ class SpeakerEventContent(object):
     DurationChanged = 5
     FileEnded = 6
     PauseChanged = 7
+    PlaybackFailed = 8
+    SpeakerGroupChanged = 9
+    AnnouncementStarted = 10
+    AnnouncementEnded = 11
+    HardwareVolumeChanged = 12
+    MuteChanged = 13
+    SpeedChanged = 14
+    AudioFilterChanged = 15
+    NowPlaying = 16
+    SleepTimerSet = 17
+    SleepTimerFired = 18
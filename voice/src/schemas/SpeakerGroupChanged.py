@@ -0,0 +1,69 @@
+# automatically generated by the FlatBuffers compiler, do not modify
+
+# namespace: 
+
+import flatbuffers
+from flatbuffers.compat import import_numpy
+np = import_numpy()
+
+class SpeakerGroupChanged(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = SpeakerGroupChanged()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsSpeakerGroupChanged(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    # SpeakerGroupChanged
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # SpeakerGroupChanged
+    def DeviceIds(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.String(a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return ""
+
+    # SpeakerGroupChanged
+    def DeviceIdsLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # SpeakerGroupChanged
+    def DeviceIdsIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        return o == 0
+
+def SpeakerGroupChangedStart(builder):
+    builder.StartObject(1)
+
+def Start(builder):
+    SpeakerGroupChangedStart(builder)
+
+def SpeakerGroupChangedAddDeviceIds(builder, deviceIds):
+    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(deviceIds), 0)
+
+def AddDeviceIds(builder, deviceIds):
+    SpeakerGroupChangedAddDeviceIds(builder, deviceIds)
+
+def SpeakerGroupChangedStartDeviceIdsVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def StartDeviceIdsVector(builder, numElems: int) -> int:
+    return SpeakerGroupChangedStartDeviceIdsVector(builder, numElems)
+
+def SpeakerGroupChangedEnd(builder):
+    return builder.EndObject()
+
+def End(builder):
+    return SpeakerGroupChangedEnd(builder)
@@ -0,0 +1,167 @@
+# automatically generated by the FlatBuffers compiler, do not modify
+
+# namespace: 
+
+import flatbuffers
+from flatbuffers.compat import import_numpy
+np = import_numpy()
+
+class SpeakerInfo(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = SpeakerInfo()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsSpeakerInfo(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    # SpeakerInfo
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # SpeakerInfo
+    def DeviceId(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.String(o + self._tab.Pos)
+        return None
+
+    # SpeakerInfo
+    def Name(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.String(o + self._tab.Pos)
+        return None
+
+    # SpeakerInfo
+    def Room(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.String(o + self._tab.Pos)
+        return None
+
+    # SpeakerInfo
+    def Icon(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            return self._tab.String(o + self._tab.Pos)
+        return None
+
+    # SpeakerInfo
+    def DefaultVolume(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return -1.0
+
+    # SpeakerInfo
+    def Hidden(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(14))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
+
+    # SpeakerInfo
+    def MusicVolume(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(16))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 0.0
+
+    # SpeakerInfo
+    def Playing(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(18))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
+
+    # SpeakerInfo
+    def Paused(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(20))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
+
+    # SpeakerInfo
+    def Url(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(22))
+        if o != 0:
+            return self._tab.String(o + self._tab.Pos)
+        return None
+
+def SpeakerInfoStart(builder):
+    builder.StartObject(10)
+
+def Start(builder):
+    SpeakerInfoStart(builder)
+
+def SpeakerInfoAddDeviceId(builder, deviceId):
+    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(deviceId), 0)
+
+def AddDeviceId(builder, deviceId):
+    SpeakerInfoAddDeviceId(builder, deviceId)
+
+def SpeakerInfoAddName(builder, name):
+    builder.PrependUOffsetTRelativeSlot(1, flatbuffers.number_types.UOffsetTFlags.py_type(name), 0)
+
+def AddName(builder, name):
+    SpeakerInfoAddName(builder, name)
+
+def SpeakerInfoAddRoom(builder, room):
+    builder.PrependUOffsetTRelativeSlot(2, flatbuffers.number_types.UOffsetTFlags.py_type(room), 0)
+
+def AddRoom(builder, room):
+    SpeakerInfoAddRoom(builder, room)
+
+def SpeakerInfoAddIcon(builder, icon):
+    builder.PrependUOffsetTRelativeSlot(3, flatbuffers.number_types.UOffsetTFlags.py_type(icon), 0)
+
+def AddIcon(builder, icon):
+    SpeakerInfoAddIcon(builder, icon)
+
+def SpeakerInfoAddDefaultVolume(builder, defaultVolume):
+    builder.PrependFloat32Slot(4, defaultVolume, -1.0)
+
+def AddDefaultVolume(builder, defaultVolume):
+    SpeakerInfoAddDefaultVolume(builder, defaultVolume)
+
+def SpeakerInfoAddHidden(builder, hidden):
+    builder.PrependBoolSlot(5, hidden, 0)
+
+def AddHidden(builder, hidden):
+    SpeakerInfoAddHidden(builder, hidden)
+
+def SpeakerInfoAddMusicVolume(builder, musicVolume):
+    builder.PrependFloat32Slot(6, musicVolume, 0.0)
+
+def AddMusicVolume(builder, musicVolume):
+    SpeakerInfoAddMusicVolume(builder, musicVolume)
+
+def SpeakerInfoAddPlaying(builder, playing):
+    builder.PrependBoolSlot(7, playing, 0)
+
+def AddPlaying(builder, playing):
+    SpeakerInfoAddPlaying(builder, playing)
+
+def SpeakerInfoAddPaused(builder, paused):
+    builder.PrependBoolSlot(8, paused, 0)
+
+def AddPaused(builder, paused):
+    SpeakerInfoAddPaused(builder, paused)
+
+def SpeakerInfoAddUrl(builder, url):
+    builder.PrependUOffsetTRelativeSlot(9, flatbuffers.number_types.UOffsetTFlags.py_type(url), 0)
+
+def AddUrl(builder, url):
+    SpeakerInfoAddUrl(builder, url)
+
+def SpeakerInfoEnd(builder):
+    return builder.EndObject()
+
+def End(builder):
+    return SpeakerInfoEnd(builder)
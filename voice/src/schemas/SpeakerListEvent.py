@@ -44,8 +44,33 @@ class SpeakerListEvent(object):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         return o == 0
 
+    # SpeakerListEvent
+    def Speakers(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            x = self._tab.Vector(o)
+            x += flatbuffers.number_types.UOffsetTFlags.py_type(j) * 4
+            x = self._tab.Indirect(x)
+            from SpeakerInfo import SpeakerInfo
+            obj = SpeakerInfo()
+            obj.Init(self._tab.Bytes, x)
+            return obj
+        return None
+
+    # SpeakerListEvent
+    def SpeakersLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # SpeakerListEvent
+    def SpeakersIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        return o == 0
+
 def SpeakerListEventStart(builder):
-    builder.StartObject(1)
+    builder.StartObject(2)
 
 def Start(builder):
     SpeakerListEventStart(builder)
@@ -62,6 +87,18 @@ def SpeakerListEventStartDeviceIdsVector(builder, numElems):
 def StartDeviceIdsVector(builder, numElems: int) -> int:
     return SpeakerListEventStartDeviceIdsVector(builder, numElems)
 
+def SpeakerListEventAddSpeakers(builder, speakers):
+    builder.PrependUOffsetTRelativeSlot(1, flatbuffers.number_types.UOffsetTFlags.py_type(speakers), 0)
+
+def AddSpeakers(builder, speakers):
+    SpeakerListEventAddSpeakers(builder, speakers)
+
+def SpeakerListEventStartSpeakersVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def StartSpeakersVector(builder, numElems: int) -> int:
+    return SpeakerListEventStartSpeakersVector(builder, numElems)
+
 def SpeakerListEventEnd(builder):
     return builder.EndObject()
 
@@ -9,3 +9,5 @@ class SpeakerQueryContent(object):
     QueryPlay = 3
     QuerySeek = 4
     QueryDuration = 5
+    QueryHardwareVolume = 6
+    QuerySleepTimer = 7
@@ -0,0 +1,50 @@
+# automatically generated by the FlatBuffers compiler, do not modify
+
+# namespace: 
+
+import flatbuffers
+from flatbuffers.compat import import_numpy
+np = import_numpy()
+
+class SpeedChanged(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = SpeedChanged()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsSpeedChanged(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    # SpeedChanged
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # SpeedChanged
+    def Speed(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 0.0
+
+def SpeedChangedStart(builder):
+    builder.StartObject(1)
+
+def Start(builder):
+    SpeedChangedStart(builder)
+
+def SpeedChangedAddSpeed(builder, speed):
+    builder.PrependFloat32Slot(0, speed, 0.0)
+
+def AddSpeed(builder, speed):
+    SpeedChangedAddSpeed(builder, speed)
+
+def SpeedChangedEnd(builder):
+    return builder.EndObject()
+
+def End(builder):
+    return SpeedChangedEnd(builder)
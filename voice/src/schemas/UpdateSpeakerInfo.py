@@ -0,0 +1,102 @@
+# automatically generated by the FlatBuffers compiler, do not modify
+
+# namespace: 
+
+import flatbuffers
+from flatbuffers.compat import import_numpy
+np = import_numpy()
+
+class UpdateSpeakerInfo(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = UpdateSpeakerInfo()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsUpdateSpeakerInfo(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    # UpdateSpeakerInfo
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # UpdateSpeakerInfo
+    def Name(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.String(o + self._tab.Pos)
+        return None
+
+    # UpdateSpeakerInfo
+    def Room(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.String(o + self._tab.Pos)
+        return None
+
+    # UpdateSpeakerInfo
+    def Icon(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.String(o + self._tab.Pos)
+        return None
+
+    # UpdateSpeakerInfo
+    def DefaultVolume(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return -1.0
+
+    # UpdateSpeakerInfo
+    def Hidden(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
+
+def UpdateSpeakerInfoStart(builder):
+    builder.StartObject(5)
+
+def Start(builder):
+    UpdateSpeakerInfoStart(builder)
+
+def UpdateSpeakerInfoAddName(builder, name):
+    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(name), 0)
+
+def AddName(builder, name):
+    UpdateSpeakerInfoAddName(builder, name)
+
+def UpdateSpeakerInfoAddRoom(builder, room):
+    builder.PrependUOffsetTRelativeSlot(1, flatbuffers.number_types.UOffsetTFlags.py_type(room), 0)
+
+def AddRoom(builder, room):
+    UpdateSpeakerInfoAddRoom(builder, room)
+
+def UpdateSpeakerInfoAddIcon(builder, icon):
+    builder.PrependUOffsetTRelativeSlot(2, flatbuffers.number_types.UOffsetTFlags.py_type(icon), 0)
+
+def AddIcon(builder, icon):
+    UpdateSpeakerInfoAddIcon(builder, icon)
+
+def UpdateSpeakerInfoAddDefaultVolume(builder, defaultVolume):
+    builder.PrependFloat32Slot(3, defaultVolume, -1.0)
+
+def AddDefaultVolume(builder, defaultVolume):
+    UpdateSpeakerInfoAddDefaultVolume(builder, defaultVolume)
+
+def UpdateSpeakerInfoAddHidden(builder, hidden):
+    builder.PrependBoolSlot(4, hidden, 0)
+
+def AddHidden(builder, hidden):
+    UpdateSpeakerInfoAddHidden(builder, hidden)
+
+def UpdateSpeakerInfoEnd(builder):
+    return builder.EndObject()
+
+def End(builder):
+    return UpdateSpeakerInfoEnd(builder)
@@ -13,27 +13,15 @@ import { useNavigate } from "react-router-dom";
 import { useNats } from "../nats/NatsProvider";
 import FeatherIcon from "feather-icons-react";
 
-const knownDevices: Record<
-  string,
-  {
-    name: string;
-    icon: React.ReactNode;
-    hide?: boolean;
-  }
-> = {
-  "plughw:CARD=MC1000,DEV=0": {
-    hide: true,
-    name: "Don't use or I will hurt you",
-    icon: <FeatherIcon icon="speaker" />,
-  },
-  "plughw:CARD=Generic,DEV=0": {
-    name: "Main Speaker",
-    icon: <FeatherIcon icon="speaker" />,
-  },
+type Speaker = {
+  deviceId: string;
+  name: string;
+  icon: string;
+  hidden: boolean;
 };
 
 export const AudioPage = () => {
-  const [speakers, setSpeakers] = useState<string[]>([]);
+  const [speakers, setSpeakers] = useState<Speaker[]>([]);
   const navigate = useNavigate();
   const nc = useNats();
 
@@ -46,11 +34,18 @@ export const AudioPage = () => {
         const content: SpeakerListEvent = message.content(
           new SpeakerListEvent()
         );
-        const devLength = content.deviceIdsLength();
-        const devices = [];
-        for (let i = 0; i < devLength; i++) {
-          const deviceId = content.deviceIds(i);
-          devices.push(deviceId);
+        const speakerLength = content.speakersLength();
+        const devices: Speaker[] = [];
+        for (let i = 0; i < speakerLength; i++) {
+          const speaker = content.speakers(i);
+          const deviceId = speaker?.deviceId();
+          if (!speaker || !deviceId) continue;
+          devices.push({
+            deviceId,
+            name: speaker.name() || deviceId,
+            icon: speaker.icon() || "speaker",
+            hidden: speaker.hidden(),
+          });
         }
         setSpeakers(devices);
       }
@@ -61,11 +56,13 @@ export const AudioPage = () => {
   return (
     <Grid>
       {speakers.map((speaker) =>
-        knownDevices[speaker]?.hide ? null : (
-          <GridCol span={6} key={speaker}>
-            <Button onClick={() => navigate(encodeURIComponent(speaker))}>
-              {knownDevices[speaker]?.icon || <FeatherIcon icon="speaker" />}
-              {knownDevices[speaker]?.name || speaker}
+        speaker.hidden ? null : (
+          <GridCol span={6} key={speaker.deviceId}>
+            <Button
+              onClick={() => navigate(encodeURIComponent(speaker.deviceId))}
+            >
+              <FeatherIcon icon={speaker.icon} />
+              {speaker.name}
             </Button>
           </GridCol>
         )
@@ -31,6 +31,7 @@ import { MusicVolumeChanged } from "../schemas/music-volume-changed";
 import { MusicEntry } from "../components/MusicEntry";
 import { SeekChanged } from "../schemas/seek-changed";
 import { DurationChanged } from "../schemas/duration-changed";
+import { NowPlaying } from "../schemas/now-playing";
 import FeatherIcon from "feather-icons-react";
 
 export const SpeakerPage = () => {
@@ -45,18 +46,16 @@ export const SpeakerPage = () => {
   const [volume, setVolume] = useState(0);
   const [seek, setSeek] = useState(0);
   const [duration, setDuration] = useState(0);
+  const [nowPlaying, setNowPlaying] = useState<string | null>(null);
 
   useEffect(() => {
     if (songs.length === 0) {
       return;
     }
+    // Speakers push SeekChanged/DurationChanged as they change, only fetch
+    // the initial state here
     sendMessage(nc, "speaker.query", constructQueryDurationMessage(speaker));
-
-    const interval = setInterval(() => {
-      sendMessage(nc, "speaker.query", constructQuerySeekMessage(speaker));
-    }, 500);
-
-    return () => clearInterval(interval);
+    sendMessage(nc, "speaker.query", constructQuerySeekMessage(speaker));
   }, [nc, speaker, songs]);
 
   useEffect(() => {
@@ -131,6 +130,14 @@ export const SpeakerPage = () => {
           const duration: DurationChanged = event.event(new DurationChanged());
           setDuration(duration.duration());
         }
+        if (type == SpeakerEventContent.NowPlaying) {
+          const nowPlaying: NowPlaying = event.event(new NowPlaying());
+          // Radio streams keep the station as title and put the song in the stream title
+          const titles = [nowPlaying.title(), nowPlaying.streamTitle()].filter(
+            (title) => !!title
+          );
+          setNowPlaying(titles.length > 0 ? titles.join(" - ") : null);
+        }
       }
     });
     return unsubVolume;
@@ -218,6 +225,7 @@ export const SpeakerPage = () => {
           <Slider w="100%" value={volume} onChange={setInternalVolumeState} />
         </Box>
       )}
+      {nowPlaying && <Text pb="16">Now playing: {nowPlaying}</Text>}
       {songs.map((song, i) =>
         i === 0 ? (
           <MusicEntry